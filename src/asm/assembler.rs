@@ -0,0 +1,1624 @@
+/*
+ * assembler.rs
+ * Assembles source text into programs
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Condition;
+use super::super::cpu::Flag;
+use super::super::cpu::Register;
+use super::super::data::*;
+use super::super::error::AsmError;
+use super::super::link::Binding;
+use super::super::link::ObjectFile;
+use super::super::link::ObjectSymbol;
+use super::super::link::Relocation;
+use super::super::link::RelocationField;
+use super::super::opcode::encode;
+use super::file_system::resolve;
+use super::include::locate;
+use super::include::Includer;
+use super::macros::Expander;
+use super::parser::parse;
+use super::parser::Line;
+use super::parser::Operand;
+use super::parser::OperandKind;
+use super::parser::Statement;
+use super::FileSystem;
+use super::MemoryFileSystem;
+use super::Program;
+use super::Span;
+use super::SymbolTable;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+/// The largest address that `.org` and `.align` can pad a program
+/// to, which is the largest label ID that `JMP` can encode
+const MAX_ADDRESS: i64 = 0xFFFFFF;
+
+/// Assembles source text into a program
+///
+/// Each non-blank line holds one instruction, written the
+/// way `Instruction`'s `Display` implementation writes it
+/// (for example `mov 50, r0`, `add r0, r1, r2`, or
+/// `js flag_Zero, L3`), optionally preceded by a label
+/// definition such as `loop:`. Mnemonics are case-insensitive,
+/// and comments start with `;`.
+///
+/// Labels are assembled in two passes. The first pass
+/// assigns each label the index of the instruction that
+/// follows it, and the second pass resolves label
+/// references. A reference that names no label may also
+/// be written `L<id>` to use a raw label ID.
+///
+/// A line may instead hold one of these directives:
+///
+/// * `.const <name>, <value>` (or `.equ`) - Defines a named constant
+/// * `.org <address>` - Pads with `NOP`s up to an instruction index
+/// * `.align <n>` - Pads with `NOP`s up to a multiple of `n`
+/// * `.data <value>, ...` - Declares values that the program's
+///   stack starts with, with the last value on top
+///
+/// Literals, shift amounts, absolute coordinates, jump targets and
+/// directive operands may be constant expressions, which combine
+/// integers, constants and labels (which stand for their IDs) with
+/// the operators of C: `+ - * / % & | ^ << >>`, unary `-` and `~`,
+/// and parentheses. Expressions are evaluated with 64-bit integers,
+/// and must fit in 32 bits where they are used. Directives are run
+/// in the first pass, so a constant, `.org` or `.align` can only
+/// refer to labels defined above it.
+///
+/// Macros defined with `.macro <name> <param>, ...` and `.endm`
+/// are expanded before anything else. A macro is called like an
+/// instruction, and each of its parameters is replaced by the
+/// tokens of the matching argument. Labels defined inside a macro
+/// are local to each call. When an error comes from an expanded
+/// macro, it points at the macro's body, and its notes trace the
+/// calls that led there.
+///
+/// Source text assembled by `assemble` can't include other files,
+/// since it has no file system to read them from. Use `assemble_file`
+/// for programs that span several files.
+///
+/// # Arguments
+///
+/// * `src` - The source text to assemble
+///
+/// # Returns
+///
+/// The assembled program, or an `AsmError`
+/// locating the first offending token
+pub fn assemble(src: &str) -> Result<Program, AsmError> {
+    assemble_source(&MemoryFileSystem::new(), "", src, false, |asm| Ok(asm.program))
+}
+
+/// Assembles a source file, along with the files that it includes
+///
+/// `.include "<path>"` splices in the file at `<path>`, relative to
+/// the including file, unless it was already included. Constants and
+/// macros are shared by every file, but the labels of an included file
+/// are local to it unless it exports them with `.global <label>, ...`.
+/// The program's `SymbolTable` lists a local label of an included
+/// file as `<name>@<path>`. The file being assembled is the main
+/// module, so its labels keep their names, but its labels are only
+/// visible to other files if it exports them too.
+///
+/// Errors carry the path of the file that they occurred in.
+///
+/// # Arguments
+///
+/// * `fs` - The file system that the files are read from
+/// * `path` - The `/`-separated path of the file to assemble
+///
+/// # Returns
+///
+/// The assembled program, or an `AsmError`
+/// locating the first offending token
+pub fn assemble_file(fs: &dyn FileSystem, path: &str) -> Result<Program, AsmError> {
+    let path = resolve("", path);
+    let src = read_main(fs, &path)?;
+    assemble_source(fs, &path, &src, false, |asm| Ok(asm.program))
+}
+
+/// Assembles a source file into a relocatable object,
+/// which `link` can combine with other objects
+///
+/// The object is assembled like `assemble_file`, but its labels
+/// are numbered from the start of the object. A line holding
+/// `.extern <label>, ...` declares labels that another object
+/// exports with `.global`. Extern labels can be jump targets,
+/// but not parts of expressions, since their IDs are only known
+/// once the objects are linked. Likewise, only jump targets are
+/// relocated, so the object's own labels can only be used in jump
+/// targets, `.org` and `.align`, and not in literals, constants
+/// or `.data`. The object's symbol table lists
+/// its labels, with the ones exported by `.global` marked global,
+/// and its externs.
+///
+/// # Arguments
+///
+/// * `fs` - The file system that the files are read from
+/// * `path` - The `/`-separated path of the file to assemble,
+///   which becomes the name of the object
+///
+/// # Returns
+///
+/// The assembled object, or an `AsmError`
+/// locating the first offending token
+pub fn assemble_object(fs: &dyn FileSystem, path: &str) -> Result<ObjectFile, AsmError> {
+    let path = resolve("", path);
+    let src = read_main(fs, &path)?;
+    assemble_source(fs, &path, &src, true, |asm| asm.into_object(&path))
+}
+
+/// [Internal use only]
+/// Reads the main file of a program
+///
+/// # Arguments
+///
+/// * `fs` - The file system to read from
+/// * `path` - The normalized path of the file
+///
+/// # Returns
+///
+/// The contents of the file, or an `AsmError` if it can't be read
+fn read_main(fs: &dyn FileSystem, path: &str) -> Result<String, AsmError> {
+    fs.read(path).map_err(|err| {
+        AsmError::new(Span::new(1, 1, 0), &format!("cannot read `{}`: {}", path, err)).with_file(path)
+    })
+}
+
+/// [Internal use only]
+/// Assembles the source text of the main file of a program
+///
+/// # Arguments
+///
+/// * `fs` - The file system that included files are read from
+/// * `path` - The normalized path of the main file, or an
+///   empty string if the source text has no file
+/// * `src` - The source text of the main file
+/// * `linking` - Whether the program is assembled into an object
+/// * `finish` - Turns the assembled statements into the output
+///
+/// # Returns
+///
+/// The output, or an `AsmError` locating the first offending token
+fn assemble_source<T>(
+    fs: &dyn FileSystem,
+    path: &str,
+    src: &str,
+    linking: bool,
+    finish: impl FnOnce(Assembly) -> Result<T, AsmError>,
+) -> Result<T, AsmError> {
+    let mut includer = Includer::new(fs);
+    let mut macros = Expander::new();
+    includer
+        .load(path, src)
+        .and_then(|tokens| macros.expand(&tokens))
+        .and_then(|tokens| parse(&tokens))
+        .and_then(|stmts| assemble_statements(&stmts, includer.files(), linking))
+        .and_then(finish)
+        .map_err(|err| {
+            let files = includer.files();
+            let err = macros.trace(err, files);
+            match files.get(err.span().file()) {
+                Some(file) if !file.is_empty() => err.with_file(file),
+                _ => err,
+            }
+        })
+}
+
+/// [Internal use only]
+/// Assembles parsed statements
+///
+/// # Arguments
+///
+/// * `stmts` - The statements to assemble, after macro expansion
+/// * `files` - The path of every source file, indexed by file ID
+/// * `linking` - Whether the program is assembled into an object
+///
+/// # Returns
+///
+/// The assembled statements, or an `AsmError`
+/// locating the first offending token
+fn assemble_statements(stmts: &[Statement], files: &[String], linking: bool) -> Result<Assembly, AsmError> {
+    // first pass: assign an ID to every label, run the directives,
+    // and lay out the instructions, where `None` marks a padding `NOP`
+    let mut scope = Scope::new(stmts, files, linking);
+    let mut layout = Vec::new();
+    let mut data = Vec::new();
+    for stmt in stmts {
+        match stmt {
+            Statement::Label(name, span) => scope.define_label(name, layout.len() as u32, *span)?,
+            Statement::Instr(line) => layout.push(Some(line)),
+            Statement::Directive(line) => directive(line, &mut scope, &mut layout, &mut data)?,
+        }
+    }
+    scope.check_links()?;
+
+    // second pass: build the instructions and data
+    let instrs = layout
+        .iter()
+        .map(|line| match line {
+            Some(line) => build(line, &scope),
+            None => Ok(NOPData::new().into()),
+        })
+        .collect::<Result<Vec<Instruction>, AsmError>>()?;
+    let data = data
+        .iter()
+        .map(|op| literal(op, &scope))
+        .collect::<Result<Vec<u32>, AsmError>>()?;
+
+    // a jump's label is its last operand
+    let mut extern_refs = Vec::new();
+    for (idx, (line, instr)) in layout.iter().zip(&instrs).enumerate() {
+        let target = line.filter(|_| instr.jmp_label_id().is_some()).and_then(|line| line.operands.last());
+        if let Some(Operand { kind: OperandKind::Name(name), span }) = target {
+            if scope.is_extern(name, *span) {
+                extern_refs.push((idx, name.clone()));
+            }
+        }
+    }
+
+    let globals = scope.globals.iter().map(|(name, _, _)| name.clone()).collect();
+    Ok(Assembly {
+        spans: layout.iter().map(|line| line.map(|line| line.span)).collect(),
+        globals,
+        externs: scope.externs,
+        extern_refs,
+        program: Program::new(instrs, scope.symbols).with_data(data),
+    })
+}
+
+/// [Internal use only]
+/// The result of assembling a program's statements
+struct Assembly {
+    /// The assembled program, where a reference to
+    /// a `.extern` label holds a label ID of 0
+    program: Program,
+
+    /// The location of each instruction's line, or `None` for a padding `NOP`
+    spans: Vec<Option<Span>>,
+
+    /// The labels exported by `.global`
+    globals: Vec<String>,
+
+    /// The labels declared by `.extern`, with their declarations
+    externs: Vec<(String, Span)>,
+
+    /// The index of each instruction that jumps to
+    /// a `.extern` label, with the name of the label
+    extern_refs: Vec<(usize, String)>,
+}
+
+// implementation
+impl Assembly {
+    /// Encodes the assembled program into an object
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the object
+    ///
+    /// # Returns
+    ///
+    /// The object, or an `AsmError` if an instruction can't be encoded
+    fn into_object(self, name: &str) -> Result<ObjectFile, AsmError> {
+        let table = self.program.symbols();
+        let mut symbols: Vec<ObjectSymbol> = table
+            .iter()
+            .map(|(label, id)| {
+                let binding = if self.globals.iter().any(|global| global == label) {
+                    Binding::Global
+                } else {
+                    Binding::Local
+                };
+                ObjectSymbol::new(label, binding, id, table.span(label).unwrap())
+            })
+            .collect();
+        let first_extern = symbols.len();
+        for (ext, span) in &self.externs {
+            if !symbols[first_extern..].iter().any(|sym| sym.name() == ext) {
+                symbols.push(ObjectSymbol::new(ext, Binding::Extern, 0, *span));
+            }
+        }
+
+        let mut code = Vec::new();
+        let mut relocations = Vec::new();
+        for (idx, instr) in self.program.instructions().iter().enumerate() {
+            let offset = code.len() as u32;
+            code.extend(encode(instr).map_err(|err| {
+                // padding `NOP`s always encode
+                let span = self.spans[idx].expect("padding NOP failed to encode");
+                AsmError::new(span, &err.reason().unwrap_or_else(|| "cannot encode instruction".to_owned()))
+            })?);
+            if instr.jmp_label_id().is_none() {
+                continue;
+            }
+            let field = match instr {
+                Instruction::Jmp(_) | Instruction::Call(_) => RelocationField::Wide,
+                _ => RelocationField::Short,
+            };
+            let symbol = self.extern_refs.iter().find(|(i, _)| *i == idx).map(|(_, ext)| {
+                symbols[first_extern..].iter().position(|sym| sym.name() == ext).unwrap() + first_extern
+            });
+            relocations.push(Relocation::new(offset, field, symbol.map(|sym| sym as u32)));
+        }
+
+        let count = self.program.instructions().len() as u32;
+        let data = self.program.data().to_vec();
+        Ok(ObjectFile::new(name, count, code, symbols, relocations).with_data(data))
+    }
+}
+
+/// [Internal use only]
+/// The names that operands can refer to
+struct Scope<'a> {
+    /// The labels defined so far, where a label that is local to
+    /// an included file is named `<name>@<path>`
+    symbols: SymbolTable,
+
+    /// The constants defined so far, with their values and definitions
+    constants: HashMap<String, (i64, Span)>,
+
+    /// The symbol-table names of the labels that are defined further down
+    pending: HashSet<String>,
+
+    /// The labels exported by `.global`, with
+    /// the file and location of each export
+    globals: Vec<(String, usize, Span)>,
+
+    /// The labels declared by `.extern`, with their declarations
+    externs: Vec<(String, Span)>,
+
+    /// Whether the program is assembled into an object, so
+    /// references to `.extern` labels are left to the linker
+    linking: bool,
+
+    /// The path of every source file, indexed by file ID
+    files: &'a [String],
+}
+
+// implementation
+impl<'a> Scope<'a> {
+    /// Creates a new `Scope` instance with nothing defined
+    ///
+    /// # Arguments
+    ///
+    /// * `stmts` - The statements of the program, whose labels are pending
+    /// * `files` - The path of every source file, indexed by file ID
+    /// * `linking` - Whether the program is assembled into an object
+    ///
+    /// # Returns
+    ///
+    /// A new `Scope` instance
+    fn new(stmts: &[Statement], files: &'a [String], linking: bool) -> Self {
+        let mut scope = Scope {
+            symbols: SymbolTable::new(),
+            constants: HashMap::new(),
+            pending: HashSet::new(),
+            globals: Vec::new(),
+            externs: Vec::new(),
+            linking,
+            files,
+        };
+
+        // find the exports and imports first, since
+        // they decide what names refer to
+        for stmt in stmts {
+            if let Statement::Directive(line) = stmt {
+                let global = line.mnemonic.eq_ignore_ascii_case(".global");
+                let external = line.mnemonic.eq_ignore_ascii_case(".extern");
+                for op in line.operands.iter().filter(|_| global || external) {
+                    if let OperandKind::Name(name) = &op.kind {
+                        if global {
+                            scope.globals.push((name.clone(), op.span.file(), op.span));
+                        } else {
+                            scope.externs.push((name.clone(), op.span));
+                        }
+                    }
+                }
+            }
+        }
+        for stmt in stmts {
+            if let Statement::Label(name, span) = stmt {
+                let key = scope.key(name, span.file());
+                scope.pending.insert(key);
+            }
+        }
+        scope
+    }
+
+    /// Gets the symbol-table name of a label
+    ///
+    /// Labels in the file being assembled keep their names,
+    /// as do the labels exported by `.global`. Any other label
+    /// in an included file is local to that file, so it is
+    /// renamed to `<name>@<path>`, which can't be written in source.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    /// * `file` - The ID of the file that defines the label
+    ///
+    /// # Returns
+    ///
+    /// The name of the label in the program's `SymbolTable`
+    fn key(&self, name: &str, file: usize) -> String {
+        let global = self.globals.iter().any(|(global, f, _)| global == name && *f == file);
+        if file == 0 || global {
+            name.to_owned()
+        } else {
+            format!("{}@{}", name, self.files[file])
+        }
+    }
+
+    /// Gets the symbol-table names that a label
+    /// reference could refer to, in priority order
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    /// * `file` - The ID of the file holding the reference
+    ///
+    /// # Returns
+    ///
+    /// The name of the label local to the file, and the
+    /// name of the exported label if there is one
+    fn keys(&self, name: &str, file: usize) -> Vec<String> {
+        let mut keys = vec![self.key(name, file)];
+        if self.globals.iter().any(|(global, _, _)| global == name) {
+            keys.push(name.to_owned());
+        }
+        keys
+    }
+
+    /// Gets the ID of a label
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    /// * `span` - The location of the reference
+    ///
+    /// # Returns
+    ///
+    /// The ID of the label, or `None` if no label by that
+    /// name is visible from the reference or defined yet
+    fn label_id(&self, name: &str, span: Span) -> Option<u32> {
+        self.keys(name, span.file())
+            .iter()
+            .find_map(|key| self.symbols.id(key))
+    }
+
+    /// Checks whether a label reference refers to a `.extern` label
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    /// * `span` - The location of the reference
+    ///
+    /// # Returns
+    ///
+    /// Whether the name is declared by `.extern`
+    /// and no label by that name is visible
+    fn is_extern(&self, name: &str, span: Span) -> bool {
+        self.label_id(name, span).is_none() && self.externs.iter().any(|(ext, _)| ext == name)
+    }
+
+    /// Defines a label
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    /// * `id` - The ID of the label
+    /// * `span` - The location of the label's definition
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `AsmError` if the name is already defined
+    fn define_label(&mut self, name: &str, id: u32, span: Span) -> Result<(), AsmError> {
+        if let Some((_, def)) = self.constants.get(name) {
+            return Err(AsmError::new(
+                span,
+                &format!("`{}` is already defined as a constant at {}", name, def),
+            ));
+        }
+        let key = self.key(name, span.file());
+        if let Some(def) = self.symbols.span(&key) {
+            // report the name as written, and where the other file defines it
+            return Err(AsmError::new(
+                span,
+                &format!("label `{}` is already defined at {}", name, locate(def, self.files)),
+            ));
+        }
+        self.symbols.define(&key, id, span)?;
+        self.pending.remove(&key);
+        Ok(())
+    }
+
+    /// Ensures that every label exported by `.global` is defined
+    /// by the same file, and that no label declared by `.extern`
+    /// is defined by the program
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `AsmError` locating a bad export or import
+    fn check_links(&self) -> Result<(), AsmError> {
+        for (name, span) in &self.externs {
+            let key = self.keys(name, span.file()).into_iter().find(|key| self.symbols.id(key).is_some());
+            if let Some(def) = key.and_then(|key| self.symbols.span(&key)) {
+                return Err(AsmError::new(
+                    *span,
+                    &format!("`.extern` label `{}` is defined at {}", name, locate(def, self.files)),
+                ));
+            }
+        }
+        for (name, file, span) in &self.globals {
+            match self.symbols.span(name) {
+                Some(def) if def.file() == *file => {}
+                _ => {
+                    return Err(AsmError::new(
+                        *span,
+                        &format!("`.global` label `{}` is not defined in this file", name),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Defines a constant
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the constant
+    /// * `val` - The value of the constant
+    /// * `span` - The location of the constant's name in its definition
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `AsmError` if the name is already defined
+    fn define_constant(&mut self, name: &str, val: i64, span: Span) -> Result<(), AsmError> {
+        if let Some((_, def)) = self.constants.get(name) {
+            return Err(AsmError::new(
+                span,
+                &format!("constant `{}` is already defined at {}", name, def),
+            ));
+        }
+        if let Some(def) = self.symbols.span(&self.key(name, span.file())) {
+            return Err(AsmError::new(
+                span,
+                &format!("`{}` is already defined as a label at {}", name, def),
+            ));
+        }
+        self.constants.insert(name.to_owned(), (val, span));
+        Ok(())
+    }
+
+    /// Gets the value of a name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of a constant or a label
+    /// * `span` - The location of the name
+    /// * `positional` - Whether the value is a position within the
+    ///   program, such as a jump target or an `.org` address, rather
+    ///   than a number that ends up in a literal, constant or `.data`
+    ///
+    /// # Returns
+    ///
+    /// The value of the constant or the ID of the label,
+    /// or an `AsmError` if the name is not defined yet
+    fn value(&self, name: &str, span: Span, positional: bool) -> Result<i64, AsmError> {
+        if let Some((val, _)) = self.constants.get(name) {
+            Ok(*val)
+        } else if let Some(id) = self.label_id(name, span) {
+            // only jump targets are relocated, so anywhere else
+            // an object's label ID would be wrong once linked
+            if self.linking && !positional {
+                return Err(AsmError::new(
+                    span,
+                    &format!("label `{}` can only be used as a jump target or an address in an object", name),
+                ));
+            }
+            Ok(id as i64)
+        } else if self.is_extern(name, span) {
+            Err(AsmError::new(
+                span,
+                &format!("`.extern` label `{}` cannot be used in an expression", name),
+            ))
+        } else if self.keys(name, span.file()).iter().any(|key| self.pending.contains(key)) {
+            Err(AsmError::new(
+                span,
+                &format!("label `{}` must be defined above this directive", name),
+            ))
+        } else {
+            Err(AsmError::new(span, &format!("undefined symbol `{}`", name)))
+        }
+    }
+
+    /// Evaluates an operand holding a constant expression
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The operand
+    /// * `what` - A description of the expected value, used in error messages
+    ///
+    /// # Returns
+    ///
+    /// The value of the operand, or an `AsmError` if it
+    /// is not an expression or can't be evaluated
+    fn eval(&self, op: &Operand, what: &str) -> Result<i64, AsmError> {
+        self.eval_with(op, what, false)
+    }
+
+    /// Evaluates an operand holding a position within the program,
+    /// such as a jump target, an `.org` address or an alignment
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The operand
+    /// * `what` - A description of the expected value, used in error messages
+    ///
+    /// # Returns
+    ///
+    /// The value of the operand, or an `AsmError` if it
+    /// is not an expression or can't be evaluated
+    fn eval_position(&self, op: &Operand, what: &str) -> Result<i64, AsmError> {
+        self.eval_with(op, what, true)
+    }
+
+    /// [Internal use only]
+    /// Evaluates an operand holding a constant expression
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The operand
+    /// * `what` - A description of the expected value, used in error messages
+    /// * `positional` - Whether the value is a position within the program
+    ///
+    /// # Returns
+    ///
+    /// The value of the operand, or an `AsmError` if it
+    /// is not an expression or can't be evaluated
+    fn eval_with(&self, op: &Operand, what: &str, positional: bool) -> Result<i64, AsmError> {
+        match &op.kind {
+            OperandKind::Int(val) => Ok(*val),
+            OperandKind::Name(name) => self.value(name, op.span, positional),
+            OperandKind::Expr(expr) => expr.eval(op.span, &|name, span| self.value(name, span, positional)),
+            _ => Err(AsmError::new(op.span, &format!("expected {}", what))),
+        }
+    }
+}
+
+/// [Internal use only]
+/// Runs a directive during the first pass
+///
+/// # Arguments
+///
+/// * `line` - The parsed directive
+/// * `scope` - The names defined so far
+/// * `layout` - The lines of the instructions laid out so
+///   far, where `None` marks a padding `NOP`
+/// * `data` - The operands of the `.data` directives so far
+///
+/// # Returns
+///
+/// Nothing, or an `AsmError` if the directive or its operands are invalid
+fn directive<'a>(
+    line: &'a Line,
+    scope: &mut Scope,
+    layout: &mut Vec<Option<&'a Line>>,
+    data: &mut Vec<&'a Operand>,
+) -> Result<(), AsmError> {
+    let ops = &line.operands;
+    match line.mnemonic.to_ascii_lowercase().as_str() {
+        ".const" | ".equ" => {
+            arity(line, 2)?;
+            let name = match &ops[0].kind {
+                OperandKind::Name(name) => name,
+                _ => return Err(AsmError::new(ops[0].span, "expected a constant name")),
+            };
+            let val = scope.eval(&ops[1], "a constant value")?;
+            scope.define_constant(name, val, ops[0].span)?;
+        }
+        ".org" => {
+            arity(line, 1)?;
+            let addr = scope.eval_position(&ops[0], "an address")?;
+            let here = layout.len() as i64;
+            if addr < here || addr > MAX_ADDRESS {
+                return Err(AsmError::new(
+                    ops[0].span,
+                    &format!(
+                        "`.org` address {} is out of range (expected {} to {})",
+                        addr, here, MAX_ADDRESS
+                    ),
+                ));
+            }
+            layout.resize(addr as usize, None);
+        }
+        ".align" => {
+            arity(line, 1)?;
+            let align = scope.eval_position(&ops[0], "an alignment")?;
+            if !(1..=MAX_ADDRESS).contains(&align) {
+                return Err(AsmError::new(
+                    ops[0].span,
+                    &format!("alignment {} is out of range (expected 1 to {})", align, MAX_ADDRESS),
+                ));
+            }
+            let addr = (layout.len() as i64 + align - 1) / align * align;
+            if addr > MAX_ADDRESS {
+                return Err(AsmError::new(
+                    ops[0].span,
+                    &format!("aligned address {} is larger than {}", addr, MAX_ADDRESS),
+                ));
+            }
+            layout.resize(addr as usize, None);
+        }
+        ".data" => {
+            if ops.is_empty() {
+                return Err(AsmError::new(line.span, "`.data` takes at least 1 operand"));
+            }
+            data.extend(ops);
+        }
+        ".global" | ".extern" => {
+            // `Scope::new` has already gathered the exports and imports
+            if ops.is_empty() {
+                return Err(AsmError::new(
+                    line.span,
+                    &format!("`{}` takes at least 1 operand", line.mnemonic),
+                ));
+            }
+            if let Some(op) = ops.iter().find(|op| !matches!(op.kind, OperandKind::Name(_))) {
+                return Err(AsmError::new(op.span, "expected a label name"));
+            }
+        }
+        _ => {
+            return Err(AsmError::new(
+                line.span,
+                &format!("unknown directive `{}`", line.mnemonic),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// [Internal use only]
+/// Builds an instruction from a parsed line
+///
+/// # Arguments
+///
+/// * `line` - The parsed line
+/// * `scope` - The labels and constants defined by the program
+///
+/// # Returns
+///
+/// The instruction held by the line, or an `AsmError`
+/// if its mnemonic or operands are invalid
+fn build(line: &Line, scope: &Scope) -> Result<Instruction, AsmError> {
+    let ops = &line.operands;
+    let mnemonic = line.mnemonic.to_ascii_lowercase();
+    let instr = match mnemonic.as_str() {
+        "mov" => {
+            arity(line, 2)?;
+            let dest = register(&ops[1])?;
+            if is_literal(&ops[0]) {
+                MOVData::from_literal(literal(&ops[0], scope)?, dest).into()
+            } else {
+                MOVData::from_register(register(&ops[0])?, dest).into()
+            }
+        }
+        "exg" => {
+            arity(line, 2)?;
+            EXGData::new(register(&ops[0])?, register(&ops[1])?).into()
+        }
+        "add" => math(line, scope, ADDData::from_register, ADDData::from_literal)?,
+        "sub" => math(line, scope, SUBData::from_register, SUBData::from_literal)?,
+        "mul" => math(line, scope, MULData::from_register, MULData::from_literal)?,
+        "div" => math(line, scope, DIVData::from_register, DIVData::from_literal)?,
+        "mod" => math(line, scope, MODData::from_register, MODData::from_literal)?,
+        "min" => math(line, scope, MINData::from_register, MINData::from_literal)?,
+        "max" => math(line, scope, MAXData::from_register, MAXData::from_literal)?,
+        "and" => math(line, scope, ANDData::from_register, ANDData::from_literal)?,
+        "or" => math(line, scope, ORData::from_register, ORData::from_literal)?,
+        "xor" => math(line, scope, XORData::from_register, XORData::from_literal)?,
+        "not" => {
+            arity(line, 2)?;
+            NOTData::new(register(&ops[0])?, register(&ops[1])?).into()
+        }
+        "shl" => shift(line, scope, SHLData::MAX_AMOUNT, SHLData::new)?,
+        "shr" => shift(line, scope, SHRData::MAX_AMOUNT, SHRData::new)?,
+        "inc" => {
+            arity(line, 1)?;
+            INCData::new(register(&ops[0])?).into()
+        }
+        "dec" => {
+            arity(line, 1)?;
+            DECData::new(register(&ops[0])?).into()
+        }
+        "neg" => {
+            arity(line, 1)?;
+            NEGData::new(register(&ops[0])?).into()
+        }
+        "push" => {
+            arity(line, 1)?;
+            PUSHData::new(register(&ops[0])?).into()
+        }
+        "pop" => {
+            arity(line, 1)?;
+            POPData::new(register(&ops[0])?).into()
+        }
+        "jmp" => {
+            arity(line, 1)?;
+            JMPData::new(label(&ops[0], scope)?).into()
+        }
+        "call" => {
+            arity(line, 1)?;
+            CALLData::new(label(&ops[0], scope)?).into()
+        }
+        "ret" => {
+            arity(line, 0)?;
+            RETData::new().into()
+        }
+        "hlt" => {
+            arity(line, 0)?;
+            HLTData::new().into()
+        }
+        "nop" => {
+            arity(line, 0)?;
+            NOPData::new().into()
+        }
+        "chk" => {
+            arity(line, 1)?;
+            CHKData::new(register(&ops[0])?).into()
+        }
+        "cmp" => {
+            arity(line, 2)?;
+            let arg0 = register(&ops[0])?;
+            if is_literal(&ops[1]) {
+                CMPData::from_literal(arg0, literal(&ops[1], scope)?).into()
+            } else {
+                CMPData::from_register(arg0, register(&ops[1])?).into()
+            }
+        }
+        "js" => {
+            arity(line, 2)?;
+            JSData::new(flag(&ops[0])?, label(&ops[1], scope)?).into()
+        }
+        "jns" => {
+            arity(line, 2)?;
+            JNSData::new(flag(&ops[0])?, label(&ops[1], scope)?).into()
+        }
+        "jeq" | "jne" | "jgt" | "jge" | "jlt" | "jle" | "jz" | "jnz" => {
+            arity(line, 1)?;
+            let cond: Condition = mnemonic[1..].parse().unwrap();
+            JCCData::new(cond, label(&ops[0], scope)?).into()
+        }
+        "blk" => {
+            arity(line, 4)?;
+            let data = BLKData::new(
+                coordinate(&ops[0], scope)?,
+                coordinate(&ops[1], scope)?,
+                coordinate(&ops[2], scope)?,
+                block(&ops[3])?,
+            );
+            if !data.has_valid_coordinates() {
+                return Err(AsmError::new(
+                    line.span,
+                    "local coordinates cannot be mixed with other coordinates",
+                ));
+            }
+            data.into()
+        }
+        "ent" => {
+            arity(line, 1)?;
+            match &ops[0].kind {
+                OperandKind::Selector(sel) => ENTData::new(sel.clone()).into(),
+                _ => return Err(AsmError::new(ops[0].span, "expected a selector")),
+            }
+        }
+        _ => {
+            return Err(AsmError::new(
+                line.span,
+                &format!("unknown mnemonic `{}`", line.mnemonic),
+            ));
+        }
+    };
+
+    Ok(instr)
+}
+
+/// [Internal use only]
+/// Builds a math instruction, whose second argument
+/// may be either a register or a literal
+///
+/// # Arguments
+///
+/// * `line` - The parsed line
+/// * `scope` - The labels and constants defined by the program
+/// * `from_register` - Builds the instruction from its
+///   first argument, second argument, and destination registers
+/// * `from_literal` - Builds the instruction from its first
+///   argument register, literal, and destination register
+///
+/// # Returns
+///
+/// The instruction held by the line, or an `AsmError` if its operands are invalid
+fn math<T: Into<Instruction>>(
+    line: &Line,
+    scope: &Scope,
+    from_register: fn(Register, Register, Register) -> T,
+    from_literal: fn(Register, u32, Register) -> T,
+) -> Result<Instruction, AsmError> {
+    arity(line, 3)?;
+    let ops = &line.operands;
+    let arg0 = register(&ops[0])?;
+    let dest = register(&ops[2])?;
+    Ok(if is_literal(&ops[1]) {
+        from_literal(arg0, literal(&ops[1], scope)?, dest).into()
+    } else {
+        from_register(arg0, register(&ops[1])?, dest).into()
+    })
+}
+
+/// [Internal use only]
+/// Assembles one of the shift instructions
+///
+/// # Arguments
+///
+/// * `line` - The parsed line
+/// * `scope` - The labels and constants defined by the program
+/// * `max` - The largest valid shift amount
+/// * `new` - Builds the instruction from its argument
+///   register, shift amount, and destination register
+///
+/// # Returns
+///
+/// The instruction held by the line, or an `AsmError` if its operands are invalid
+fn shift<T: Into<Instruction>>(
+    line: &Line,
+    scope: &Scope,
+    max: u32,
+    new: fn(Register, u32, Register) -> T,
+) -> Result<Instruction, AsmError> {
+    arity(line, 3)?;
+    let ops = &line.operands;
+    let arg0 = register(&ops[0])?;
+    let dest = register(&ops[2])?;
+    let amount = scope.eval(&ops[1], "a shift amount")?;
+    if !(0..=max as i64).contains(&amount) {
+        return Err(AsmError::new(
+            ops[1].span,
+            &format!("shift amount {} is out of range (expected 0 to {})", amount, max),
+        ));
+    }
+    Ok(new(arg0, amount as u32, dest).into())
+}
+
+/// [Internal use only]
+/// Ensures that a line has the right number of operands
+///
+/// # Arguments
+///
+/// * `line` - The parsed line
+/// * `count` - The number of operands its instruction takes
+///
+/// # Returns
+///
+/// Nothing, or an `AsmError` if the operand count is wrong
+fn arity(line: &Line, count: usize) -> Result<(), AsmError> {
+    if line.operands.len() == count {
+        Ok(())
+    } else {
+        Err(AsmError::new(
+            line.span,
+            &format!(
+                "`{}` takes {} operand(s), but {} were given",
+                line.mnemonic,
+                count,
+                line.operands.len()
+            ),
+        ))
+    }
+}
+
+/// [Internal use only]
+/// Gets a register operand
+///
+/// # Arguments
+///
+/// * `op` - The operand
+///
+/// # Returns
+///
+/// The register, or an `AsmError` if the operand is not a userspace register
+fn register(op: &Operand) -> Result<Register, AsmError> {
+    match op.kind {
+        OperandKind::Register(Register::SR) | OperandKind::Register(Register::SWP) => Err(
+            AsmError::new(op.span, "the stack and swap registers cannot be used as operands"),
+        ),
+        OperandKind::Register(r) => Ok(r),
+        _ => Err(AsmError::new(op.span, "expected a register")),
+    }
+}
+
+/// [Internal use only]
+/// Gets a flag operand
+///
+/// # Arguments
+///
+/// * `op` - The operand
+///
+/// # Returns
+///
+/// The flag, or an `AsmError` if the operand is not a flag
+fn flag(op: &Operand) -> Result<Flag, AsmError> {
+    match op.kind {
+        OperandKind::Flag(f) => Ok(f),
+        _ => Err(AsmError::new(op.span, "expected a flag")),
+    }
+}
+
+/// [Internal use only]
+/// Gets a label operand
+///
+/// A label operand may also be a constant expression, such
+/// as `table + 2`, or a constant that holds a label ID.
+///
+/// # Arguments
+///
+/// * `op` - The operand
+/// * `scope` - The labels and constants defined by the program
+///
+/// # Returns
+///
+/// The label ID, or an `AsmError` if the operand is not a defined label
+fn label(op: &Operand, scope: &Scope) -> Result<u32, AsmError> {
+    let id = match &op.kind {
+        // defined labels take priority over raw IDs
+        OperandKind::Name(name) if scope.is_extern(name, op.span) => {
+            // the linker fills in the ID of an extern label
+            if scope.linking {
+                return Ok(0);
+            }
+            return Err(AsmError::new(
+                op.span,
+                &format!("`.extern` label `{}` can only be resolved by the linker", name),
+            ));
+        }
+        OperandKind::Name(name) => match scope.label_id(name, op.span).or_else(|| raw_label_id(name)) {
+            Some(id) => return Ok(id),
+            None if scope.constants.contains_key(name) => scope.eval_position(op, "a label")?,
+            None => return Err(AsmError::new(op.span, &format!("undefined label `{}`", name))),
+        },
+        OperandKind::Expr(_) => scope.eval_position(op, "a label")?,
+        _ => return Err(AsmError::new(op.span, "expected a label")),
+    };
+    u32::try_from(id).map_err(|_| {
+        AsmError::new(op.span, &format!("label ID {} does not fit in 32 bits", id))
+    })
+}
+
+/// [Internal use only]
+/// Parses a raw label ID, spelled `L<id>`
+///
+/// # Arguments
+///
+/// * `name` - The name to parse
+///
+/// # Returns
+///
+/// The label ID, or `None` if `name` is not a raw label ID
+fn raw_label_id(name: &str) -> Option<u32> {
+    name.strip_prefix('L')
+        .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+        .and_then(|id| id.parse().ok())
+}
+
+/// [Internal use only]
+/// Checks whether an operand is a literal rather than a register
+///
+/// # Arguments
+///
+/// * `op` - The operand
+///
+/// # Returns
+///
+/// Whether the operand is an integer, a name, or an expression
+fn is_literal(op: &Operand) -> bool {
+    matches!(op.kind, OperandKind::Int(_) | OperandKind::Name(_) | OperandKind::Expr(_))
+}
+
+/// [Internal use only]
+/// Gets a 32-bit literal operand
+///
+/// Negative literals are stored in two's complement.
+///
+/// # Arguments
+///
+/// * `op` - The operand
+/// * `scope` - The labels and constants defined by the program
+///
+/// # Returns
+///
+/// The literal, or an `AsmError` if the operand is
+/// not a literal or does not fit in 32 bits
+fn literal(op: &Operand, scope: &Scope) -> Result<u32, AsmError> {
+    let val = scope.eval(op, "a literal")?;
+    if val >= i32::MIN as i64 && val <= u32::MAX as i64 {
+        return Ok(val as u32);
+    }
+    let what = match &op.kind {
+        OperandKind::Name(name) => format!("constant `{}` ({})", name, val),
+        OperandKind::Expr(_) => format!("expression value {}", val),
+        _ => format!("literal {}", val),
+    };
+    Err(AsmError::new(
+        op.span,
+        &format!("{} does not fit in 32 bits", what),
+    ))
+}
+
+/// [Internal use only]
+/// Gets a coordinate operand
+///
+/// # Arguments
+///
+/// * `op` - The operand
+/// * `scope` - The labels and constants defined by the program
+///
+/// # Returns
+///
+/// The coordinate, or an `AsmError` if the operand is
+/// not a coordinate or its offset does not fit in 32 bits
+fn coordinate(op: &Operand, scope: &Scope) -> Result<Coordinate, AsmError> {
+    let (val, make): (i64, fn(i32) -> Coordinate) = match op.kind {
+        OperandKind::Int(_) | OperandKind::Name(_) | OperandKind::Expr(_) => {
+            (scope.eval(op, "a coordinate")?, Coordinate::Absolute)
+        }
+        OperandKind::Relative(val) => (val, Coordinate::Relative),
+        OperandKind::Local(val) => (val, Coordinate::Local),
+        OperandKind::Register(_) => return Ok(Coordinate::Register(register(op)?)),
+        _ => return Err(AsmError::new(op.span, "expected a coordinate")),
+    };
+    if val < i32::MIN as i64 || val > i32::MAX as i64 {
+        return Err(AsmError::new(
+            op.span,
+            &format!("coordinate {} does not fit in 32 bits", val),
+        ));
+    }
+    Ok(make(val as i32))
+}
+
+/// [Internal use only]
+/// Gets a block operand
+///
+/// A bare name such as `stone` is a block in the `minecraft` namespace.
+///
+/// # Arguments
+///
+/// * `op` - The operand
+///
+/// # Returns
+///
+/// The block, or an `AsmError` if the operand is not a block
+fn block(op: &Operand) -> Result<BlockState, AsmError> {
+    match &op.kind {
+        OperandKind::Block(block) => Ok(block.clone()),
+        OperandKind::Name(name) => Ok(BlockState::new(name)),
+        _ => Err(AsmError::new(op.span, "expected a block")),
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the function into scope
+    use super::*;
+    use super::super::Span;
+
+    // tests assembling a small program
+    #[test]
+    fn test_assemble() {
+        let src = "mov 50, r0 ; load\nMOV r0, r1\n\nadd r0, r1, r2\njs flag_Zero, L0\n";
+        let instrs = assemble(src).unwrap().into_instructions();
+        assert_eq!(
+            instrs,
+            vec![
+                MOVData::from_literal(50, Register::R0).into(),
+                MOVData::from_register(Register::R0, Register::R1).into(),
+                ADDData::new(Register::R0, Register::R1, Register::R2).into(),
+                JSData::new(Flag::Zero, 0).into(),
+            ]
+        );
+    }
+
+    // tests that printed instructions assemble back into themselves
+    #[test]
+    fn test_round_trip() {
+        let instrs: Vec<Instruction> = vec![
+            MOVData::from_literal(0xFFFFFFFF, Register::R0).into(),
+            MOVData::from_register(Register::R15, Register::R7).into(),
+            EXGData::new(Register::R3, Register::R9).into(),
+            ADDData::new(Register::R0, Register::R1, Register::R2).into(),
+            SUBData::new(Register::R3, Register::R4, Register::R5).into(),
+            MULData::new(Register::R6, Register::R7, Register::R8).into(),
+            DIVData::new(Register::R9, Register::R10, Register::R11).into(),
+            PUSHData::new(Register::R12).into(),
+            POPData::new(Register::R13).into(),
+            JMPData::new(42).into(),
+            CHKData::new(Register::R14).into(),
+            CMPData::new(Register::R15, Register::R0).into(),
+            ADDData::from_literal(Register::R1, 1, Register::R1).into(),
+            SUBData::from_literal(Register::R2, 0xFFFFFFFF, Register::R3).into(),
+            MULData::from_literal(Register::R4, 10, Register::R5).into(),
+            DIVData::from_literal(Register::R6, 0x10000, Register::R7).into(),
+            CMPData::from_literal(Register::R8, 42).into(),
+            MODData::new(Register::R0, Register::R1, Register::R2).into(),
+            MINData::from_literal(Register::R3, 0xFFFFFFFF, Register::R4).into(),
+            MAXData::new(Register::R5, Register::R6, Register::R7).into(),
+            ANDData::new(Register::R0, Register::R1, Register::R2).into(),
+            ORData::from_literal(Register::R3, 0x80000000, Register::R4).into(),
+            XORData::new(Register::R5, Register::R6, Register::R7).into(),
+            NOTData::new(Register::R8, Register::R9).into(),
+            SHLData::new(Register::R10, 0, Register::R11).into(),
+            SHRData::new(Register::R12, 31, Register::R13).into(),
+            CALLData::new(3).into(),
+            RETData::new().into(),
+            HLTData::new().into(),
+            NOPData::new().into(),
+            INCData::new(Register::R1).into(),
+            DECData::new(Register::R2).into(),
+            NEGData::new(Register::R3).into(),
+            JSData::new(Flag::Block, 7).into(),
+            JCCData::new(Condition::Equal, 1).into(),
+            JCCData::new(Condition::LessOrEqual, 2).into(),
+            JCCData::new(Condition::Zero, 3).into(),
+            JNSData::new(Flag::Entity, 0).into(),
+            BLKData::new(
+                Coordinate::Absolute(-64),
+                Coordinate::Relative(0),
+                Coordinate::Register(Register::R1),
+                BlockState::new("oak_stairs").with_property("facing", "north"),
+            )
+            .into(),
+            BLKData::new(
+                Coordinate::Local(1),
+                Coordinate::Local(0),
+                Coordinate::Local(-2),
+                BlockState::new("mod:ore"),
+            )
+            .into(),
+            ENTData::new("@a[tag=!afk,distance=3..,sort=random]".parse().unwrap()).into(),
+        ];
+        let src: String = instrs.iter().map(|i| format!("{}\n", i)).collect();
+        assert_eq!(assemble(&src).unwrap().instructions(), &instrs[..]);
+    }
+
+    // tests negative literals
+    #[test]
+    fn test_negative_literal() {
+        let instrs = assemble("mov -1, r3").unwrap().into_instructions();
+        assert_eq!(instrs, vec![MOVData::from_literal(0xFFFFFFFF, Register::R3).into()]);
+    }
+
+    // tests resolving symbolic labels
+    #[test]
+    fn test_labels() {
+        let src = "start:\n  mov 10, r0\nloop: chk r0\n  js flag_Zero, end\n  jmp loop\nend:\n";
+        let prog = assemble(src).unwrap();
+        assert_eq!(
+            prog.instructions(),
+            &[
+                MOVData::from_literal(10, Register::R0).into(),
+                CHKData::new(Register::R0).into(),
+                JSData::new(Flag::Zero, 4).into(),
+                JMPData::new(1).into(),
+            ][..]
+        );
+        assert_eq!(prog.symbols().id("start"), Some(0));
+        assert_eq!(prog.symbols().id("end"), Some(4));
+        assert_eq!(prog.symbols().name(1), Some("loop"));
+    }
+
+    // tests duplicate and undefined labels
+    #[test]
+    fn test_label_errors() {
+        let err1 = assemble("a: chk r0\na: chk r1").unwrap_err();
+        let err2 = assemble("chk r0\njmp nowhere").unwrap_err();
+        assert_eq!(err1.span(), Span::new(2, 1, 1));
+        assert_eq!(err1.message(), "label `a` is already defined at 1:1");
+        assert_eq!(err2.span(), Span::new(2, 5, 7));
+        assert_eq!(err2.message(), "undefined label `nowhere`");
+    }
+
+    // tests constants and expressions in operands
+    #[test]
+    fn test_constants() {
+        let src = ".const SIZE, 4 * 4\n.equ MASK, (1 << SIZE) - 1\n\
+                   start: mov MASK, r0\nand r0, ~MASK & 0xFF | 1, r1\nshl r0, SIZE / 2, r2\n\
+                   blk SIZE, -SIZE, ~, stone\ncmp r0, end - start\njmp start + 1\n\
+                   end:\n";
+        let prog = assemble(src).unwrap();
+        assert_eq!(
+            prog.instructions(),
+            &[
+                MOVData::from_literal(0xFFFF, Register::R0).into(),
+                ANDData::from_literal(Register::R0, 1, Register::R1).into(),
+                SHLData::new(Register::R0, 8, Register::R2).into(),
+                BLKData::new(
+                    Coordinate::Absolute(16),
+                    Coordinate::Absolute(-16),
+                    Coordinate::Relative(0),
+                    BlockState::new("stone"),
+                )
+                .into(),
+                CMPData::from_literal(Register::R0, 6).into(),
+                JMPData::new(1).into(),
+            ][..]
+        );
+        assert!(prog.data().is_empty());
+    }
+
+    // tests padding with `.org` and `.align`, and declaring data
+    #[test]
+    fn test_layout_directives() {
+        let src = "jmp main\n.org 3\nmain: chk r0\n.align 4\ntable: .data table, main * 2\nnop\n.align 2\n.data -1\n";
+        let prog = assemble(src).unwrap();
+        let nop: Instruction = NOPData::new().into();
+        assert_eq!(
+            prog.instructions(),
+            &[
+                JMPData::new(3).into(),
+                nop.clone(),
+                nop.clone(),
+                CHKData::new(Register::R0).into(),
+                nop.clone(),
+                nop,
+            ][..]
+        );
+        assert_eq!(prog.symbols().id("main"), Some(3));
+        assert_eq!(prog.symbols().id("table"), Some(4));
+        assert_eq!(prog.data(), &[4, 6, 0xFFFFFFFF]);
+    }
+
+    // tests invalid directives and constants
+    #[test]
+    fn test_directive_errors() {
+        let err1 = assemble(".const BIG, 1 << 32\nmov BIG, r0").unwrap_err();
+        let err2 = assemble("mov 0xFFFF * 0x10001 + 1, r0").unwrap_err();
+        let err3 = assemble(".const A, 1\n.const A, 2").unwrap_err();
+        let err4 = assemble("a: nop\n.const a, 2").unwrap_err();
+        let err5 = assemble(".const A, 1\nA: nop").unwrap_err();
+        let err6 = assemble(".const A, end\nend:").unwrap_err();
+        let err7 = assemble("nop\nnop\n.org 1").unwrap_err();
+        let err8 = assemble(".align 0").unwrap_err();
+        let err9 = assemble(".bss 4").unwrap_err();
+        let err10 = assemble("mov UNDEFINED + 1, r0").unwrap_err();
+        let err11 = assemble(".const r0, 1").unwrap_err();
+        let err12 = assemble(".data").unwrap_err();
+        let err13 = assemble("jmp -1 + 0").unwrap_err();
+        let err14 = assemble(".const N, 40\nshl r0, N, r1").unwrap_err();
+        let err15 = assemble("a: mov a - 0x80000001, r0").unwrap_err();
+        assert_eq!(err1.message(), "constant `BIG` (4294967296) does not fit in 32 bits");
+        assert_eq!(err1.span(), Span::new(2, 5, 3));
+        assert_eq!(err2.message(), "literal 4294967296 does not fit in 32 bits");
+        assert_eq!(err2.span(), Span::new(1, 5, 20));
+        assert_eq!(err3.message(), "constant `A` is already defined at 1:8");
+        assert_eq!(err4.message(), "`a` is already defined as a label at 1:1");
+        assert_eq!(err5.message(), "`A` is already defined as a constant at 1:8");
+        assert_eq!(err6.message(), "label `end` must be defined above this directive");
+        assert_eq!(err6.span(), Span::new(1, 11, 3));
+        assert_eq!(err7.message(), "`.org` address 1 is out of range (expected 2 to 16777215)");
+        assert_eq!(err8.message(), "alignment 0 is out of range (expected 1 to 16777215)");
+        assert_eq!(err9.message(), "unknown directive `.bss`");
+        assert_eq!(err10.message(), "undefined symbol `UNDEFINED`");
+        assert_eq!(err10.span(), Span::new(1, 5, 9));
+        assert_eq!(err11.message(), "expected a constant name");
+        assert_eq!(err12.message(), "`.data` takes at least 1 operand");
+        assert_eq!(err13.message(), "expected a label");
+        assert_eq!(err14.message(), "shift amount 40 is out of range (expected 0 to 31)");
+        assert_eq!(err15.message(), "expression value -2147483649 does not fit in 32 bits");
+    }
+
+    // tests expanding macros with local labels
+    #[test]
+    fn test_macros() {
+        let src = ".macro countdown reg, n\n  mov n, reg\nloop: dec reg\n  chk reg\n  jns flag_Zero, loop\n.endm\n\
+                   countdown r0, 3\nlater: countdown r1, LIMIT\n.const LIMIT, 5\n";
+        let prog = assemble(src).unwrap();
+        assert_eq!(
+            prog.instructions(),
+            &[
+                MOVData::from_literal(3, Register::R0).into(),
+                DECData::new(Register::R0).into(),
+                CHKData::new(Register::R0).into(),
+                JNSData::new(Flag::Zero, 1).into(),
+                MOVData::from_literal(5, Register::R1).into(),
+                DECData::new(Register::R1).into(),
+                CHKData::new(Register::R1).into(),
+                JNSData::new(Flag::Zero, 5).into(),
+            ][..]
+        );
+        assert_eq!(prog.symbols().id("later"), Some(4));
+        assert_eq!(prog.symbols().id("loop"), None);
+    }
+
+    // tests that errors in expanded macros trace back to their calls
+    #[test]
+    fn test_macro_errors() {
+        let src = ".macro load v\n  mov v, r0\n  pusj r0\n.endm\n.macro twice v\n  load v\n  load v\n.endm\ntwice 1\n";
+        let err = assemble(src).unwrap_err();
+        assert_eq!(err.message(), "unknown mnemonic `pusj`");
+        assert_eq!((err.span().line(), err.span().col()), (3, 3));
+        assert_eq!(
+            err.to_string(),
+            "3:3: unknown mnemonic `pusj`\n  \
+             in macro `load` (defined at 1:8), called at 6:3\n  \
+             in macro `twice` (defined at 5:8), called at 9:1"
+        );
+
+        // arguments keep the location of the call
+        let err = assemble(".macro load v\n  mov v, r0\n.endm\nload flag_Zero\n").unwrap_err();
+        assert_eq!(err.message(), "expected a register");
+        assert_eq!(err.span(), Span::new(4, 6, 9));
+        assert!(err.notes().is_empty());
+    }
+
+    // tests labels that are local to their files or exported by `.global`
+    #[test]
+    fn test_include() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(
+            "src/main.asm",
+            ".include \"lib/math.asm\"\nloop: call square\njmp loop\n.include \"lib/util.asm\"\n",
+        );
+        fs.add_file(
+            "src/lib/math.asm",
+            ".macro bump r\n  inc r\n.endm\n.global square\nsquare: neg r0\nloop: ret\n",
+        );
+        fs.add_file("src/lib/util.asm", ".include \"math.asm\"\nloop: bump r0\njmp loop\n");
+        let program = assemble_file(&fs, "./src/main.asm").unwrap();
+        let labels: Vec<(&str, u32)> = program.symbols().iter().collect();
+        assert_eq!(
+            labels,
+            vec![("square", 0), ("loop@src/lib/math.asm", 1), ("loop", 2), ("loop@src/lib/util.asm", 4)]
+        );
+        assert_eq!(
+            &program.instructions()[2..],
+            &[
+                CALLData::new(0).into(),
+                JMPData::new(2).into(),
+                INCData::new(Register::R0).into(),
+                JMPData::new(4).into(),
+            ][..]
+        );
+
+        // the main file's labels are local too, unless they are exported
+        fs.add_file("main.asm", ".include \"lib.asm\"\nstart: hlt\n");
+        fs.add_file("lib.asm", "jmp start\n");
+        let err = assemble_file(&fs, "main.asm").unwrap_err();
+        assert_eq!(err.to_string(), "lib.asm:1:5: undefined label `start`");
+        fs.add_file("main.asm", ".include \"lib.asm\"\n.global start\nstart: hlt\n");
+        let program = assemble_file(&fs, "main.asm").unwrap();
+        assert_eq!(program.instructions()[0], JMPData::new(1).into());
+    }
+
+    // tests errors in programs that span several files
+    #[test]
+    fn test_include_errors() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file("a.asm", ".include \"b.asm\"\nx: nop\n");
+        fs.add_file("b.asm", ".global x\nx: nop\n");
+        fs.add_file("c.asm", ".global y\nnop\n");
+        fs.add_file("d.asm", ".global 5\n");
+        fs.add_file("e.asm", "nop\n.include \"a.asm\" nop\n");
+        let err1 = assemble_file(&fs, "a.asm").unwrap_err();
+        let err2 = assemble_file(&fs, "c.asm").unwrap_err();
+        let err3 = assemble_file(&fs, "d.asm").unwrap_err();
+        let err4 = assemble_file(&fs, "e.asm").unwrap_err();
+        let err5 = assemble_file(&fs, "g.asm").unwrap_err();
+        assert_eq!(err1.to_string(), "a.asm:2:1: label `x` is already defined at b.asm:2:1");
+        assert_eq!(err2.to_string(), "c.asm:1:9: `.global` label `y` is not defined in this file");
+        assert_eq!(err3.message(), "expected a label name");
+        assert_eq!(err4.to_string(), "e.asm:2:1: `.include` takes one file path in double quotes");
+        assert_eq!(err5.to_string(), "g.asm:1:1: cannot read `g.asm`: no such file");
+
+        // macro traces name the files of the definition and the call
+        fs.add_file("m.asm", ".include \"mac.asm\"\nbad\n");
+        fs.add_file("mac.asm", ".macro bad\n  pusj r0\n.endm\n");
+        let err = assemble_file(&fs, "m.asm").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "mac.asm:2:3: unknown mnemonic `pusj`\n  in macro `bad` (defined at mac.asm:1:8), called at m.asm:2:1"
+        );
+    }
+
+    // tests objects with exported, local and extern labels
+    #[test]
+    fn test_object() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file("obj.asm", ".extern print\n.global start\nstart: call print\nloop: jmp loop\n.data 5\n");
+        let obj = assemble_object(&fs, "./obj.asm").unwrap();
+        assert_eq!(obj.name(), "obj.asm");
+        assert_eq!(obj.instr_count(), 2);
+        assert_eq!(obj.code(), &[0x43000000.into(), 0x40000001.into()][..]);
+        assert_eq!(obj.data(), &[5]);
+        assert_eq!(
+            obj.symbols(),
+            &[
+                ObjectSymbol::new("start", Binding::Global, 0, Span::new(3, 1, 5)),
+                ObjectSymbol::new("loop", Binding::Local, 1, Span::new(4, 1, 4)),
+                ObjectSymbol::new("print", Binding::Extern, 0, Span::new(1, 9, 5)),
+            ][..]
+        );
+        assert_eq!(
+            obj.relocations(),
+            &[
+                Relocation::new(0, RelocationField::Wide, Some(2)),
+                Relocation::new(1, RelocationField::Wide, None),
+            ][..]
+        );
+
+        // extern labels need the linker, and can only be jump targets
+        fs.add_file("bad1.asm", ".extern f\ncall f\n");
+        fs.add_file("bad2.asm", ".extern f\njmp f + 1\n");
+        fs.add_file("bad3.asm", ".extern f\nf: nop\n");
+        let err1 = assemble_file(&fs, "bad1.asm").unwrap_err();
+        let err2 = assemble_object(&fs, "bad2.asm").unwrap_err();
+        let err3 = assemble_object(&fs, "bad3.asm").unwrap_err();
+        assert_eq!(err1.to_string(), "bad1.asm:2:6: `.extern` label `f` can only be resolved by the linker");
+        assert_eq!(err2.message(), "`.extern` label `f` cannot be used in an expression");
+        assert_eq!(err3.to_string(), "bad3.asm:1:9: `.extern` label `f` is defined at bad3.asm:2:1");
+    }
+
+    // tests block check errors
+    #[test]
+    fn test_blk_errors() {
+        let err1 = assemble("blk ^, ~, ^, stone").unwrap_err();
+        let err2 = assemble("blk 0, 0, ~3000000000, stone").unwrap_err();
+        let err3 = assemble("blk 0, 0, flag_Zero, stone").unwrap_err();
+        let err4 = assemble("blk 0, 0, 0, r0").unwrap_err();
+        assert_eq!(err1.message(), "local coordinates cannot be mixed with other coordinates");
+        assert_eq!(err2.span(), Span::new(1, 11, 11));
+        assert_eq!(err2.message(), "coordinate 3000000000 does not fit in 32 bits");
+        assert_eq!(err3.message(), "expected a coordinate");
+        assert_eq!(err4.message(), "expected a block");
+
+        // huge offsets are errors rather than overflows
+        let err5 = assemble("blk ~-9223372036854775808, 0, 0, stone").unwrap_err();
+        let err6 = assemble("blk ~18446744073709551615, 0, 0, stone").unwrap_err();
+        assert_eq!(err5.message(), "coordinate -9223372036854775808 does not fit in 32 bits");
+        assert_eq!(err6.message(), "integer literal 18446744073709551615 does not fit in 64 bits");
+    }
+
+    // tests that errors point at the offending token
+    #[test]
+    fn test_errors() {
+        let err1 = assemble("mov 5, r0\nmvo 5, r0").unwrap_err();
+        let err2 = assemble("add r0, r1").unwrap_err();
+        let err3 = assemble("push sr").unwrap_err();
+        let err4 = assemble("js r0, L1").unwrap_err();
+        let err5 = assemble("mov 0x100000000, r0").unwrap_err();
+        let err6 = assemble("shl r0, 32, r1").unwrap_err();
+        let err7 = assemble("shr r0, r1, r2").unwrap_err();
+        assert_eq!(err1.span(), Span::new(2, 1, 3));
+        assert_eq!(err1.message(), "unknown mnemonic `mvo`");
+        assert_eq!(err2.message(), "`add` takes 3 operand(s), but 2 were given");
+        assert_eq!(err3.span(), Span::new(1, 6, 2));
+        assert_eq!(err4.message(), "expected a flag");
+        assert_eq!(err5.message(), "literal 4294967296 does not fit in 32 bits");
+        assert_eq!(err6.span(), Span::new(1, 9, 2));
+        assert_eq!(err6.message(), "shift amount 32 is out of range (expected 0 to 31)");
+        assert_eq!(err7.message(), "expected a shift amount");
+    }
+}
+
+// end of file
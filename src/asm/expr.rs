@@ -0,0 +1,213 @@
+/*
+ * expr.rs
+ * Constant expressions in assembly source
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::error::AsmError;
+use super::Span;
+
+/// [Internal use only]
+/// A binary operator in a constant expression
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BinaryOp {
+    /// `+`
+    Add,
+
+    /// `-`
+    Sub,
+
+    /// `*`
+    Mul,
+
+    /// `/`, which rounds toward negative infinity like `DIV`
+    Div,
+
+    /// `%`, which takes the sign of the divisor like `MOD`
+    Mod,
+
+    /// `&`
+    And,
+
+    /// `|`
+    Or,
+
+    /// `^`
+    Xor,
+
+    /// `<<`
+    Shl,
+
+    /// `>>`, which shifts in copies of the sign bit
+    Shr,
+}
+
+/// [Internal use only]
+/// A constant expression, which is evaluated with 64-bit
+/// integers and checked for overflow at every step
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Expr {
+    /// An integer literal
+    Int(i64),
+
+    /// A constant or a label, and the location of its name
+    Name(String, Span),
+
+    /// A negation, written `-<expr>`
+    Neg(Box<Expr>),
+
+    /// A bitwise complement, written `~<expr>`
+    Not(Box<Expr>),
+
+    /// A binary operation
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+// implementation
+impl Expr {
+    /// Checks whether the expression can be evaluated
+    /// without looking up any names
+    ///
+    /// # Returns
+    ///
+    /// Whether the expression refers to no constants or labels
+    pub(crate) fn is_literal(&self) -> bool {
+        match self {
+            Expr::Int(_) => true,
+            Expr::Name(..) => false,
+            Expr::Neg(arg) | Expr::Not(arg) => arg.is_literal(),
+            Expr::Binary(_, lhs, rhs) => lhs.is_literal() && rhs.is_literal(),
+        }
+    }
+
+    /// Evaluates the expression
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The location of the whole expression, used in error messages
+    /// * `lookup` - Gets the value of a name, given the name and its location
+    ///
+    /// # Returns
+    ///
+    /// The value of the expression, or an `AsmError` if a name can't be
+    /// resolved or the expression overflows or divides by zero
+    pub(crate) fn eval(
+        &self,
+        span: Span,
+        lookup: &dyn Fn(&str, Span) -> Result<i64, AsmError>,
+    ) -> Result<i64, AsmError> {
+        let overflow = || AsmError::new(span, "constant expression overflows 64 bits");
+        match self {
+            Expr::Int(val) => Ok(*val),
+            Expr::Name(name, name_span) => lookup(name, *name_span),
+            Expr::Neg(arg) => arg.eval(span, lookup)?.checked_neg().ok_or_else(overflow),
+            Expr::Not(arg) => Ok(!arg.eval(span, lookup)?),
+            Expr::Binary(op, lhs, rhs) => {
+                let a = lhs.eval(span, lookup)?;
+                let b = rhs.eval(span, lookup)?;
+                if matches!(op, BinaryOp::Div | BinaryOp::Mod) && b == 0 {
+                    return Err(AsmError::new(span, "division by zero in constant expression"));
+                }
+                if matches!(op, BinaryOp::Shl | BinaryOp::Shr) && !(0..64).contains(&b) {
+                    return Err(AsmError::new(
+                        span,
+                        &format!("cannot shift by {} in constant expression (expected 0 to 63)", b),
+                    ));
+                }
+                let val = match op {
+                    BinaryOp::Add => a.checked_add(b),
+                    BinaryOp::Sub => a.checked_sub(b),
+                    BinaryOp::Mul => a.checked_mul(b),
+                    BinaryOp::Div => a.checked_div(b).map(|q| {
+                        if a % b != 0 && (a < 0) != (b < 0) {
+                            q - 1
+                        } else {
+                            q
+                        }
+                    }),
+                    BinaryOp::Mod => a.checked_rem(b).map(|r| {
+                        if r != 0 && (r < 0) != (b < 0) {
+                            r + b
+                        } else {
+                            r
+                        }
+                    }),
+                    BinaryOp::And => Some(a & b),
+                    BinaryOp::Or => Some(a | b),
+                    BinaryOp::Xor => Some(a ^ b),
+                    BinaryOp::Shl => Some(a << b).filter(|val| val >> b == a),
+                    BinaryOp::Shr => Some(a >> b),
+                };
+                val.ok_or_else(overflow)
+            }
+        }
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the type into scope
+    use super::*;
+
+    /// Builds a binary expression
+    fn bin(op: BinaryOp, lhs: Expr, rhs: Expr) -> Expr {
+        Expr::Binary(op, Box::new(lhs), Box::new(rhs))
+    }
+
+    /// Evaluates an expression in which `x` is 10
+    fn eval(expr: &Expr) -> Result<i64, AsmError> {
+        expr.eval(Span::new(1, 1, 1), &|name, span| match name {
+            "x" => Ok(10),
+            _ => Err(AsmError::new(span, &format!("undefined symbol `{}`", name))),
+        })
+    }
+
+    // tests evaluating operators and names
+    #[test]
+    fn test_eval() {
+        let x = || Expr::Name("x".to_owned(), Span::new(1, 1, 1));
+        assert_eq!(eval(&bin(BinaryOp::Add, x(), Expr::Int(5))).unwrap(), 15);
+        assert_eq!(eval(&bin(BinaryOp::Sub, Expr::Int(5), x())).unwrap(), -5);
+        assert_eq!(eval(&bin(BinaryOp::Mul, x(), x())).unwrap(), 100);
+        assert_eq!(eval(&bin(BinaryOp::Div, Expr::Int(-7), Expr::Int(2))).unwrap(), -4);
+        assert_eq!(eval(&bin(BinaryOp::Mod, Expr::Int(-7), Expr::Int(2))).unwrap(), 1);
+        assert_eq!(eval(&bin(BinaryOp::Mod, Expr::Int(7), Expr::Int(-2))).unwrap(), -1);
+        assert_eq!(eval(&bin(BinaryOp::And, x(), Expr::Int(6))).unwrap(), 2);
+        assert_eq!(eval(&bin(BinaryOp::Or, x(), Expr::Int(5))).unwrap(), 15);
+        assert_eq!(eval(&bin(BinaryOp::Xor, x(), Expr::Int(3))).unwrap(), 9);
+        assert_eq!(eval(&bin(BinaryOp::Shl, Expr::Int(1), Expr::Int(31))).unwrap(), 1 << 31);
+        assert_eq!(eval(&bin(BinaryOp::Shl, Expr::Int(-1), Expr::Int(63))).unwrap(), i64::MIN);
+        assert_eq!(eval(&bin(BinaryOp::Shl, Expr::Int(-3), Expr::Int(2))).unwrap(), -12);
+        assert_eq!(eval(&bin(BinaryOp::Shr, Expr::Int(-8), Expr::Int(1))).unwrap(), -4);
+        assert_eq!(eval(&Expr::Not(Box::new(Expr::Int(0)))).unwrap(), -1);
+        assert_eq!(eval(&Expr::Neg(Box::new(x()))).unwrap(), -10);
+    }
+
+    // tests evaluating invalid expressions
+    #[test]
+    fn test_eval_errors() {
+        let y = Expr::Name("y".to_owned(), Span::new(1, 3, 1));
+        let err1 = eval(&bin(BinaryOp::Add, Expr::Int(1), y)).unwrap_err();
+        let err2 = eval(&bin(BinaryOp::Div, Expr::Int(1), Expr::Int(0))).unwrap_err();
+        let err3 = eval(&bin(BinaryOp::Mul, Expr::Int(i64::MAX), Expr::Int(2))).unwrap_err();
+        let err4 = eval(&bin(BinaryOp::Shl, Expr::Int(1), Expr::Int(64))).unwrap_err();
+        let err5 = eval(&bin(BinaryOp::Shl, Expr::Int(2), Expr::Int(63))).unwrap_err();
+        let err6 = eval(&bin(BinaryOp::Shl, Expr::Int(1), Expr::Int(63))).unwrap_err();
+        assert_eq!(err1.message(), "undefined symbol `y`");
+        assert_eq!(err1.span(), Span::new(1, 3, 1));
+        assert_eq!(err2.message(), "division by zero in constant expression");
+        assert_eq!(err3.message(), "constant expression overflows 64 bits");
+        assert_eq!(err4.message(), "cannot shift by 64 in constant expression (expected 0 to 63)");
+        assert_eq!(err5.message(), "constant expression overflows 64 bits");
+        assert_eq!(err6.message(), "constant expression overflows 64 bits");
+    }
+}
+
+// end of file
@@ -0,0 +1,168 @@
+/*
+ * file_system.rs
+ * Sources of assembly files for `.include`
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+
+/// A source of assembly files, which lets the assembler
+/// follow `.include` directives without depending on
+/// where the files are stored
+///
+/// Paths are `/`-separated, and an included path is
+/// resolved against the directory of the including file
+/// before it is passed to `read`.
+pub trait FileSystem {
+    /// Reads a source file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The `/`-separated path of the file
+    ///
+    /// # Returns
+    ///
+    /// The contents of the file, or the `io::Error` that stopped the read
+    fn read(&self, path: &str) -> io::Result<String>;
+}
+
+/// A `FileSystem` that reads files from disk, with
+/// relative paths resolved against the working directory
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiskFileSystem;
+
+// FileSystem implementation
+impl FileSystem for DiskFileSystem {
+    fn read(&self, path: &str) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+}
+
+/// A `FileSystem` whose files are held in memory
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryFileSystem {
+    /// The contents of each file, keyed by its normalized path
+    files: HashMap<String, String>,
+}
+
+// implementation
+impl MemoryFileSystem {
+    /// Creates a new, empty `MemoryFileSystem` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `MemoryFileSystem` with no files
+    pub fn new() -> Self {
+        MemoryFileSystem::default()
+    }
+
+    /// Adds a file, replacing any file at the same path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The `/`-separated path of the file
+    /// * `contents` - The contents of the file
+    pub fn add_file(&mut self, path: &str, contents: &str) {
+        self.files.insert(normalize(path), contents.to_owned());
+    }
+}
+
+// FileSystem implementation
+impl FileSystem for MemoryFileSystem {
+    fn read(&self, path: &str) -> io::Result<String> {
+        self.files.get(&normalize(path)).cloned().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "no such file")
+        })
+    }
+}
+
+/// [Internal use only]
+/// Resolves the path of an included file
+///
+/// # Arguments
+///
+/// * `from` - The path of the including file
+/// * `path` - The path written in the `.include` directive
+///
+/// # Returns
+///
+/// The normalized path of the included file, which is `path`
+/// itself if it starts with `/`, and is otherwise relative
+/// to the directory that holds `from`
+pub(crate) fn resolve(from: &str, path: &str) -> String {
+    if path.starts_with('/') {
+        return normalize(path);
+    }
+    match from.rfind('/') {
+        Some(idx) => normalize(&format!("{}/{}", &from[..idx], path)),
+        None => normalize(path),
+    }
+}
+
+/// [Internal use only]
+/// Normalizes a path by removing empty and `.`
+/// components and applying `..` components
+///
+/// # Arguments
+///
+/// * `path` - The `/`-separated path to normalize
+///
+/// # Returns
+///
+/// The normalized path
+fn normalize(path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." if parts.last().is_some_and(|last| *last != "..") => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    let joined = parts.join("/");
+    if path.starts_with('/') {
+        format!("/{}", joined)
+    } else {
+        joined
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the types into scope
+    use super::*;
+
+    // tests resolving included paths
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve("main.asm", "lib.asm"), "lib.asm");
+        assert_eq!(resolve("src/main.asm", "lib/math.asm"), "src/lib/math.asm");
+        assert_eq!(resolve("src/lib/math.asm", "../util.asm"), "src/util.asm");
+        assert_eq!(resolve("src/main.asm", "./a//b.asm"), "src/a/b.asm");
+        assert_eq!(resolve("main.asm", "../shared.asm"), "../shared.asm");
+        assert_eq!(resolve("src/main.asm", "/abs/x.asm"), "/abs/x.asm");
+    }
+
+    // tests reading files from memory
+    #[test]
+    fn test_memory() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file("./src/main.asm", "nop\n");
+        assert_eq!(fs.read("src/main.asm").unwrap(), "nop\n");
+        assert_eq!(fs.read("src/lib/../main.asm").unwrap(), "nop\n");
+        assert_eq!(fs.read("main.asm").unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+}
+
+// end of file
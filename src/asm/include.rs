@@ -0,0 +1,200 @@
+/*
+ * include.rs
+ * Splices included files into assembly source
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::error::AsmError;
+use super::file_system::resolve;
+use super::lexer::lex_file;
+use super::lexer::Token;
+use super::lexer::TokenKind;
+use super::macros::is_directive;
+use super::macros::split_lines;
+use super::FileSystem;
+use super::Span;
+
+/// [Internal use only]
+/// Loads a source file along with the files that it includes
+///
+/// A line holding `.include "<path>"` is replaced by the tokens
+/// of the file at `<path>`, relative to the including file. Each
+/// file is included at most once, so including a file that was
+/// already included elsewhere does nothing, but a file that
+/// includes itself, directly or not, is an error.
+pub(crate) struct Includer<'a> {
+    /// The file system that included files are read from
+    fs: &'a dyn FileSystem,
+
+    /// The path of every file read so far, where file `n` is at index `n`
+    files: Vec<String>,
+
+    /// The files being loaded, from the outermost inward
+    active: Vec<usize>,
+}
+
+// implementation
+impl<'a> Includer<'a> {
+    /// Creates a new `Includer` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `fs` - The file system that included files are read from
+    ///
+    /// # Returns
+    ///
+    /// A new `Includer` instance that has read no files
+    pub(crate) fn new(fs: &'a dyn FileSystem) -> Self {
+        Includer {
+            fs,
+            files: Vec::new(),
+            active: Vec::new(),
+        }
+    }
+
+    /// Gets the paths of the files read so far
+    ///
+    /// # Returns
+    ///
+    /// The path of every file, indexed by file ID
+    pub(crate) fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    /// Lexes a file and splices in the files that it includes
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The normalized path of the file
+    /// * `src` - The contents of the file
+    ///
+    /// # Returns
+    ///
+    /// The tokens of the file, or an `AsmError` if it or a
+    /// file that it includes is invalid or can't be read
+    pub(crate) fn load(&mut self, path: &str, src: &str) -> Result<Vec<Token>, AsmError> {
+        let id = self.files.len();
+        self.files.push(path.to_owned());
+        self.active.push(id);
+
+        let tokens = lex_file(src, id)?;
+        let mut out = Vec::new();
+        for line in split_lines(&tokens) {
+            if let Some(tok) = line[1..].iter().find(|tok| is_directive(tok, "include")) {
+                return Err(AsmError::new(tok.span, "`.include` must start its line"));
+            }
+            if !is_directive(&line[0], "include") {
+                out.extend_from_slice(line);
+                continue;
+            }
+            let (name, span) = match &line[1].kind {
+                TokenKind::Str(name) if line.len() == 3 => (name, line[1].span),
+                _ => {
+                    return Err(AsmError::new(
+                        line[0].span,
+                        "`.include` takes one file path in double quotes",
+                    ))
+                }
+            };
+
+            let included = resolve(path, name);
+            match self.files.iter().position(|file| *file == included) {
+                Some(file) if self.active.contains(&file) => {
+                    let mut chain: Vec<&str> = self.active.iter().map(|&f| self.files[f].as_str()).collect();
+                    chain.push(&included);
+                    return Err(AsmError::new(
+                        span,
+                        &format!("include cycle: {}", chain.join(" -> ")),
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    let src = self.fs.read(&included).map_err(|err| {
+                        AsmError::new(span, &format!("cannot read `{}`: {}", included, err))
+                    })?;
+                    out.extend(self.load(&included, &src)?);
+                }
+            }
+        }
+
+        self.active.pop();
+        Ok(out)
+    }
+}
+
+/// [Internal use only]
+/// Formats the location of a token for a message
+///
+/// # Arguments
+///
+/// * `span` - The location of the token
+/// * `files` - The path of every source file, indexed by file ID
+///
+/// # Returns
+///
+/// The location as `<path>:<line>:<col>`, or just `<line>:<col>`
+/// if the token's file has no path
+pub(crate) fn locate(span: Span, files: &[String]) -> String {
+    match files.get(span.file()) {
+        Some(path) if !path.is_empty() => format!("{}:{}", path, span),
+        _ => span.to_string(),
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the type into scope
+    use super::*;
+    use super::super::MemoryFileSystem;
+
+    // loads a file from a file system, and prints its tokens
+    fn load(fs: &MemoryFileSystem, path: &str) -> Result<(String, Vec<String>), AsmError> {
+        let mut includer = Includer::new(fs);
+        let tokens = includer.load(path, &fs.read(path).unwrap())?;
+        let words: Vec<String> = tokens.iter().map(|t| t.kind.to_string()).collect();
+        Ok((words.join(" "), includer.files().to_vec()))
+    }
+
+    // tests splicing in included files once each
+    #[test]
+    fn test_include() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file("src/main.asm", ".include \"lib/a.asm\"\n.include \"lib/b.asm\"\nhlt\n");
+        fs.add_file("src/lib/a.asm", ".include \"b.asm\"\ninc r0\n");
+        fs.add_file("src/lib/b.asm", "dec r0\n");
+        let (words, files) = load(&fs, "src/main.asm").unwrap();
+        assert_eq!(words, "dec r0  inc r0  hlt ");
+        assert_eq!(files, vec!["src/main.asm", "src/lib/a.asm", "src/lib/b.asm"]);
+    }
+
+    // tests include cycles and unreadable files
+    #[test]
+    fn test_include_errors() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file("main.asm", ".include \"a.asm\"\n");
+        fs.add_file("a.asm", "nop\n.include \"b.asm\"\n");
+        fs.add_file("b.asm", ".include \"a.asm\"\n");
+        fs.add_file("bad.asm", ".include \"missing.asm\"\n");
+        fs.add_file("worse.asm", ".include missing\n");
+        fs.add_file("late.asm", "x: .include \"a.asm\"\n");
+        let err1 = load(&fs, "main.asm").unwrap_err();
+        let err2 = load(&fs, "bad.asm").unwrap_err();
+        let err3 = load(&fs, "worse.asm").unwrap_err();
+        let err4 = load(&fs, "late.asm").unwrap_err();
+        assert_eq!(err1.message(), "include cycle: main.asm -> a.asm -> b.asm -> a.asm");
+        assert_eq!(err1.span(), Span::new(1, 10, 7).in_file(2));
+        assert_eq!(err2.message(), "cannot read `missing.asm`: no such file");
+        assert_eq!(err3.message(), "`.include` takes one file path in double quotes");
+        assert_eq!(err4.message(), "`.include` must start its line");
+        assert_eq!(err4.span(), Span::new(1, 4, 8));
+    }
+}
+
+// end of file
@@ -0,0 +1,420 @@
+/*
+ * lexer.rs
+ * Splits assembly source text into tokens
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::error::AsmError;
+use super::Span;
+use std::fmt;
+
+/// [Internal use only]
+/// The kind of a token in assembly source
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    /// A name, such as a mnemonic, register, or flag
+    Ident(String),
+
+    /// A directive name, written `.<name>`
+    Directive(String),
+
+    /// An unsigned integer literal
+    Int(u64),
+
+    /// A string literal, written in double quotes
+    Str(String),
+
+    /// A `,`
+    Comma,
+
+    /// A `:`
+    Colon,
+
+    /// A `+`
+    Plus,
+
+    /// A `-`
+    Minus,
+
+    /// A `*`
+    Star,
+
+    /// A `/`
+    Slash,
+
+    /// A `%`
+    Percent,
+
+    /// A `&`
+    Amp,
+
+    /// A `|`
+    Pipe,
+
+    /// A `<<`
+    ShiftLeft,
+
+    /// A `>>`
+    ShiftRight,
+
+    /// A `(`
+    LParen,
+
+    /// A `)`
+    RParen,
+
+    /// A `~`
+    Tilde,
+
+    /// A `^`
+    Caret,
+
+    /// A `[`
+    LBracket,
+
+    /// A `]`
+    RBracket,
+
+    /// A `=`
+    Equals,
+
+    /// A `@`
+    At,
+
+    /// A `!`
+    Bang,
+
+    /// A `..`
+    DotDot,
+
+    /// The end of a line
+    Newline,
+}
+
+/// [Internal use only]
+/// A token in assembly source
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Token {
+    /// The kind of the token
+    pub(crate) kind: TokenKind,
+
+    /// The location of the token
+    pub(crate) span: Span,
+}
+
+/// [Internal use only]
+/// Splits assembly source text that has no file into tokens
+///
+/// # Arguments
+///
+/// * `src` - The source text to split
+///
+/// # Returns
+///
+/// The tokens in the source text, or an `AsmError`
+/// if the text contains an invalid token
+#[cfg(test)]
+pub(crate) fn lex(src: &str) -> Result<Vec<Token>, AsmError> {
+    lex_file(src, 0)
+}
+
+/// [Internal use only]
+/// Splits the text of a source file into tokens
+///
+/// Comments start with `;` and run to the end of the line.
+///
+/// # Arguments
+///
+/// * `src` - The source text to split
+/// * `file` - The ID of the file, which every token's span is tagged with
+///
+/// # Returns
+///
+/// The tokens in the source text, or an `AsmError`
+/// if the text contains an invalid token
+pub(crate) fn lex_file(src: &str, file: usize) -> Result<Vec<Token>, AsmError> {
+    let mut tokens = Vec::new();
+
+    for (idx, line) in src.lines().enumerate() {
+        let line_no = idx + 1;
+        let chars: Vec<char> = line.chars().collect();
+        let mut col = 0;
+
+        while col < chars.len() {
+            let c = chars[col];
+            let start = col;
+
+            // skip whitespace, and stop at comments
+            if c.is_whitespace() {
+                col += 1;
+                continue;
+            }
+            if c == ';' {
+                break;
+            }
+
+            let kind = if c.is_ascii_alphabetic() || c == '_' {
+                while col < chars.len() && (chars[col].is_ascii_alphanumeric() || chars[col] == '_') {
+                    col += 1;
+                }
+                TokenKind::Ident(chars[start..col].iter().collect())
+            } else if c.is_ascii_digit() {
+                while col < chars.len() && chars[col].is_ascii_alphanumeric() {
+                    col += 1;
+                }
+                let text: String = chars[start..col].iter().collect();
+                let span = Span::new(line_no, start + 1, col - start).in_file(file);
+                TokenKind::Int(parse_int(&text, span)?)
+            } else if c == '"' {
+                // strings have no escapes, and end at the next quote
+                col += 1;
+                while col < chars.len() && chars[col] != '"' {
+                    col += 1;
+                }
+                if col == chars.len() {
+                    return Err(AsmError::new(
+                        Span::new(line_no, start + 1, col - start).in_file(file),
+                        "unterminated string literal",
+                    ));
+                }
+                col += 1;
+                TokenKind::Str(chars[start + 1..col - 1].iter().collect())
+            } else if c == '.' && chars.get(col + 1) == Some(&'.') {
+                col += 2;
+                TokenKind::DotDot
+            } else if c == '.' && chars.get(col + 1).is_some_and(|c| c.is_ascii_alphabetic()) {
+                col += 1;
+                while col < chars.len() && (chars[col].is_ascii_alphanumeric() || chars[col] == '_') {
+                    col += 1;
+                }
+                TokenKind::Directive(chars[start + 1..col].iter().collect())
+            } else if (c == '<' || c == '>') && chars.get(col + 1) == Some(&c) {
+                col += 2;
+                if c == '<' {
+                    TokenKind::ShiftLeft
+                } else {
+                    TokenKind::ShiftRight
+                }
+            } else {
+                col += 1;
+                match c {
+                    ',' => TokenKind::Comma,
+                    ':' => TokenKind::Colon,
+                    '+' => TokenKind::Plus,
+                    '-' => TokenKind::Minus,
+                    '*' => TokenKind::Star,
+                    '/' => TokenKind::Slash,
+                    '%' => TokenKind::Percent,
+                    '&' => TokenKind::Amp,
+                    '|' => TokenKind::Pipe,
+                    '(' => TokenKind::LParen,
+                    ')' => TokenKind::RParen,
+                    '~' => TokenKind::Tilde,
+                    '^' => TokenKind::Caret,
+                    '[' => TokenKind::LBracket,
+                    ']' => TokenKind::RBracket,
+                    '=' => TokenKind::Equals,
+                    '@' => TokenKind::At,
+                    '!' => TokenKind::Bang,
+                    _ => {
+                        return Err(AsmError::new(
+                            Span::new(line_no, start + 1, 1).in_file(file),
+                            &format!("unexpected character `{}`", c),
+                        ));
+                    }
+                }
+            };
+
+            tokens.push(Token {
+                kind,
+                span: Span::new(line_no, start + 1, col - start).in_file(file),
+            });
+        }
+
+        // every line ends with a newline token
+        tokens.push(Token {
+            kind: TokenKind::Newline,
+            span: Span::new(line_no, chars.len() + 1, 0).in_file(file),
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// [Internal use only]
+/// Parses the text of an integer literal
+///
+/// Literals may be decimal, hexadecimal (`0x`),
+/// or binary (`0b`).
+///
+/// # Arguments
+///
+/// * `text` - The text of the literal
+/// * `span` - The location of the literal
+///
+/// # Returns
+///
+/// The value of the literal, or an `AsmError` if it is invalid
+fn parse_int(text: &str, span: Span) -> Result<u64, AsmError> {
+    let lower = text.to_ascii_lowercase();
+    let (digits, radix) = if let Some(hex) = lower.strip_prefix("0x") {
+        (hex, 16)
+    } else if let Some(bin) = lower.strip_prefix("0b") {
+        (bin, 2)
+    } else {
+        (lower.as_str(), 10)
+    };
+
+    u64::from_str_radix(digits, radix).map_err(|_| {
+        AsmError::new(span, &format!("invalid integer literal `{}`", text))
+    })
+}
+
+// Display implementation
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenKind::Ident(name) => write!(f, "{}", name),
+            TokenKind::Directive(name) => write!(f, ".{}", name),
+            TokenKind::Int(val) => write!(f, "{}", val),
+            TokenKind::Str(text) => write!(f, "\"{}\"", text),
+            TokenKind::Comma => write!(f, ","),
+            TokenKind::Colon => write!(f, ":"),
+            TokenKind::Plus => write!(f, "+"),
+            TokenKind::Minus => write!(f, "-"),
+            TokenKind::Star => write!(f, "*"),
+            TokenKind::Slash => write!(f, "/"),
+            TokenKind::Percent => write!(f, "%"),
+            TokenKind::Amp => write!(f, "&"),
+            TokenKind::Pipe => write!(f, "|"),
+            TokenKind::ShiftLeft => write!(f, "<<"),
+            TokenKind::ShiftRight => write!(f, ">>"),
+            TokenKind::LParen => write!(f, "("),
+            TokenKind::RParen => write!(f, ")"),
+            TokenKind::Tilde => write!(f, "~"),
+            TokenKind::Caret => write!(f, "^"),
+            TokenKind::LBracket => write!(f, "["),
+            TokenKind::RBracket => write!(f, "]"),
+            TokenKind::Equals => write!(f, "="),
+            TokenKind::At => write!(f, "@"),
+            TokenKind::Bang => write!(f, "!"),
+            TokenKind::DotDot => write!(f, ".."),
+            TokenKind::Newline => Ok(()),
+        }
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the function into scope
+    use super::*;
+
+    // tests lexing a simple line
+    #[test]
+    fn test_lex_line() {
+        let tokens = lex("  mov 0x32, r0 ; comment").unwrap();
+        let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Ident("mov".to_owned()),
+                TokenKind::Int(0x32),
+                TokenKind::Comma,
+                TokenKind::Ident("r0".to_owned()),
+                TokenKind::Newline,
+            ]
+        );
+        assert_eq!(tokens[1].span, Span::new(1, 7, 4));
+        assert_eq!(tokens[3].span, Span::new(1, 13, 2));
+    }
+
+    // tests lexing integer literals
+    #[test]
+    fn test_lex_ints() {
+        let tokens = lex("50 0b101 0XfF").unwrap();
+        assert_eq!(tokens[0].kind, TokenKind::Int(50));
+        assert_eq!(tokens[1].kind, TokenKind::Int(5));
+        assert_eq!(tokens[2].kind, TokenKind::Int(255));
+    }
+
+    // tests lexing coordinates and block states
+    #[test]
+    fn test_lex_block() {
+        let tokens = lex("~-1 ^ stone[half=top]").unwrap();
+        let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Tilde,
+                TokenKind::Minus,
+                TokenKind::Int(1),
+                TokenKind::Caret,
+                TokenKind::Ident("stone".to_owned()),
+                TokenKind::LBracket,
+                TokenKind::Ident("half".to_owned()),
+                TokenKind::Equals,
+                TokenKind::Ident("top".to_owned()),
+                TokenKind::RBracket,
+                TokenKind::Newline,
+            ]
+        );
+    }
+
+    // tests lexing selectors
+    #[test]
+    fn test_lex_selector() {
+        let tokens = lex("@e[tag=!a,distance=1..5]").unwrap();
+        let text: String = tokens.iter().map(|t| t.kind.to_string()).collect();
+        assert_eq!(text, "@e[tag=!a,distance=1..5]");
+        assert_eq!(tokens[11].kind, TokenKind::DotDot);
+        assert_eq!(tokens[11].span, Span::new(1, 21, 2));
+        assert_eq!(lex("1.5").unwrap_err().message(), "unexpected character `.`");
+    }
+
+    // tests lexing directives and expression operators
+    #[test]
+    fn test_lex_directive() {
+        let tokens = lex(".const SIZE, (1 << 4) * 3 % 5 | ~0x0F & 7 >> 1 + -2 / 1").unwrap();
+        let text: Vec<String> = tokens.iter().map(|t| t.kind.to_string()).collect();
+        assert_eq!(
+            text.join(" "),
+            ".const SIZE , ( 1 << 4 ) * 3 % 5 | ~ 15 & 7 >> 1 + - 2 / 1 "
+        );
+        assert_eq!(tokens[0].kind, TokenKind::Directive("const".to_owned()));
+        assert_eq!(tokens[0].span, Span::new(1, 1, 6));
+        assert_eq!(tokens[5].span, Span::new(1, 17, 2));
+        assert_eq!(lex("< 1").unwrap_err().message(), "unexpected character `<`");
+    }
+
+    // tests lexing string literals
+    #[test]
+    fn test_lex_string() {
+        let tokens = lex_file(".include \"lib/a b.asm\" ; \"comment\"", 2).unwrap();
+        assert_eq!(tokens[1].kind, TokenKind::Str("lib/a b.asm".to_owned()));
+        assert_eq!(tokens[1].span, Span::new(1, 10, 13).in_file(2));
+        assert_eq!(tokens.len(), 3);
+        let err = lex_file("\n.include \"lib.asm", 1).unwrap_err();
+        assert_eq!(err.message(), "unterminated string literal");
+        assert_eq!(err.span(), Span::new(2, 10, 8).in_file(1));
+    }
+
+    // tests lexing invalid tokens
+    #[test]
+    fn test_lex_errors() {
+        let err1 = lex("mov 12ab, r0").unwrap_err();
+        let err2 = lex("\nadd r0 $ r1").unwrap_err();
+        assert_eq!(err1.span(), Span::new(1, 5, 4));
+        assert_eq!(err1.message(), "invalid integer literal `12ab`");
+        assert_eq!(err2.span(), Span::new(2, 8, 1));
+        assert_eq!(err2.message(), "unexpected character `$`");
+    }
+}
+
+// end of file
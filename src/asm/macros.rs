@@ -0,0 +1,538 @@
+/*
+ * macros.rs
+ * Expands assembler macros
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::error::AsmError;
+use super::include::locate;
+use super::lexer::Token;
+use super::lexer::TokenKind;
+use super::parser::ident_operand;
+use super::Span;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// The deepest that macro calls can nest, which stops
+/// a macro that calls itself from expanding forever
+pub const MAX_MACRO_DEPTH: usize = 32;
+
+/// [Internal use only]
+/// A macro defined with `.macro`
+struct Macro {
+    /// The location of the macro's name in its definition
+    span: Span,
+
+    /// The names of the macro's parameters
+    params: Vec<String>,
+
+    /// The lines of the macro's body, each ending with a newline
+    body: Vec<Token>,
+}
+
+/// [Internal use only]
+/// A single expansion of a macro
+struct Expansion {
+    /// The name of the expanded macro
+    name: String,
+
+    /// The location of the macro's name in its definition
+    def: Span,
+
+    /// The location of the macro's name in the call
+    call: Span,
+}
+
+/// [Internal use only]
+/// Expands the macros in assembly source
+///
+/// A macro is defined with
+///
+/// ```text
+/// .macro <name> <param>, ...
+///     <body>
+/// .endm
+/// ```
+///
+/// and called like an instruction, as in `<name> <arg>, ...`.
+/// Every parameter named in the body is replaced by the tokens
+/// of its argument, and labels defined in the body are local to
+/// each expansion, so a macro can be called more than once.
+/// Macros can be called before they are defined, and can call
+/// other macros, up to `MAX_MACRO_DEPTH` calls deep.
+///
+/// Tokens copied from a macro body keep their location in the
+/// definition, tagged with the expansion that they belong to,
+/// so that `trace` can explain errors in expanded code.
+pub(crate) struct Expander {
+    /// The defined macros, by name
+    macros: HashMap<String, Rc<Macro>>,
+
+    /// Every expansion so far, where expansion `n` is at index `n - 1`
+    expansions: Vec<Expansion>,
+}
+
+// implementation
+impl Expander {
+    /// Creates a new `Expander` instance with no macros
+    ///
+    /// # Returns
+    ///
+    /// A new `Expander` instance
+    pub(crate) fn new() -> Self {
+        Expander {
+            macros: HashMap::new(),
+            expansions: Vec::new(),
+        }
+    }
+
+    /// Removes the macro definitions from tokens
+    /// and expands every macro call
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens to expand, as produced by `lex`
+    ///
+    /// # Returns
+    ///
+    /// The expanded tokens, or an `AsmError` if a
+    /// macro definition or call is malformed
+    pub(crate) fn expand(&mut self, tokens: &[Token]) -> Result<Vec<Token>, AsmError> {
+        let rest = self.define(tokens)?;
+        let mut out = Vec::new();
+        self.expand_lines(&rest, 0, &mut out)?;
+        Ok(out)
+    }
+
+    /// Adds notes to an error that locate the macro expansions
+    /// that produced its token, from the innermost outward
+    ///
+    /// # Arguments
+    ///
+    /// * `err` - The error
+    /// * `files` - The path of every source file, indexed by file ID
+    ///
+    /// # Returns
+    ///
+    /// The error with its expansion trace
+    pub(crate) fn trace(&self, mut err: AsmError, files: &[String]) -> AsmError {
+        let mut id = err.span().expansion();
+        while id != 0 {
+            let exp = &self.expansions[id - 1];
+            err = err.with_note(&format!(
+                "in macro `{}` (defined at {}), called at {}",
+                exp.name,
+                locate(exp.def, files),
+                locate(exp.call, files)
+            ));
+            id = exp.call.expansion();
+        }
+        err
+    }
+
+    /// Gathers the macro definitions in tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens to search
+    ///
+    /// # Returns
+    ///
+    /// The tokens outside of the definitions, or an
+    /// `AsmError` if a definition is malformed
+    fn define(&mut self, tokens: &[Token]) -> Result<Vec<Token>, AsmError> {
+        let mut rest = Vec::new();
+        let mut lines = split_lines(tokens).into_iter();
+        while let Some(line) = lines.next() {
+            if is_directive(&line[0], "endm") {
+                return Err(AsmError::new(line[0].span, "`.endm` without a matching `.macro`"));
+            }
+            if !is_directive(&line[0], "macro") {
+                rest.extend_from_slice(line);
+                continue;
+            }
+
+            // gather the body, up to the `.endm`
+            let (name, span, params) = header(line)?;
+            let mut body = Vec::new();
+            loop {
+                // a definition can't run past the end of its file
+                let body_line = lines
+                    .next()
+                    .filter(|body_line| body_line[0].span.file() == line[0].span.file())
+                    .ok_or_else(|| AsmError::new(line[0].span, "`.macro` without a matching `.endm`"))?;
+                if is_directive(&body_line[0], "macro") {
+                    return Err(AsmError::new(
+                        body_line[0].span,
+                        "macro definitions cannot be nested",
+                    ));
+                }
+                if is_directive(&body_line[0], "endm") {
+                    if body_line.len() > 2 {
+                        return Err(AsmError::new(body_line[1].span, "`.endm` takes no operands"));
+                    }
+                    break;
+                }
+                body.extend_from_slice(body_line);
+            }
+
+            if let Some(prev) = self.macros.get(&name) {
+                return Err(AsmError::new(
+                    span,
+                    &format!("macro `{}` is already defined at {}", name, prev.span),
+                ));
+            }
+            self.macros.insert(name, Rc::new(Macro { span, params, body }));
+        }
+        Ok(rest)
+    }
+
+    /// Expands the macro calls in some lines of tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The lines to expand, each ending with a newline
+    /// * `depth` - The number of macro calls that the lines are nested in
+    /// * `out` - Receives the expanded tokens
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `AsmError` if a macro call is invalid
+    fn expand_lines(&mut self, tokens: &[Token], depth: usize, out: &mut Vec<Token>) -> Result<(), AsmError> {
+        for line in split_lines(tokens) {
+            // a label may come before the call
+            let start = if line.len() > 2 && is_label(line) { 2 } else { 0 };
+            let (name, mac) = match &line[start].kind {
+                TokenKind::Ident(name) => match self.macros.get(name) {
+                    Some(mac) => (name, Rc::clone(mac)),
+                    None => {
+                        out.extend_from_slice(line);
+                        continue;
+                    }
+                },
+                _ => {
+                    out.extend_from_slice(line);
+                    continue;
+                }
+            };
+
+            // the label marks the first line of the expansion
+            if start == 2 {
+                out.extend_from_slice(&line[..2]);
+                out.push(Token {
+                    kind: TokenKind::Newline,
+                    span: line[1].span,
+                });
+            }
+            let call = line[start].span;
+            if depth >= MAX_MACRO_DEPTH {
+                return Err(AsmError::new(
+                    call,
+                    &format!("macro `{}` is nested more than {} calls deep", name, MAX_MACRO_DEPTH),
+                ));
+            }
+            let body = self.instantiate(name, &mac, call, &line[start + 1..line.len() - 1])?;
+            self.expand_lines(&body, depth + 1, out)?;
+        }
+        Ok(())
+    }
+
+    /// Copies the body of a macro for a call
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the macro
+    /// * `mac` - The macro
+    /// * `call` - The location of the macro's name in the call
+    /// * `args` - The tokens of the call's arguments
+    ///
+    /// # Returns
+    ///
+    /// The body of the macro, with its parameters replaced and
+    /// its labels renamed, or an `AsmError` if the arguments are invalid
+    fn instantiate(&mut self, name: &str, mac: &Macro, call: Span, args: &[Token]) -> Result<Vec<Token>, AsmError> {
+        let args = split_args(args)?;
+        if args.len() != mac.params.len() {
+            return Err(AsmError::new(
+                call,
+                &format!(
+                    "macro `{}` takes {} argument(s), but {} were given",
+                    name,
+                    mac.params.len(),
+                    args.len()
+                ),
+            ));
+        }
+        self.expansions.push(Expansion {
+            name: name.to_owned(),
+            def: mac.span,
+            call,
+        });
+        let id = self.expansions.len();
+
+        // labels defined in the body are renamed to names that
+        // can't be written in source, so each expansion has its own
+        let locals: HashSet<&str> = split_lines(&mac.body)
+            .into_iter()
+            .filter(|line| is_label(line))
+            .filter_map(|line| match &line[0].kind {
+                TokenKind::Ident(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        let mut body = Vec::new();
+        for tok in &mac.body {
+            let kind = match &tok.kind {
+                TokenKind::Ident(name) => match mac.params.iter().position(|p| p == name) {
+                    Some(idx) => {
+                        body.extend_from_slice(args[idx]);
+                        continue;
+                    }
+                    None if locals.contains(name.as_str()) => TokenKind::Ident(format!("{}@{}", name, id)),
+                    None => tok.kind.clone(),
+                },
+                kind => kind.clone(),
+            };
+            body.push(Token {
+                kind,
+                span: tok.span.in_expansion(id),
+            });
+        }
+        Ok(body)
+    }
+}
+
+/// [Internal use only]
+/// Parses the first line of a macro definition
+///
+/// # Arguments
+///
+/// * `line` - The tokens of the line, starting with `.macro`
+///
+/// # Returns
+///
+/// The name of the macro, its location, and the names of
+/// its parameters, or an `AsmError` if the line is malformed
+fn header(line: &[Token]) -> Result<(String, Span, Vec<String>), AsmError> {
+    let (name, span) = match &line[1].kind {
+        TokenKind::Ident(name) => (name.clone(), line[1].span),
+        _ => return Err(AsmError::new(line[1].span, "expected a macro name")),
+    };
+
+    // parse the comma-separated parameters
+    let mut params: Vec<String> = Vec::new();
+    let mut idx = 2;
+    while line[idx].kind != TokenKind::Newline {
+        if !params.is_empty() {
+            if line[idx].kind != TokenKind::Comma {
+                return Err(AsmError::new(line[idx].span, "expected `,` or end of line"));
+            }
+            idx += 1;
+        }
+        let tok = &line[idx];
+        let param = match &tok.kind {
+            TokenKind::Ident(param) if ident_operand(param).is_some() => {
+                return Err(AsmError::new(
+                    tok.span,
+                    &format!("`{}` cannot be used as a macro parameter", param),
+                ));
+            }
+            TokenKind::Ident(param) => param,
+            _ => return Err(AsmError::new(tok.span, "expected a parameter name")),
+        };
+        if params.contains(param) {
+            return Err(AsmError::new(
+                tok.span,
+                &format!("parameter `{}` is declared more than once", param),
+            ));
+        }
+        params.push(param.clone());
+        idx += 1;
+    }
+
+    Ok((name, span, params))
+}
+
+/// [Internal use only]
+/// Splits the arguments of a macro call at the commas
+/// that are not inside brackets or parentheses
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens of the arguments
+///
+/// # Returns
+///
+/// The tokens of each argument, or an `AsmError` if an argument is empty
+fn split_args(tokens: &[Token]) -> Result<Vec<&[Token]>, AsmError> {
+    if tokens.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (idx, tok) in tokens.iter().enumerate() {
+        match tok.kind {
+            TokenKind::LBracket | TokenKind::LParen => depth += 1,
+            TokenKind::RBracket | TokenKind::RParen => depth -= 1,
+            TokenKind::Comma if depth == 0 => {
+                if idx == start {
+                    return Err(AsmError::new(tok.span, "expected a macro argument before `,`"));
+                }
+                args.push(&tokens[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if start == tokens.len() {
+        return Err(AsmError::new(
+            tokens[start - 1].span,
+            "expected a macro argument after `,`",
+        ));
+    }
+    args.push(&tokens[start..]);
+    Ok(args)
+}
+
+/// [Internal use only]
+/// Splits tokens into lines
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens to split, where every line ends with a newline
+///
+/// # Returns
+///
+/// The tokens of each line, including its newline
+pub(crate) fn split_lines(tokens: &[Token]) -> Vec<&[Token]> {
+    tokens
+        .split_inclusive(|tok| tok.kind == TokenKind::Newline)
+        .collect()
+}
+
+/// [Internal use only]
+/// Checks whether a line starts with a label definition
+///
+/// # Arguments
+///
+/// * `line` - The tokens of the line
+///
+/// # Returns
+///
+/// Whether the line starts with `<name>:`
+fn is_label(line: &[Token]) -> bool {
+    matches!(line[0].kind, TokenKind::Ident(_)) && line.get(1).map(|t| &t.kind) == Some(&TokenKind::Colon)
+}
+
+/// [Internal use only]
+/// Checks whether a token is a given directive
+///
+/// # Arguments
+///
+/// * `tok` - The token
+/// * `name` - The name of the directive, without its `.`
+///
+/// # Returns
+///
+/// Whether the token is the directive, ignoring case
+pub(crate) fn is_directive(tok: &Token, name: &str) -> bool {
+    matches!(&tok.kind, TokenKind::Directive(dir) if dir.eq_ignore_ascii_case(name))
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the type into scope
+    use super::*;
+    use super::super::lexer::lex;
+
+    // prints tokens separated by spaces, with newlines as `\n`
+    fn words(tokens: &[Token]) -> String {
+        let words: Vec<String> = tokens
+            .iter()
+            .map(|t| match t.kind {
+                TokenKind::Newline => "\n".to_owned(),
+                _ => t.kind.to_string(),
+            })
+            .collect();
+        words.join(" ")
+    }
+
+    // expands source text and prints the expanded tokens
+    fn expand(src: &str) -> Result<String, AsmError> {
+        Ok(words(&Expander::new().expand(&lex(src)?)?))
+    }
+
+    // tests replacing parameters and renaming local labels
+    #[test]
+    fn test_expand() {
+        let src = "swap r0, r1\n.macro swap a, b\npush a\nexg a, b\npop b\n.endm\n\
+                   .macro count n\nmov n, r0\nloop: dec r0\nchk r0\njns flag_Zero, loop\n.endm\n\
+                   start: count (1, 2)\ncount blk[x=1,y=2]\n";
+        assert_eq!(
+            expand(src).unwrap(),
+            "push r0 \n exg r0 , r1 \n pop r1 \n \
+             start : \n mov ( 1 , 2 ) , r0 \n loop@2 : dec r0 \n chk r0 \n jns flag_Zero , loop@2 \n \
+             mov blk [ x = 1 , y = 2 ] , r0 \n loop@3 : dec r0 \n chk r0 \n jns flag_Zero , loop@3 \n"
+        );
+    }
+
+    // tests macros that call other macros, and the expansion trace
+    #[test]
+    fn test_nested() {
+        let mut macros = Expander::new();
+        let src = ".macro outer x\ninner x\n.endm\n.macro inner y\nmov y, r0\n.endm\n\nouter 5\n";
+        let tokens = macros.expand(&lex(src).unwrap()).unwrap();
+        assert_eq!(words(&tokens), "\n mov 5 , r0 \n");
+        assert_eq!(tokens[1].span, Span::new(5, 1, 3).in_expansion(2));
+        assert_eq!(tokens[2].span, Span::new(8, 7, 1));
+
+        let err = macros.trace(AsmError::new(tokens[1].span, "boom"), &[String::new()]);
+        assert_eq!(
+            err.notes(),
+            &[
+                "in macro `inner` (defined at 4:8), called at 2:1".to_owned(),
+                "in macro `outer` (defined at 1:8), called at 8:1".to_owned(),
+            ]
+        );
+    }
+
+    // tests malformed macro definitions and calls
+    #[test]
+    fn test_expand_errors() {
+        let err1 = expand(".macro m\nnop\n").unwrap_err();
+        let err2 = expand("nop\n.endm\n").unwrap_err();
+        let err3 = expand(".macro m a, a\n.endm\n").unwrap_err();
+        let err4 = expand(".macro m r0\n.endm\n").unwrap_err();
+        let err5 = expand(".macro m\n.endm\n.macro m\n.endm\n").unwrap_err();
+        let err6 = expand(".macro m a\n.endm\nm 1, 2\n").unwrap_err();
+        let err7 = expand(".macro m a, b\n.endm\nm 1,\n").unwrap_err();
+        let err8 = expand(".macro m\n.macro n\n.endm\n.endm\n").unwrap_err();
+        let err9 = expand(".macro m\nm\n.endm\nm\n").unwrap_err();
+        assert_eq!(err1.message(), "`.macro` without a matching `.endm`");
+        assert_eq!(err1.span(), Span::new(1, 1, 6));
+        assert_eq!(err2.message(), "`.endm` without a matching `.macro`");
+        assert_eq!(err3.message(), "parameter `a` is declared more than once");
+        assert_eq!(err4.message(), "`r0` cannot be used as a macro parameter");
+        assert_eq!(err5.message(), "macro `m` is already defined at 1:8");
+        assert_eq!(err6.message(), "macro `m` takes 1 argument(s), but 2 were given");
+        assert_eq!(err6.span(), Span::new(3, 1, 1));
+        assert_eq!(err7.message(), "expected a macro argument after `,`");
+        assert_eq!(err7.span(), Span::new(3, 4, 1));
+        assert_eq!(err8.message(), "macro definitions cannot be nested");
+        assert_eq!(err9.message(), "macro `m` is nested more than 32 calls deep");
+        assert_eq!(err9.span(), Span::new(2, 1, 1).in_expansion(MAX_MACRO_DEPTH));
+    }
+}
+
+// end of file
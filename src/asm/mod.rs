@@ -0,0 +1,34 @@
+/*
+ * mod.rs
+ * Module header for mcisa's asm module
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// module exports
+mod span;
+pub use span::Span;
+mod symbols;
+pub use symbols::SymbolTable;
+mod program;
+pub use program::Program;
+mod expr;
+mod file_system;
+pub use file_system::DiskFileSystem;
+pub use file_system::FileSystem;
+pub use file_system::MemoryFileSystem;
+mod include;
+mod lexer;
+mod macros;
+pub use macros::MAX_MACRO_DEPTH;
+mod parser;
+mod assembler;
+pub use assembler::assemble;
+pub use assembler::assemble_file;
+pub use assembler::assemble_object;
+
+// end of file
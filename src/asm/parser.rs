@@ -0,0 +1,735 @@
+/*
+ * parser.rs
+ * Parses tokens of assembly source into statements
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Flag;
+use super::super::cpu::Register;
+use super::super::data::BlockState;
+use super::super::data::Selector;
+use super::super::error::AsmError;
+use super::expr::BinaryOp;
+use super::expr::Expr;
+use super::lexer::Token;
+use super::lexer::TokenKind;
+use super::Span;
+use std::convert::TryFrom;
+use strum::IntoEnumIterator;
+
+/// [Internal use only]
+/// The kind of an instruction operand
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum OperandKind {
+    /// A register, spelled as its `Display` form
+    Register(Register),
+
+    /// A flag, spelled as its `Display` form
+    Flag(Flag),
+
+    /// An integer literal
+    Int(i64),
+
+    /// A relative coordinate, written `~` or `~<offset>`
+    Relative(i64),
+
+    /// A local coordinate, written `^` or `^<offset>`
+    Local(i64),
+
+    /// A block, written `<namespace>:<id>[<key>=<value>,...]`,
+    /// where the namespace and properties are optional if
+    /// the name is followed by the other
+    Block(BlockState),
+
+    /// A target selector, such as `@e[type=zombie]`
+    Selector(Selector),
+
+    /// Any other name, which refers to a label or a constant
+    Name(String),
+
+    /// A constant expression that refers to labels or constants,
+    /// such as `end - start`. Expressions without any names are
+    /// evaluated while parsing, and become `Int` operands.
+    Expr(Expr),
+}
+
+/// [Internal use only]
+/// An instruction operand
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Operand {
+    /// The kind of the operand
+    pub(crate) kind: OperandKind,
+
+    /// The location of the operand
+    pub(crate) span: Span,
+}
+
+/// [Internal use only]
+/// A single line of assembly holding an instruction or a directive
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Line {
+    /// The mnemonic of the instruction, or the
+    /// name of the directive including its `.`
+    pub(crate) mnemonic: String,
+
+    /// The location of the mnemonic
+    pub(crate) span: Span,
+
+    /// The operands of the instruction
+    pub(crate) operands: Vec<Operand>,
+}
+
+/// [Internal use only]
+/// A single statement of assembly
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Statement {
+    /// A label definition, written `name:`
+    Label(String, Span),
+
+    /// An instruction
+    Instr(Line),
+
+    /// An assembler directive, such as `.const SIZE, 16`
+    Directive(Line),
+}
+
+/// [Internal use only]
+/// Parses tokens into statements of assembly
+///
+/// Blank lines are skipped, and a label definition
+/// may share a line with the instruction it marks.
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens to parse, as produced by `lex`
+///
+/// # Returns
+///
+/// The parsed statements, or an `AsmError` if the tokens are malformed
+pub(crate) fn parse(tokens: &[Token]) -> Result<Vec<Statement>, AsmError> {
+    let mut parser = Parser { tokens, pos: 0 };
+    let mut stmts = Vec::new();
+
+    while parser.pos < tokens.len() {
+        if let Some(label) = parser.label()? {
+            stmts.push(label);
+        }
+        if let Some(stmt) = parser.line()? {
+            stmts.push(stmt);
+        }
+    }
+
+    Ok(stmts)
+}
+
+/// [Internal use only]
+/// Holds the state of the parser
+struct Parser<'a> {
+    /// The tokens being parsed
+    tokens: &'a [Token],
+
+    /// The index of the next token
+    pos: usize,
+}
+
+// implementation
+impl<'a> Parser<'a> {
+    /// Gets the next token without consuming it
+    ///
+    /// # Returns
+    ///
+    /// The next token
+    fn peek(&self) -> &'a Token {
+        &self.tokens[self.pos]
+    }
+
+    /// Consumes the next token
+    ///
+    /// # Returns
+    ///
+    /// The consumed token
+    fn next(&mut self) -> &'a Token {
+        let tok = &self.tokens[self.pos];
+        self.pos += 1;
+        tok
+    }
+
+    /// Parses a label definition at the start of a line
+    ///
+    /// # Returns
+    ///
+    /// The label definition, or `None` if the line does not start with one
+    fn label(&mut self) -> Result<Option<Statement>, AsmError> {
+        let tok = self.peek();
+        let name = match &tok.kind {
+            TokenKind::Ident(name) if self.tokens[self.pos + 1].kind == TokenKind::Colon => name,
+            _ => return Ok(None),
+        };
+
+        // registers and flags can't double as label names
+        if ident_operand(name).is_some() {
+            return Err(AsmError::new(
+                tok.span,
+                &format!("`{}` cannot be used as a label name", name),
+            ));
+        }
+
+        self.pos += 2;
+        Ok(Some(Statement::Label(name.clone(), tok.span)))
+    }
+
+    /// Parses the rest of a line, including its newline token
+    ///
+    /// # Returns
+    ///
+    /// The instruction or directive on the line,
+    /// or `None` if the line is blank
+    fn line(&mut self) -> Result<Option<Statement>, AsmError> {
+        let tok = self.next();
+        let (mnemonic, make): (String, fn(Line) -> Statement) = match &tok.kind {
+            TokenKind::Newline => return Ok(None),
+            TokenKind::Ident(name) => (name.clone(), Statement::Instr),
+            TokenKind::Directive(name) => (format!(".{}", name), Statement::Directive),
+            _ => return Err(AsmError::new(tok.span, "expected a mnemonic")),
+        };
+
+        // parse the comma-separated operands
+        let mut operands = Vec::new();
+        if self.peek().kind != TokenKind::Newline {
+            loop {
+                operands.push(self.operand()?);
+                let tok = self.next();
+                match tok.kind {
+                    TokenKind::Comma => continue,
+                    TokenKind::Newline => break,
+                    _ => return Err(AsmError::new(tok.span, "expected `,` or end of line")),
+                }
+            }
+        } else {
+            self.next();
+        }
+
+        Ok(Some(make(Line {
+            mnemonic,
+            span: tok.span,
+            operands,
+        })))
+    }
+
+    /// Parses a single operand
+    ///
+    /// # Returns
+    ///
+    /// The parsed operand
+    fn operand(&mut self) -> Result<Operand, AsmError> {
+        let tok = self.next();
+        match &tok.kind {
+            TokenKind::Int(_) | TokenKind::Minus | TokenKind::Plus | TokenKind::LParen => {
+                self.pos -= 1;
+                self.expression()
+            }
+            TokenKind::Tilde if matches!(self.peek().kind, TokenKind::Ident(_) | TokenKind::LParen | TokenKind::Tilde) => {
+                self.pos -= 1;
+                self.expression()
+            }
+            TokenKind::Tilde | TokenKind::Caret => {
+                // an offset may follow the coordinate prefix
+                let (offset, span) = match self.peek().kind {
+                    TokenKind::Int(_) | TokenKind::Minus => self.int()?,
+                    _ => (0, tok.span),
+                };
+                let kind = match tok.kind {
+                    TokenKind::Tilde => OperandKind::Relative(offset),
+                    _ => OperandKind::Local(offset),
+                };
+                Ok(Operand {
+                    kind,
+                    span: tok.span.through(span),
+                })
+            }
+            TokenKind::At => {
+                self.pos -= 1;
+                self.selector()
+            }
+            TokenKind::Ident(name) if matches!(self.peek().kind, TokenKind::Colon | TokenKind::LBracket) => {
+                self.pos -= 1;
+                self.block()
+            }
+            TokenKind::Ident(name) => match ident_operand(name) {
+                Some(kind) => Ok(Operand {
+                    kind,
+                    span: tok.span,
+                }),
+                None => {
+                    self.pos -= 1;
+                    self.expression()
+                }
+            },
+            _ => Err(AsmError::new(tok.span, "expected an operand")),
+        }
+    }
+
+    /// Parses an operand holding a constant expression
+    ///
+    /// A `~` at the start of an operand begins a relative coordinate
+    /// when it is followed by an integer, so complementing a literal
+    /// at the start of an operand needs parentheses, as in `(~5)`.
+    ///
+    /// # Returns
+    ///
+    /// The parsed operand
+    fn expression(&mut self) -> Result<Operand, AsmError> {
+        let start = self.peek().span;
+        let expr = self.binary(0)?;
+        let span = start.through(self.tokens[self.pos - 1].span);
+        let kind = match expr {
+            Expr::Name(name, _) => OperandKind::Name(name),
+            expr if expr.is_literal() => {
+                OperandKind::Int(expr.eval(span, &|_, _| unreachable!())?)
+            }
+            expr => OperandKind::Expr(expr),
+        };
+        Ok(Operand { kind, span })
+    }
+}
+
+// parsing helpers
+impl<'a> Parser<'a> {
+    /// Parses the binary operations of an expression
+    /// that bind at least as tightly as a given level
+    ///
+    /// Operators bind the way they do in C, from `|` (the loosest)
+    /// through `^`, `&`, the shifts, and `+` and `-`, up to `*`,
+    /// `/` and `%` (the tightest), and associate to the left.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_prec` - The loosest binding level to parse
+    ///
+    /// # Returns
+    ///
+    /// The parsed expression
+    fn binary(&mut self, min_prec: u8) -> Result<Expr, AsmError> {
+        let mut lhs = self.unary()?;
+        while let Some((op, prec)) = binary_op(&self.peek().kind) {
+            if prec < min_prec {
+                break;
+            }
+            self.next();
+            let rhs = self.binary(prec + 1)?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    /// Parses a unary operation or a single term of an expression
+    ///
+    /// # Returns
+    ///
+    /// The parsed expression
+    fn unary(&mut self) -> Result<Expr, AsmError> {
+        let tok = self.next();
+        match &tok.kind {
+            TokenKind::Minus => match self.peek().kind {
+                // fold the sign into a literal, so that `i64::MIN` can be written
+                TokenKind::Int(val) => {
+                    let num = self.next();
+                    i128::from(val)
+                        .checked_neg()
+                        .and_then(|val| i64::try_from(val).ok())
+                        .map(Expr::Int)
+                        .ok_or_else(|| {
+                            AsmError::new(
+                                tok.span.through(num.span),
+                                &format!("integer literal -{} does not fit in 64 bits", val),
+                            )
+                        })
+                }
+                _ => Ok(Expr::Neg(Box::new(self.unary()?))),
+            },
+            TokenKind::Tilde => Ok(Expr::Not(Box::new(self.unary()?))),
+            TokenKind::Plus => self.unary(),
+            TokenKind::Int(val) => i64::try_from(*val).map(Expr::Int).map_err(|_| {
+                AsmError::new(tok.span, &format!("integer literal {} does not fit in 64 bits", val))
+            }),
+            TokenKind::Ident(name) if ident_operand(name).is_some() => Err(AsmError::new(
+                tok.span,
+                &format!("`{}` cannot be used in an expression", name),
+            )),
+            TokenKind::Ident(name) => Ok(Expr::Name(name.clone(), tok.span)),
+            TokenKind::LParen => {
+                let expr = self.binary(0)?;
+                let close = self.next();
+                if close.kind != TokenKind::RParen {
+                    return Err(AsmError::new(close.span, "expected `)`"));
+                }
+                Ok(expr)
+            }
+            _ => Err(AsmError::new(tok.span, "expected an expression")),
+        }
+    }
+
+    /// Parses an integer literal, which may be negative
+    ///
+    /// # Returns
+    ///
+    /// The value and location of the literal
+    fn int(&mut self) -> Result<(i64, Span), AsmError> {
+        let tok = self.next();
+        let too_big = |val: String, span| {
+            AsmError::new(span, &format!("integer literal {} does not fit in 64 bits", val))
+        };
+        match tok.kind {
+            TokenKind::Int(val) => i64::try_from(val)
+                .map(|val| (val, tok.span))
+                .map_err(|_| too_big(val.to_string(), tok.span)),
+            TokenKind::Minus => {
+                // a negative integer literal, which may be `i64::MIN`
+                let num = self.next();
+                let span = tok.span.through(num.span);
+                match num.kind {
+                    TokenKind::Int(val) => i128::from(val)
+                        .checked_neg()
+                        .and_then(|val| i64::try_from(val).ok())
+                        .map(|val| (val, span))
+                        .ok_or_else(|| too_big(format!("-{}", val), span)),
+                    _ => Err(AsmError::new(num.span, "expected an integer literal after `-`")),
+                }
+            }
+            _ => Err(AsmError::new(tok.span, "expected an integer literal")),
+        }
+    }
+
+    /// Parses a name or an integer literal, such as a block-state value
+    ///
+    /// # Arguments
+    ///
+    /// * `what` - A description of the expected name, used in error messages
+    ///
+    /// # Returns
+    ///
+    /// The text of the name or literal and its location
+    fn word(&mut self, what: &str) -> Result<(String, Span), AsmError> {
+        let tok = self.next();
+        match &tok.kind {
+            TokenKind::Ident(name) => Ok((name.clone(), tok.span)),
+            TokenKind::Int(val) => Ok((val.to_string(), tok.span)),
+            _ => Err(AsmError::new(tok.span, &format!("expected {}", what))),
+        }
+    }
+
+    /// Parses a target selector operand
+    ///
+    /// # Returns
+    ///
+    /// The parsed selector operand
+    fn selector(&mut self) -> Result<Operand, AsmError> {
+        // gather the text of the selector, up to its closing bracket
+        let start = self.next().span;
+        let var = self.next();
+        if !matches!(var.kind, TokenKind::Ident(_)) {
+            return Err(AsmError::new(var.span, "expected a selector variable after `@`"));
+        }
+        let mut end = var.span;
+        let mut text = format!("@{}", var.kind);
+        if self.peek().kind == TokenKind::LBracket {
+            loop {
+                let tok = self.next();
+                match tok.kind {
+                    TokenKind::Newline => {
+                        return Err(AsmError::new(tok.span, "expected `]` to close the selector"));
+                    }
+                    TokenKind::RBracket => {
+                        text.push(']');
+                        end = tok.span;
+                        break;
+                    }
+                    _ => text.push_str(&tok.kind.to_string()),
+                }
+            }
+        }
+
+        let span = start.through(end);
+        match text.parse() {
+            Ok(sel) => Ok(Operand {
+                kind: OperandKind::Selector(sel),
+                span,
+            }),
+            Err(_) => Err(AsmError::new(span, &format!("invalid selector `{}`", text))),
+        }
+    }
+
+    /// Parses a block operand
+    ///
+    /// # Returns
+    ///
+    /// The parsed block operand
+    fn block(&mut self) -> Result<Operand, AsmError> {
+        let (mut id, start) = self.word("a block ID")?;
+        let mut end = start;
+        if self.peek().kind == TokenKind::Colon {
+            self.next();
+            let (path, span) = self.word("a block ID after `:`")?;
+            id = format!("{}:{}", id, path);
+            end = span;
+        }
+        let mut block = BlockState::new(&id);
+
+        // parse the comma-separated properties
+        if self.peek().kind == TokenKind::LBracket {
+            self.next();
+            while self.peek().kind != TokenKind::RBracket {
+                let (key, _) = self.word("a block-state property name")?;
+                let tok = self.next();
+                if tok.kind != TokenKind::Equals {
+                    return Err(AsmError::new(tok.span, "expected `=` after property name"));
+                }
+                let (val, _) = self.word("a block-state property value")?;
+                block = block.with_property(&key, &val);
+                match self.peek().kind {
+                    TokenKind::Comma => {
+                        self.next();
+                    }
+                    TokenKind::RBracket => {}
+                    _ => return Err(AsmError::new(self.peek().span, "expected `,` or `]`")),
+                }
+            }
+            end = self.next().span;
+        }
+
+        Ok(Operand {
+            kind: OperandKind::Block(block),
+            span: start.through(end),
+        })
+    }
+}
+
+/// [Internal use only]
+/// Gets the binary operator spelled by a token
+///
+/// # Arguments
+///
+/// * `kind` - The kind of the token
+///
+/// # Returns
+///
+/// The operator and its binding level, where higher levels
+/// bind more tightly, or `None` if the token is not an operator
+fn binary_op(kind: &TokenKind) -> Option<(BinaryOp, u8)> {
+    let op = match kind {
+        TokenKind::Pipe => (BinaryOp::Or, 1),
+        TokenKind::Caret => (BinaryOp::Xor, 2),
+        TokenKind::Amp => (BinaryOp::And, 3),
+        TokenKind::ShiftLeft => (BinaryOp::Shl, 4),
+        TokenKind::ShiftRight => (BinaryOp::Shr, 4),
+        TokenKind::Plus => (BinaryOp::Add, 5),
+        TokenKind::Minus => (BinaryOp::Sub, 5),
+        TokenKind::Star => (BinaryOp::Mul, 6),
+        TokenKind::Slash => (BinaryOp::Div, 6),
+        TokenKind::Percent => (BinaryOp::Mod, 6),
+        _ => return None,
+    };
+    Some(op)
+}
+
+/// [Internal use only]
+/// Resolves a name used as an operand
+///
+/// # Arguments
+///
+/// * `name` - The name to resolve
+///
+/// # Returns
+///
+/// The register or flag named by `name`, or `None` if it names neither
+pub(crate) fn ident_operand(name: &str) -> Option<OperandKind> {
+    // registers and flags are spelled the way they are displayed
+    if let Some(reg) = Register::iter().find(|r| r.to_string() == name) {
+        return Some(OperandKind::Register(reg));
+    }
+    Flag::iter()
+        .find(|f| f.to_string() == name)
+        .map(OperandKind::Flag)
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the function into scope
+    use super::*;
+    use super::super::lexer::lex;
+
+    // tests parsing lines with operands
+    #[test]
+    fn test_parse_lines() {
+        let stmts = parse(&lex("\nmov -5, r0\njs flag_Zero, loop\n").unwrap()).unwrap();
+        let lines: Vec<&Line> = stmts
+            .iter()
+            .map(|s| match s {
+                Statement::Instr(line) => line,
+                _ => panic!("unexpected label"),
+            })
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].mnemonic, "mov");
+        assert_eq!(lines[0].span, Span::new(2, 1, 3));
+        assert_eq!(lines[0].operands[0].kind, OperandKind::Int(-5));
+        assert_eq!(lines[0].operands[0].span, Span::new(2, 5, 2));
+        assert_eq!(lines[0].operands[1].kind, OperandKind::Register(Register::R0));
+        assert_eq!(lines[1].operands[0].kind, OperandKind::Flag(Flag::Zero));
+        assert_eq!(lines[1].operands[1].kind, OperandKind::Name("loop".to_owned()));
+    }
+
+    // tests parsing label definitions
+    #[test]
+    fn test_parse_labels() {
+        let stmts = parse(&lex("start:\nloop: chk r0\n").unwrap()).unwrap();
+        assert_eq!(stmts.len(), 3);
+        assert_eq!(stmts[0], Statement::Label("start".to_owned(), Span::new(1, 1, 5)));
+        assert_eq!(stmts[1], Statement::Label("loop".to_owned(), Span::new(2, 1, 4)));
+        assert!(matches!(stmts[2], Statement::Instr(_)));
+    }
+
+    // tests parsing coordinates and blocks
+    #[test]
+    fn test_parse_block_operands() {
+        let stmts = parse(&lex("blk ~, ^-3, ~2, minecraft:oak_stairs[facing=north, age=3]\nblk 0, 0, 0, stone[]\n").unwrap()).unwrap();
+        let ops: Vec<&Operand> = stmts
+            .iter()
+            .flat_map(|s| match s {
+                Statement::Instr(line) => line.operands.iter(),
+                _ => panic!("unexpected label"),
+            })
+            .collect();
+        let stairs = BlockState::new("oak_stairs")
+            .with_property("facing", "north")
+            .with_property("age", "3");
+        assert_eq!(ops[0].kind, OperandKind::Relative(0));
+        assert_eq!(ops[1].kind, OperandKind::Local(-3));
+        assert_eq!(ops[1].span, Span::new(1, 8, 3));
+        assert_eq!(ops[2].kind, OperandKind::Relative(2));
+        assert_eq!(ops[3].kind, OperandKind::Block(stairs));
+        assert_eq!(ops[3].span, Span::new(1, 17, 41));
+        assert_eq!(ops[7].kind, OperandKind::Block(BlockState::new("stone")));
+
+        let err = parse(&lex("blk 0, 0, 0, stone[facing north]").unwrap()).unwrap_err();
+        assert_eq!(err.message(), "expected `=` after property name");
+
+        // offsets are checked against the range of 64-bit integers
+        let stmts = parse(&lex("blk ~-9223372036854775808, ^-9223372036854775808, 0, stone").unwrap()).unwrap();
+        match &stmts[0] {
+            Statement::Instr(line) => {
+                assert_eq!(line.operands[0].kind, OperandKind::Relative(i64::MIN));
+                assert_eq!(line.operands[1].kind, OperandKind::Local(i64::MIN));
+            }
+            _ => panic!("unexpected label"),
+        }
+        let err1 = parse(&lex("blk ~18446744073709551615, 0, 0, stone").unwrap()).unwrap_err();
+        let err2 = parse(&lex("blk ^-9223372036854775809, 0, 0, stone").unwrap()).unwrap_err();
+        assert_eq!(err1.message(), "integer literal 18446744073709551615 does not fit in 64 bits");
+        assert_eq!(err1.span(), Span::new(1, 6, 20));
+        assert_eq!(err2.message(), "integer literal -9223372036854775809 does not fit in 64 bits");
+        assert_eq!(err2.span(), Span::new(1, 6, 20));
+    }
+
+    // tests parsing selectors
+    #[test]
+    fn test_parse_selector() {
+        let stmts = parse(&lex("ent @e[type=minecraft:zombie, distance=..8]\n").unwrap()).unwrap();
+        let op = match &stmts[0] {
+            Statement::Instr(line) => &line.operands[0],
+            _ => panic!("unexpected label"),
+        };
+        assert_eq!(
+            op.kind,
+            OperandKind::Selector("@e[type=zombie,distance=..8]".parse().unwrap())
+        );
+        assert_eq!(op.span, Span::new(1, 5, 39));
+
+        let err1 = parse(&lex("ent @e[limit=0]").unwrap()).unwrap_err();
+        let err2 = parse(&lex("ent @e[limit=1").unwrap()).unwrap_err();
+        assert_eq!(err1.message(), "invalid selector `@e[limit=0]`");
+        assert_eq!(err1.span(), Span::new(1, 5, 11));
+        assert_eq!(err2.message(), "expected `]` to close the selector");
+    }
+
+    // tests parsing directives and expressions
+    #[test]
+    fn test_parse_expressions() {
+        let stmts = parse(&lex("len: .const LEN, end - len
+mov 1 + 2 * 3 << 1, r0
+.data -(~0 ^ 2), (end)
+").unwrap()).unwrap();
+        assert_eq!(stmts.len(), 4);
+        let dir = match &stmts[1] {
+            Statement::Directive(line) => line,
+            _ => panic!("expected a directive"),
+        };
+        assert_eq!(dir.mnemonic, ".const");
+        assert_eq!(dir.span, Span::new(1, 6, 6));
+        assert_eq!(dir.operands[0].kind, OperandKind::Name("LEN".to_owned()));
+        assert_eq!(
+            dir.operands[1].kind,
+            OperandKind::Expr(Expr::Binary(
+                BinaryOp::Sub,
+                Box::new(Expr::Name("end".to_owned(), Span::new(1, 18, 3))),
+                Box::new(Expr::Name("len".to_owned(), Span::new(1, 24, 3))),
+            ))
+        );
+        assert_eq!(dir.operands[1].span, Span::new(1, 18, 9));
+        let ops = match (&stmts[2], &stmts[3]) {
+            (Statement::Instr(mov), Statement::Directive(data)) => [&mov.operands[0], &data.operands[0], &data.operands[1]],
+            _ => panic!("expected an instruction and a directive"),
+        };
+        assert_eq!(ops[0].kind, OperandKind::Int(14));
+        assert_eq!(ops[0].span, Span::new(2, 5, 14));
+        assert_eq!(ops[1].kind, OperandKind::Int(3));
+        assert_eq!(ops[2].kind, OperandKind::Name("end".to_owned()));
+
+        let err1 = parse(&lex("mov (1 + 2, r0").unwrap()).unwrap_err();
+        let err2 = parse(&lex("mov 1 + r1, r0").unwrap()).unwrap_err();
+        let err3 = parse(&lex("mov 5 / (2 - 2), r0").unwrap()).unwrap_err();
+        let err4 = parse(&lex("mov 1 +, r0").unwrap()).unwrap_err();
+        assert_eq!(err1.message(), "expected `)`");
+        assert_eq!(err1.span(), Span::new(1, 11, 1));
+        assert_eq!(err2.message(), "`r1` cannot be used in an expression");
+        assert_eq!(err3.message(), "division by zero in constant expression");
+        assert_eq!(err3.span(), Span::new(1, 5, 11));
+        assert_eq!(err4.message(), "expected an expression");
+
+        // a negative literal may reach `i64::MIN`, but no further
+        let stmts = parse(&lex(".data -9223372036854775808, -9223372036854775807 - 1\n").unwrap()).unwrap();
+        match &stmts[0] {
+            Statement::Directive(line) => {
+                assert_eq!(line.operands[0].kind, OperandKind::Int(i64::MIN));
+                assert_eq!(line.operands[1].kind, OperandKind::Int(i64::MIN));
+            }
+            _ => panic!("expected a directive"),
+        }
+        let err5 = parse(&lex(".data -9223372036854775809").unwrap()).unwrap_err();
+        let err6 = parse(&lex(".data -(9223372036854775808)").unwrap()).unwrap_err();
+        assert_eq!(err5.message(), "integer literal -9223372036854775809 does not fit in 64 bits");
+        assert_eq!(err5.span(), Span::new(1, 7, 20));
+        assert_eq!(err6.message(), "integer literal 9223372036854775808 does not fit in 64 bits");
+    }
+
+    // tests parsing malformed lines
+    #[test]
+    fn test_parse_errors() {
+        let err1 = parse(&lex("add r0 r1").unwrap()).unwrap_err();
+        let err2 = parse(&lex("r0: push r1").unwrap()).unwrap_err();
+        let err3 = parse(&lex("5").unwrap()).unwrap_err();
+        assert_eq!(err1.message(), "expected `,` or end of line");
+        assert_eq!(err1.span(), Span::new(1, 8, 2));
+        assert_eq!(err2.message(), "`r0` cannot be used as a label name");
+        assert_eq!(err3.message(), "expected a mnemonic");
+    }
+}
+
+// end of file
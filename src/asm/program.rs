@@ -0,0 +1,102 @@
+/*
+ * program.rs
+ * Defines a struct that holds an assembled program
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::data::Instruction;
+use super::SymbolTable;
+
+/// An assembled program
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Program {
+    /// The instructions of the program
+    instrs: Vec<Instruction>,
+
+    /// The labels defined by the program
+    symbols: SymbolTable,
+
+    /// The values declared by `.data` directives
+    data: Vec<u32>,
+}
+
+// implementation
+impl Program {
+    /// Creates a new `Program` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `instrs` - The instructions of the program
+    /// * `symbols` - The labels defined by the program
+    ///
+    /// # Returns
+    ///
+    /// A new `Program` instance with the given data
+    pub fn new(instrs: Vec<Instruction>, symbols: SymbolTable) -> Self {
+        Program {
+            instrs,
+            symbols,
+            data: Vec::new(),
+        }
+    }
+
+    /// Sets the data declared by the program
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The values that the program's stack starts
+    ///   with, from the bottom of the stack to the top
+    ///
+    /// # Returns
+    ///
+    /// The program with the given data
+    pub fn with_data(mut self, data: Vec<u32>) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Gets the instructions of the program
+    ///
+    /// # Returns
+    ///
+    /// The instructions of the program
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instrs
+    }
+
+    /// Gets the labels defined by the program
+    ///
+    /// # Returns
+    ///
+    /// The program's `SymbolTable`
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    /// Gets the data declared by the program
+    ///
+    /// # Returns
+    ///
+    /// The values that the program's stack starts
+    /// with, from the bottom of the stack to the top
+    pub fn data(&self) -> &[u32] {
+        &self.data
+    }
+
+    /// Consumes the program, keeping only its instructions
+    ///
+    /// # Returns
+    ///
+    /// The instructions of the program
+    pub fn into_instructions(self) -> Vec<Instruction> {
+        self.instrs
+    }
+}
+
+// end of file
@@ -0,0 +1,178 @@
+/*
+ * span.rs
+ * Defines a struct that locates a token in assembly source
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::fmt;
+
+/// The location of a token in assembly source
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The line that the token is on (starting at 1)
+    line: usize,
+
+    /// The column that the token starts at (starting at 1)
+    col: usize,
+
+    /// The length of the token, in characters
+    len: usize,
+
+    /// The source file that the token is in, where 0 is
+    /// the file being assembled and the files that it
+    /// includes are numbered in the order they are read
+    file: usize,
+
+    /// The macro expansion that the token was copied
+    /// into, or 0 if it was written in the source text
+    expansion: usize,
+}
+
+// implementation
+impl Span {
+    /// Creates a new `Span` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line that the token is on (starting at 1)
+    /// * `col` - The column that the token starts at (starting at 1)
+    /// * `len` - The length of the token, in characters
+    ///
+    /// # Returns
+    ///
+    /// A new `Span` instance with the given data
+    pub fn new(line: usize, col: usize, len: usize) -> Self {
+        Span {
+            line,
+            col,
+            len,
+            file: 0,
+            expansion: 0,
+        }
+    }
+
+    /// Gets the line that the token is on
+    ///
+    /// # Returns
+    ///
+    /// The line number of the token, starting at 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Gets the column that the token starts at
+    ///
+    /// # Returns
+    ///
+    /// The column number of the token, starting at 1
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    /// Gets the length of the token
+    ///
+    /// # Returns
+    ///
+    /// The number of characters covered by the token
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// [Internal use only]
+    /// Gets the source file that the token is in
+    ///
+    /// # Returns
+    ///
+    /// The ID of the file, where 0 is the file being assembled
+    pub(crate) fn file(&self) -> usize {
+        self.file
+    }
+
+    /// [Internal use only]
+    /// Moves the span into a source file
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The ID of the file
+    ///
+    /// # Returns
+    ///
+    /// A new `Span` at the same location within the file
+    pub(crate) fn in_file(&self, file: usize) -> Span {
+        Span { file, ..*self }
+    }
+
+    /// [Internal use only]
+    /// Gets the macro expansion that the token was copied into
+    ///
+    /// # Returns
+    ///
+    /// The 1-based ID of the expansion, or 0 if
+    /// the token was written in the source text
+    pub(crate) fn expansion(&self) -> usize {
+        self.expansion
+    }
+
+    /// [Internal use only]
+    /// Marks the span as copied into a macro expansion
+    ///
+    /// # Arguments
+    ///
+    /// * `expansion` - The 1-based ID of the expansion
+    ///
+    /// # Returns
+    ///
+    /// A new `Span` at the same location within the expansion
+    pub(crate) fn in_expansion(&self, expansion: usize) -> Span {
+        Span { expansion, ..*self }
+    }
+
+    /// [Internal use only]
+    /// Creates a span that runs from the start of this span
+    /// to the end of another span on the same line
+    ///
+    /// A macro argument can put tokens from a call site next to
+    /// tokens from a macro body, so if `end` does not follow this
+    /// span on the same line, this span is returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - The span to extend this span through
+    ///
+    /// # Returns
+    ///
+    /// A new `Span` covering both spans
+    pub(crate) fn through(&self, end: Span) -> Span {
+        if end.line != self.line || end.file != self.file || end.expansion != self.expansion || end.col < self.col {
+            return *self;
+        }
+        Span {
+            len: end.col + end.len - self.col,
+            ..*self
+        }
+    }
+
+    /// Determines whether the span covers no characters
+    ///
+    /// # Returns
+    ///
+    /// Whether the span is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+// Display implementation
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.col)
+    }
+}
+
+// end of file
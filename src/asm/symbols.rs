@@ -0,0 +1,178 @@
+/*
+ * symbols.rs
+ * Defines a table mapping label names to label IDs
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::error::AsmError;
+use super::Span;
+use std::collections::HashMap;
+
+/// A table of the labels defined in a program
+///
+/// The ID assigned to a label is the index of the
+/// instruction that follows its definition, so the
+/// same source always produces the same IDs. Several
+/// labels may share an ID if they mark the same
+/// instruction.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolTable {
+    /// The defined labels, in definition order
+    labels: Vec<(String, u32, Span)>,
+
+    /// Maps label names to their index in `labels`
+    by_name: HashMap<String, usize>,
+}
+
+// implementation
+impl SymbolTable {
+    /// Creates a new, empty `SymbolTable` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `SymbolTable` with no labels
+    pub fn new() -> Self {
+        SymbolTable::default()
+    }
+
+    /// Defines a label
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    /// * `id` - The ID of the label
+    /// * `span` - The location of the label's definition
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `AsmError` if the label is already defined
+    pub fn define(&mut self, name: &str, id: u32, span: Span) -> Result<(), AsmError> {
+        if let Some(&idx) = self.by_name.get(name) {
+            return Err(AsmError::new(
+                span,
+                &format!(
+                    "label `{}` is already defined at {}",
+                    name, self.labels[idx].2
+                ),
+            ));
+        }
+
+        self.by_name.insert(name.to_owned(), self.labels.len());
+        self.labels.push((name.to_owned(), id, span));
+        Ok(())
+    }
+
+    /// Gets the ID of a label
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    ///
+    /// # Returns
+    ///
+    /// The ID of the label, or `None` if it is not defined
+    pub fn id(&self, name: &str) -> Option<u32> {
+        self.by_name.get(name).map(|&idx| self.labels[idx].1)
+    }
+
+    /// Gets the name of the label with a given ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the label
+    ///
+    /// # Returns
+    ///
+    /// The name of the first label defined with the
+    /// ID, or `None` if no label has the ID
+    pub fn name(&self, id: u32) -> Option<&str> {
+        self.labels
+            .iter()
+            .find(|(_, lbl_id, _)| *lbl_id == id)
+            .map(|(name, _, _)| name.as_str())
+    }
+
+    /// Gets the location where a label was defined
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    ///
+    /// # Returns
+    ///
+    /// The `Span` of the label's definition, or `None` if it is not defined
+    pub fn span(&self, name: &str) -> Option<Span> {
+        self.by_name.get(name).map(|&idx| self.labels[idx].2)
+    }
+
+    /// Iterates over the defined labels in definition order
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the name and ID of each label
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.labels.iter().map(|(name, id, _)| (name.as_str(), *id))
+    }
+
+    /// Gets the number of defined labels
+    ///
+    /// # Returns
+    ///
+    /// The number of labels in the table
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Determines whether the table has no labels
+    ///
+    /// # Returns
+    ///
+    /// Whether the table is empty
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the struct into scope
+    use super::*;
+
+    // tests defining and looking up labels
+    #[test]
+    fn test_lookup() {
+        let mut table = SymbolTable::new();
+        table.define("start", 0, Span::new(1, 1, 5)).unwrap();
+        table.define("loop", 3, Span::new(4, 1, 4)).unwrap();
+        table.define("again", 3, Span::new(5, 1, 5)).unwrap();
+        assert_eq!(table.id("loop"), Some(3));
+        assert_eq!(table.id("end"), None);
+        assert_eq!(table.name(3), Some("loop"));
+        assert_eq!(table.name(1), None);
+        assert_eq!(table.span("again"), Some(Span::new(5, 1, 5)));
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table.iter().collect::<Vec<_>>(),
+            vec![("start", 0), ("loop", 3), ("again", 3)]
+        );
+    }
+
+    // tests defining a label twice
+    #[test]
+    fn test_duplicate() {
+        let mut table = SymbolTable::new();
+        table.define("loop", 0, Span::new(1, 1, 4)).unwrap();
+        let err = table.define("loop", 2, Span::new(3, 1, 4)).unwrap_err();
+        assert_eq!(err.span(), Span::new(3, 1, 4));
+        assert_eq!(err.message(), "label `loop` is already defined at 1:1");
+    }
+}
+
+// end of file
@@ -0,0 +1,114 @@
+/*
+ * datapack.rs
+ * Defines an in-memory datapack that can be written to disk
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A generated datapack, held in memory
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Datapack {
+    /// The files of the datapack, keyed by their
+    /// `/`-separated path relative to the pack root
+    files: BTreeMap<String, String>,
+}
+
+// implementation
+impl Datapack {
+    /// Creates a new, empty `Datapack` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `Datapack` with no files
+    pub fn new() -> Self {
+        Datapack::default()
+    }
+
+    /// Adds a file to the datapack, replacing any file at the same path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The `/`-separated path of the file, relative to the pack root
+    /// * `contents` - The contents of the file
+    pub fn add_file(&mut self, path: &str, contents: &str) {
+        self.files.insert(path.to_owned(), contents.to_owned());
+    }
+
+    /// Gets the contents of a file in the datapack
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The `/`-separated path of the file, relative to the pack root
+    ///
+    /// # Returns
+    ///
+    /// The contents of the file, or `None` if there is no such file
+    pub fn file(&self, path: &str) -> Option<&str> {
+        self.files.get(path).map(|s| s.as_str())
+    }
+
+    /// Iterates over the files of the datapack in path order
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the path and contents of each file
+    pub fn files(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.files.iter().map(|(p, c)| (p.as_str(), c.as_str()))
+    }
+
+    /// Writes the datapack to disk
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory to write the datapack into,
+    ///   which is created if it does not exist
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or the `io::Error` that stopped the write
+    pub fn write_to(&self, root: &Path) -> io::Result<()> {
+        for (path, contents) in &self.files {
+            let full = path.split('/').fold(root.to_path_buf(), |p, part| p.join(part));
+            if let Some(parent) = full.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(full, contents)?;
+        }
+        Ok(())
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the struct into scope
+    use super::*;
+    use std::env;
+
+    // tests writing a datapack to disk
+    #[test]
+    fn test_write_to() {
+        let mut pack = Datapack::new();
+        pack.add_file("pack.mcmeta", "{}");
+        pack.add_file("data/test/functions/main.mcfunction", "say hi\n");
+
+        let root = env::temp_dir().join(format!("mcisa_datapack_{}", std::process::id()));
+        pack.write_to(&root).unwrap();
+        let main = fs::read_to_string(root.join("data/test/functions/main.mcfunction")).unwrap();
+        assert_eq!(main, "say hi\n");
+        assert_eq!(fs::read_to_string(root.join("pack.mcmeta")).unwrap(), "{}");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
+
+// end of file
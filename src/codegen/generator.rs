@@ -0,0 +1,1190 @@
+/*
+ * generator.rs
+ * Lowers instructions into datapack functions
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Flag;
+use super::super::cpu::Register;
+use super::super::cpu::Status;
+use super::super::data::Coordinate;
+use super::super::data::Instruction;
+use super::super::data::SHLData;
+use super::super::data::SHRData;
+use super::super::error::CodegenError;
+use super::Datapack;
+use std::collections::BTreeSet;
+use strum::IntoEnumIterator;
+
+/// The fake player that holds every CPU score
+pub const HOLDER: &str = "#cpu";
+
+/// The objective that holds the CPU's `Status` as a score
+pub const STATUS_OBJECTIVE: &str = "status";
+
+/// The fake player that holds literal operands
+/// for scoreboard operations that need a score
+pub const LITERAL_HOLDER: &str = "#lit";
+
+/// The tag of the marker entity used to build
+/// positions from register coordinates
+pub const POS_TAG: &str = "mcisa_pos";
+
+/// The pack format written to `pack.mcmeta`
+pub const PACK_FORMAT: u32 = 26;
+
+/// Generates a datapack that runs a program
+///
+/// Every register and flag is a `dummy` scoreboard objective
+/// named after its `Display` form, and the CPU's scores are
+/// held by the fake player `#cpu`. The stack lives in the
+/// `stack` list of the `<namespace>:cpu` command storage,
+/// with the top of the stack at index 0.
+///
+/// The program is split into basic blocks, and each block
+/// becomes a function named `l<index>` after the index of its
+/// first instruction, so jumping to a label is a function call.
+/// The generated functions are:
+///
+/// * `<namespace>:init` - Creates and resets the objectives and stack
+/// * `<namespace>:main` - Runs `init`, and then the program
+/// * `<namespace>:l<index>` - Runs one basic block
+/// * `<namespace>:bitwise/<and|or|xor>` - Combines `#cpu swp` with
+///   `#bit_b swp` one bit at a time (only generated when used)
+///
+/// Scoreboards have no bitwise operators, so `SHL` and `SHR`
+/// multiply and floor-divide by powers of two, `NOT` subtracts
+/// from -1, and `AND`, `OR` and `XOR` call a helper function that
+/// runs the unrolled bit-by-bit loop described in `emu::arith`.
+///
+/// Instructions that read coordinates from registers summon a
+/// temporary marker entity tagged `mcisa_pos`, copy the registers
+/// into its position, and run their check at the marker.
+/// Checks at local coordinates run `rotated 0 0`, so that, as in
+/// the emulator, `^` is measured facing south with no pitch rather
+/// than from wherever the function's executor happens to face.
+///
+/// The `status` objective holds the score of the CPU's `Status`.
+/// `HLT` sets it to halted, and reaching the end of the program,
+/// either by running its last instruction or by jumping to the
+/// label at its end, sets it to finished. Either one then returns
+/// from the current block, which unwinds the whole program.
+///
+/// Every block transfer is in tail position, so `CALL` simply
+/// calls the subroutine's block function and then continues
+/// with the next block once it returns, and `RET` lowers to
+/// `return 0`, which unwinds the chain of tail calls back to the
+/// `CALL`. The call stack is therefore Minecraft's own function
+/// call stack, and is separate from the `stack` storage list.
+/// After the subroutine returns, `CALL` also returns if the CPU
+/// is no longer running, so that halting stops the caller too.
+/// A `RET` that can run outside of any subroutine would quietly
+/// stop the program, where the emulator reports a call stack
+/// underflow, so it is rejected instead.
+///
+/// Since every jump is a nested function call, a program can
+/// run for at most `maxCommandChainLength` commands per call
+/// of `main`.
+///
+/// # Arguments
+///
+/// * `program` - The instructions of the program
+/// * `namespace` - The namespace of the generated functions
+///
+/// # Returns
+///
+/// The generated `Datapack`, or a `CodegenError` if the
+/// namespace or an instruction is invalid
+pub fn generate(program: &[Instruction], namespace: &str) -> Result<Datapack, CodegenError> {
+    generate_with_data(program, &[], namespace)
+}
+
+/// Generates a datapack that runs a program whose stack
+/// starts out holding some data, such as the data declared
+/// by an assembled `Program`
+///
+/// `init` fills the `stack` storage list with the data and sets
+/// `sr` to the value on top. Otherwise, the datapack is laid out
+/// the same way as by `generate`.
+///
+/// # Arguments
+///
+/// * `program` - The instructions of the program
+/// * `data` - The initial contents of the stack,
+///   from the bottom of the stack to the top
+/// * `namespace` - The namespace of the generated functions
+///
+/// # Returns
+///
+/// The generated `Datapack`, or a `CodegenError` if the
+/// namespace or an instruction is invalid
+pub fn generate_with_data(program: &[Instruction], data: &[u32], namespace: &str) -> Result<Datapack, CodegenError> {
+    // make sure that the namespace is valid
+    let valid = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || "_-.".contains(c);
+    if namespace.is_empty() || !namespace.chars().all(valid) {
+        return Err(CodegenError::new(&format!(
+            "invalid namespace `{}`",
+            namespace
+        )));
+    }
+
+    let gen = Generator {
+        ns: namespace,
+        program,
+        data,
+    };
+    let mut pack = Datapack::new();
+    pack.add_file(
+        "pack.mcmeta",
+        &format!(
+            "{{\n  \"pack\": {{\n    \"pack_format\": {},\n    \"description\": \"Generated by mcisa\"\n  }}\n}}\n",
+            PACK_FORMAT
+        ),
+    );
+    pack.add_file(&gen.path("init"), &gen.init());
+
+    // the entry point resets the CPU and then runs the first block
+    let mut main = format!("function {}:init\n", namespace);
+    if program.is_empty() {
+        main.push_str(&finish());
+    } else {
+        main.push_str(&format!("function {}\n", gen.block_name(0)));
+    }
+    pack.add_file(&gen.path("main"), &main);
+
+    // lower each basic block into its own function
+    let leaders = gen.leaders()?;
+    gen.check_returns()?;
+    let bounds: Vec<usize> = leaders.iter().copied().collect();
+    for (i, &start) in bounds.iter().enumerate() {
+        let end = bounds.get(i + 1).copied().unwrap_or(program.len()).max(start);
+        let mut body = String::new();
+        for (pc, instr) in program.iter().enumerate().take(end).skip(start) {
+            for cmd in gen.lower(pc, instr)? {
+                body.push_str(&cmd);
+                body.push('\n');
+            }
+        }
+
+        // fall through into the next block, or
+        // finish if this is the end of the program
+        let transfers = matches!(
+            program[start..end].last(),
+            Some(Instruction::Jmp(_)) | Some(Instruction::Ret(_)) | Some(Instruction::Hlt(_))
+        );
+        if !transfers && end < program.len() {
+            body.push_str(&format!("function {}\n", gen.block_name(end)));
+        } else if !transfers {
+            body.push_str(&finish());
+        }
+
+        pack.add_file(&gen.path(&format!("l{}", start)), &body);
+    }
+
+    // add the helpers for the bitwise instructions that the program uses
+    let helpers: BTreeSet<&str> = program
+        .iter()
+        .filter(|i| matches!(i, Instruction::And(_) | Instruction::Or(_) | Instruction::Xor(_)))
+        .map(|i| i.mnemonic())
+        .collect();
+    for name in helpers {
+        pack.add_file(&gen.path(&format!("bitwise/{}", name)), &bitwise_helper(name));
+    }
+
+    Ok(pack)
+}
+
+/// [Internal use only]
+/// Holds the state of the generator
+struct Generator<'a> {
+    /// The namespace of the generated functions
+    ns: &'a str,
+
+    /// The instructions of the program
+    program: &'a [Instruction],
+
+    /// The initial contents of the stack, from the bottom to the top
+    data: &'a [u32],
+}
+
+// implementation
+impl<'a> Generator<'a> {
+    /// Gets the path of a function file
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function
+    ///
+    /// # Returns
+    ///
+    /// The path of the function, relative to the pack root
+    fn path(&self, name: &str) -> String {
+        format!("data/{}/functions/{}.mcfunction", self.ns, name)
+    }
+
+    /// Gets the namespaced name of the function for a block
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The index of the first instruction in the block
+    ///
+    /// # Returns
+    ///
+    /// The resource location of the block's function
+    fn block_name(&self, start: usize) -> String {
+        format!("{}:l{}", self.ns, start)
+    }
+
+    /// Generates the body of the `init` function
+    ///
+    /// # Returns
+    ///
+    /// The commands that create and reset the CPU state
+    fn init(&self) -> String {
+        let mut body = String::new();
+        let objectives = Register::iter()
+            .map(|r| r.to_string())
+            .chain(Flag::iter().map(|f| f.to_string()))
+            .chain(std::iter::once(STATUS_OBJECTIVE.to_owned()));
+        for obj in objectives {
+            body.push_str(&format!("scoreboard objectives add {} dummy\n", obj));
+            body.push_str(&format!("scoreboard players set {} {} 0\n", HOLDER, obj));
+        }
+        let stack: Vec<String> = self.data.iter().rev().map(|&val| (val as i32).to_string()).collect();
+        body.push_str(&format!(
+            "data modify storage {}:cpu stack set value [{}]\n",
+            self.ns,
+            stack.join(", ")
+        ));
+        if let Some(&top) = self.data.last() {
+            body.push_str(&format!("scoreboard players set {} sr {}\n", HOLDER, top as i32));
+        }
+        body
+    }
+
+    /// Finds the first instruction of every basic block
+    ///
+    /// # Returns
+    ///
+    /// The sorted indices of every block's first instruction,
+    /// or a `CodegenError` if a label is out of range
+    fn leaders(&self) -> Result<BTreeSet<usize>, CodegenError> {
+        let mut leaders = BTreeSet::new();
+        if !self.program.is_empty() {
+            leaders.insert(0);
+        }
+
+        for (pc, instr) in self.program.iter().enumerate() {
+            // returns and halts end their blocks
+            if let Instruction::Ret(_) | Instruction::Hlt(_) = instr {
+                if pc + 1 < self.program.len() {
+                    leaders.insert(pc + 1);
+                }
+            }
+
+            if let Some(id) = instr.jmp_label_id() {
+                // a jump to the end of the program gets an empty block
+                let id = id as usize;
+                if id > self.program.len() {
+                    return Err(CodegenError::at(
+                        pc,
+                        &format!("label ID {} is out of range", id),
+                    ));
+                }
+                leaders.insert(id);
+                if pc + 1 < self.program.len() {
+                    leaders.insert(pc + 1);
+                }
+            }
+        }
+
+        Ok(leaders)
+    }
+
+    /// Makes sure that no `RET` can run outside of a subroutine
+    ///
+    /// Every path from the start of the program is followed up to the
+    /// first `CALL` on it, taking both sides of conditional jumps, so
+    /// a `RET` is rejected if any such path can reach it.
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or a `CodegenError` if a `RET` can run without a matching `CALL`
+    fn check_returns(&self) -> Result<(), CodegenError> {
+        let mut seen = vec![false; self.program.len()];
+        let mut todo = vec![0];
+        while let Some(pc) = todo.pop() {
+            if pc >= self.program.len() || seen[pc] {
+                continue;
+            }
+            seen[pc] = true;
+            match &self.program[pc] {
+                Instruction::Ret(_) => {
+                    return Err(CodegenError::at(
+                        pc,
+                        "RET can run without a matching CALL, which underflows the call stack",
+                    ));
+                }
+                // the subroutine returns to the next instruction
+                Instruction::Call(_) => todo.push(pc + 1),
+                Instruction::Hlt(_) => {}
+                Instruction::Jmp(d) => todo.push(d.jmp_label_id() as usize),
+                instr => {
+                    todo.push(pc + 1);
+                    todo.extend(instr.jmp_label_id().map(|id| id as usize));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lowers a bitwise instruction into a call to its helper function
+    ///
+    /// # Arguments
+    ///
+    /// * `pc` - The index of the instruction
+    /// * `name` - The name of the helper function, which is the instruction's mnemonic
+    /// * `arg0` - The first argument `Register`
+    /// * `lit` - The second argument, if it is a literal
+    /// * `reg` - The second argument, if it is a register
+    /// * `dest` - The destination `Register`
+    ///
+    /// # Returns
+    ///
+    /// The commands for the instruction, or a `CodegenError` if a register is invalid
+    fn bitwise(&self, pc: usize, name: &str, arg0: Register, lit: Option<u32>, reg: Option<Register>, dest: Register) -> Result<Vec<String>, CodegenError> {
+        let load_b = match (lit, reg) {
+            (Some(lit), _) => format!("scoreboard players set #bit_b swp {}", lit as i32),
+            (None, Some(reg)) => format!("scoreboard players operation #bit_b swp = {}", score(pc, reg)?),
+            (None, None) => unreachable!("bitwise instruction has no second argument"),
+        };
+        Ok(vec![
+            format!("scoreboard players operation {} swp = {}", HOLDER, score(pc, arg0)?),
+            load_b,
+            format!("function {}:bitwise/{}", self.ns, name),
+            format!("scoreboard players operation {} = {} swp", score(pc, dest)?, HOLDER),
+        ])
+    }
+
+    /// Lowers a single instruction into commands
+    ///
+    /// # Arguments
+    ///
+    /// * `pc` - The index of the instruction
+    /// * `instr` - The instruction to lower
+    ///
+    /// # Returns
+    ///
+    /// The commands for the instruction, or a `CodegenError`
+    /// if one of its operands is invalid
+    fn lower(&self, pc: usize, instr: &Instruction) -> Result<Vec<String>, CodegenError> {
+        let cmds = match instr {
+            Instruction::Mov(d) => {
+                let dest = score(pc, d.dest())?;
+                match d.src_literal() {
+                    Some(lit) => vec![format!("scoreboard players set {} {}", dest, lit as i32)],
+                    None => vec![format!(
+                        "scoreboard players operation {} = {}",
+                        dest,
+                        score(pc, d.unwrap_src_register())?
+                    )],
+                }
+            }
+            Instruction::Exg(d) => {
+                let a = score(pc, d.arg0())?;
+                let b = score(pc, d.arg1())?;
+                vec![
+                    format!("scoreboard players operation {} swp = {}", HOLDER, a),
+                    format!("scoreboard players operation {} = {}", a, b),
+                    format!("scoreboard players operation {} = {} swp", b, HOLDER),
+                ]
+            }
+            Instruction::Add(d) => math(pc, "+=", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Sub(d) => math(pc, "-=", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Mul(d) => math(pc, "*=", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Div(d) => math(pc, "/=", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Mod(d) => math(pc, "%=", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Min(d) => math(pc, "<", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Max(d) => math(pc, ">", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::And(d) => self.bitwise(pc, "and", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Or(d) => self.bitwise(pc, "or", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Xor(d) => self.bitwise(pc, "xor", d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+            Instruction::Not(d) => vec![
+                format!("scoreboard players set {} swp -1", HOLDER),
+                format!("scoreboard players operation {} swp -= {}", HOLDER, score(pc, d.arg0())?),
+                format!("scoreboard players operation {} = {} swp", score(pc, d.dest())?, HOLDER),
+            ],
+            Instruction::Shl(d) => shift(pc, "*=", d.arg0(), d.amount(), SHLData::MAX_AMOUNT, d.dest())?,
+            Instruction::Shr(d) => shift(pc, "/=", d.arg0(), d.amount(), SHRData::MAX_AMOUNT, d.dest())?,
+            Instruction::Inc(d) => vec![format!("scoreboard players add {} 1", score(pc, d.arg0())?)],
+            Instruction::Dec(d) => vec![format!("scoreboard players remove {} 1", score(pc, d.arg0())?)],
+            Instruction::Neg(d) => vec![
+                format!("scoreboard players set {} swp -1", LITERAL_HOLDER),
+                format!(
+                    "scoreboard players operation {} *= {} swp",
+                    score(pc, d.arg0())?,
+                    LITERAL_HOLDER
+                ),
+            ],
+            Instruction::Push(d) => {
+                let src = score(pc, d.arg0())?;
+                vec![
+                    format!("data modify storage {}:cpu stack prepend value 0", self.ns),
+                    format!(
+                        "execute store result storage {}:cpu stack[0] int 1 run scoreboard players get {}",
+                        self.ns, src
+                    ),
+                    format!(
+                        "scoreboard players operation {} sr = {}",
+                        HOLDER, src
+                    ),
+                ]
+            }
+            Instruction::Pop(d) => {
+                let dest = score(pc, d.arg0())?;
+                vec![
+                    format!(
+                        "execute store result score {} run data get storage {}:cpu stack[0]",
+                        dest, self.ns
+                    ),
+                    format!("data remove storage {}:cpu stack[0]", self.ns),
+                    format!(
+                        "execute store result score {} sr run data get storage {}:cpu stack[0]",
+                        HOLDER, self.ns
+                    ),
+                ]
+            }
+            Instruction::Jmp(d) => vec![format!(
+                "function {}",
+                self.block_name(d.jmp_label_id() as usize)
+            )],
+            Instruction::Jcc(d) => {
+                let (flag, set) = d.condition().flag_test();
+                vec![format!(
+                    "execute {} score {} {} matches 1 run return run function {}",
+                    if set { "if" } else { "unless" },
+                    HOLDER,
+                    flag,
+                    self.block_name(d.jmp_label_id() as usize)
+                )]
+            }
+            Instruction::Call(d) => vec![
+                format!("function {}", self.block_name(d.jmp_label_id() as usize)),
+                format!(
+                    "execute unless score {} {} matches {} run return 0",
+                    HOLDER,
+                    STATUS_OBJECTIVE,
+                    i32::from(Status::Running)
+                ),
+            ],
+            Instruction::Ret(_) => vec!["return 0".to_owned()],
+            Instruction::Hlt(_) => vec![
+                format!(
+                    "scoreboard players set {} {} {}",
+                    HOLDER,
+                    STATUS_OBJECTIVE,
+                    i32::from(Status::Halted)
+                ),
+                "return 0".to_owned(),
+            ],
+            Instruction::Nop(_) => Vec::new(),
+            Instruction::Chk(d) => {
+                let src = score(pc, d.chk_reg())?;
+                vec![
+                    format!(
+                        "execute store success score {} {} if score {} matches 0",
+                        HOLDER,
+                        Flag::Zero,
+                        src
+                    ),
+                    format!(
+                        "execute store success score {} {} if score {} matches ..-1",
+                        HOLDER,
+                        Flag::Negative,
+                        src
+                    ),
+                ]
+            }
+            Instruction::Cmp(d) if d.has_literal_arg1() => {
+                let a = score(pc, d.arg0())?;
+                let lit = d.arg1_literal().unwrap() as i32;
+                let test = |flag: Flag, range: Option<String>| match range {
+                    Some(range) => format!(
+                        "execute store success score {} {} if score {} matches {}",
+                        HOLDER, flag, a, range
+                    ),
+                    None => format!("scoreboard players set {} {} 0", HOLDER, flag),
+                };
+
+                // nothing is greater than the maximum or less than the minimum
+                vec![
+                    test(Flag::Equal, Some(lit.to_string())),
+                    test(Flag::GreaterThan, lit.checked_add(1).map(|n| format!("{}..", n))),
+                    test(Flag::LessThan, lit.checked_sub(1).map(|n| format!("..{}", n))),
+                ]
+            }
+            Instruction::Cmp(d) => {
+                let a = score(pc, d.arg0())?;
+                let b = score(pc, d.arg1())?;
+                [(Flag::Equal, "="), (Flag::GreaterThan, ">"), (Flag::LessThan, "<")]
+                    .iter()
+                    .map(|(flag, op)| {
+                        format!(
+                            "execute store success score {} {} if score {} {} {}",
+                            HOLDER, flag, a, op, b
+                        )
+                    })
+                    .collect()
+            }
+            Instruction::Js(d) => vec![format!(
+                "execute if score {} {} matches 1 run return run function {}",
+                HOLDER,
+                d.cond_flag(),
+                self.block_name(d.jmp_label_id() as usize)
+            )],
+            Instruction::Jns(d) => vec![format!(
+                "execute unless score {} {} matches 1 run return run function {}",
+                HOLDER,
+                d.cond_flag(),
+                self.block_name(d.jmp_label_id() as usize)
+            )],
+            Instruction::Blk(d) => {
+                if !d.has_valid_coordinates() {
+                    return Err(CodegenError::at(
+                        pc,
+                        "local coordinates cannot be mixed with other coordinates",
+                    ));
+                }
+                let check = |pos: &str| format!("if block {} {}", pos, d.block());
+                at_position(pc, d.coordinates(), &check, Flag::Block)?
+            }
+            Instruction::Ent(d) => vec![format!(
+                "execute store success score {} {} if entity {}",
+                HOLDER,
+                Flag::Entity,
+                d.selector()
+            )],
+        };
+
+        Ok(cmds)
+    }
+}
+
+/// [Internal use only]
+/// Generates the command that marks the program as finished
+///
+/// # Returns
+///
+/// The command, followed by a newline
+fn finish() -> String {
+    format!(
+        "scoreboard players set {} {} {}\n",
+        HOLDER,
+        STATUS_OBJECTIVE,
+        i32::from(Status::Finished)
+    )
+}
+
+/// [Internal use only]
+/// Gets the score that holds a userspace register
+///
+/// # Arguments
+///
+/// * `pc` - The index of the instruction using the register
+/// * `reg` - The `Register` to get the score of
+///
+/// # Returns
+///
+/// The score holder and objective for the register, or
+/// a `CodegenError` if the register is `SR` or `SWP`
+fn score(pc: usize, reg: Register) -> Result<String, CodegenError> {
+    match reg {
+        Register::SR | Register::SWP => Err(CodegenError::at(
+            pc,
+            &format!("{} cannot be accessed from userspace code", reg),
+        )),
+        _ => Ok(format!("{} {}", HOLDER, reg)),
+    }
+}
+
+/// [Internal use only]
+/// Lowers a math instruction through the swap register
+///
+/// Literal addends use `scoreboard players add` and `remove`,
+/// and other literals are set on `LITERAL_HOLDER` first.
+///
+/// # Arguments
+///
+/// * `pc` - The index of the instruction
+/// * `op` - The scoreboard operation to apply
+/// * `arg0` - The first argument `Register`
+/// * `lit` - The second argument, if it is a literal
+/// * `reg` - The second argument, if it is a register
+/// * `dest` - The destination `Register`
+///
+/// # Returns
+///
+/// The commands for the instruction, or a `CodegenError` if a register is invalid
+fn math(pc: usize, op: &str, arg0: Register, lit: Option<u32>, reg: Option<Register>, dest: Register) -> Result<Vec<String>, CodegenError> {
+    let mut cmds = vec![format!(
+        "scoreboard players operation {} swp = {}",
+        HOLDER,
+        score(pc, arg0)?
+    )];
+    match (lit.map(|l| l as i32), reg, op) {
+        (Some(lit), _, "+=") => cmds.extend(add_literal(lit)),
+        (Some(lit), _, "-=") => cmds.extend(add_literal(lit.wrapping_neg())),
+        (Some(lit), _, _) => {
+            cmds.push(format!("scoreboard players set {} swp {}", LITERAL_HOLDER, lit));
+            cmds.push(format!(
+                "scoreboard players operation {} swp {} {} swp",
+                HOLDER, op, LITERAL_HOLDER
+            ));
+        }
+        (None, Some(reg), _) => cmds.push(format!(
+            "scoreboard players operation {} swp {} {}",
+            HOLDER,
+            op,
+            score(pc, reg)?
+        )),
+        (None, None, _) => unreachable!("math instruction has no second argument"),
+    }
+    cmds.push(format!(
+        "scoreboard players operation {} = {} swp",
+        score(pc, dest)?,
+        HOLDER
+    ));
+    Ok(cmds)
+}
+
+/// [Internal use only]
+/// Lowers a shift instruction into a multiplication
+/// or floored division by a power of two
+///
+/// # Arguments
+///
+/// * `pc` - The index of the instruction
+/// * `op` - The scoreboard operation to apply, either `*=` or `/=`
+/// * `arg0` - The `Register` being shifted
+/// * `amount` - The number of bits to shift by
+/// * `max` - The largest valid shift amount
+/// * `dest` - The destination `Register`
+///
+/// # Returns
+///
+/// The commands for the instruction, or a `CodegenError` if
+/// a register is invalid or the shift amount is out of range
+fn shift(pc: usize, op: &str, arg0: Register, amount: u32, max: u32, dest: Register) -> Result<Vec<String>, CodegenError> {
+    if amount > max {
+        return Err(CodegenError::at(
+            pc,
+            &format!("shift amount {} is out of range (expected 0 to {})", amount, max),
+        ));
+    }
+
+    // 2^31 does not fit in a score, so a right shift
+    // by 31 divides by 2^30 and then by 2
+    if op == "/=" && amount == 31 {
+        let mut cmds = math(pc, op, arg0, Some(1 << 30), None, dest)?;
+        let store = cmds.pop().unwrap();
+        cmds.push(format!("scoreboard players set {} swp 2", LITERAL_HOLDER));
+        cmds.push(format!(
+            "scoreboard players operation {} swp /= {} swp",
+            HOLDER, LITERAL_HOLDER
+        ));
+        cmds.push(store);
+        return Ok(cmds);
+    }
+    math(pc, op, arg0, Some(1 << amount), None, dest)
+}
+
+/// [Internal use only]
+/// Generates the body of a bitwise helper function
+///
+/// The helper combines `#cpu swp` with `#bit_b swp` and leaves
+/// the result in `#cpu swp`. Each of its 32 unrolled rounds takes
+/// the low bit of both scores with `%= 2`, combines the two bits,
+/// adds the combined bit times its place value to the result, and
+/// then shifts both scores right with `/= 2`.
+///
+/// # Arguments
+///
+/// * `name` - The mnemonic of the instruction, which is `and`, `or` or `xor`
+///
+/// # Returns
+///
+/// The commands of the helper function
+fn bitwise_helper(name: &str) -> String {
+    let op = |a: &str, op: &str, b: &str| format!("scoreboard players operation {} swp {} {} swp\n", a, op, b);
+    let combine = match name {
+        "and" => op("#bit_x", "<", "#bit_y"),
+        "or" => op("#bit_x", ">", "#bit_y"),
+        _ => op("#bit_x", "+=", "#bit_y") + &op("#bit_x", "%=", LITERAL_HOLDER),
+    };
+
+    let mut body = op("#bit_a", "=", HOLDER);
+    body.push_str(&format!("scoreboard players set {} swp 0\n", HOLDER));
+    body.push_str("scoreboard players set #bit_p swp 1\n");
+    body.push_str(&format!("scoreboard players set {} swp 2\n", LITERAL_HOLDER));
+    for _ in 0..32 {
+        body.push_str(&op("#bit_x", "=", "#bit_a"));
+        body.push_str(&op("#bit_x", "%=", LITERAL_HOLDER));
+        body.push_str(&op("#bit_y", "=", "#bit_b"));
+        body.push_str(&op("#bit_y", "%=", LITERAL_HOLDER));
+        body.push_str(&combine);
+        body.push_str(&op("#bit_x", "*=", "#bit_p"));
+        body.push_str(&op(HOLDER, "+=", "#bit_x"));
+        body.push_str(&op("#bit_a", "/=", LITERAL_HOLDER));
+        body.push_str(&op("#bit_b", "/=", LITERAL_HOLDER));
+        body.push_str(&op("#bit_p", "*=", LITERAL_HOLDER));
+    }
+    body
+}
+
+/// [Internal use only]
+/// Adds a literal to the swap register
+///
+/// `scoreboard players add` and `remove` only accept
+/// nonnegative amounts, so negative literals are removed
+/// instead, and the minimum literal is removed in two steps.
+///
+/// # Arguments
+///
+/// * `lit` - The literal to add
+///
+/// # Returns
+///
+/// The commands that add the literal
+fn add_literal(lit: i32) -> Vec<String> {
+    let cmd = |verb: &str, n: i64| format!("scoreboard players {} {} swp {}", verb, HOLDER, n);
+    match lit {
+        i32::MIN => vec![cmd("remove", i32::MAX as i64), cmd("remove", 1)],
+        n if n < 0 => vec![cmd("remove", -(n as i64))],
+        n => vec![cmd("add", n as i64)],
+    }
+}
+
+/// [Internal use only]
+/// Lowers a check at a position into commands that store its result in a flag
+///
+/// # Arguments
+///
+/// * `pc` - The index of the instruction
+/// * `pos` - The x, y, and z coordinates to check at
+/// * `check` - Builds the `execute` subcommand for the check
+///   from the space-separated position to check at
+/// * `flag` - The `Flag` that stores whether the check succeeded
+///
+/// # Returns
+///
+/// The commands for the check, or a `CodegenError` if a register is invalid
+fn at_position(pc: usize, pos: [Coordinate; 3], check: &dyn Fn(&str) -> String, flag: Flag) -> Result<Vec<String>, CodegenError> {
+    let regs: Vec<(usize, Register)> = pos
+        .iter()
+        .enumerate()
+        .filter_map(|(axis, c)| match c {
+            Coordinate::Register(r) => Some((axis, *r)),
+            _ => None,
+        })
+        .collect();
+    let store = format!("execute store success score {} {}", HOLDER, flag);
+
+    // constant positions can be checked directly, facing
+    // south if they are local (local coordinates can't be
+    // mixed with registers, so the marker is never rotated)
+    if regs.is_empty() {
+        let rotate = match pos[0] {
+            Coordinate::Local(_) => "rotated 0 0 ",
+            _ => "",
+        };
+        let pos: Vec<String> = pos.iter().map(|c| c.to_string()).collect();
+        return Ok(vec![format!("{} {}{}", store, rotate, check(&pos.join(" ")))]);
+    }
+
+    // otherwise, build the position on a marker and check there
+    let marker = format!("@e[type=minecraft:marker,tag={},limit=1]", POS_TAG);
+    let summon: Vec<String> = pos
+        .iter()
+        .map(|c| match c {
+            Coordinate::Register(_) => "~".to_owned(),
+            _ => c.to_string(),
+        })
+        .collect();
+    let mut cmds = vec![format!(
+        "summon minecraft:marker {} {{Tags:[\"{}\"]}}",
+        summon.join(" "),
+        POS_TAG
+    )];
+    for (axis, reg) in regs {
+        cmds.push(format!(
+            "execute store result entity {} Pos[{}] double 1 run scoreboard players get {}",
+            marker,
+            axis,
+            score(pc, reg)?
+        ));
+    }
+    cmds.push(format!("{} at {} {}", store, marker, check("~ ~ ~")));
+    cmds.push(format!("kill @e[type=minecraft:marker,tag={}]", POS_TAG));
+    Ok(cmds)
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the function into scope
+    use super::*;
+    use super::super::super::asm::assemble;
+
+    // assembles a program and generates a datapack for it
+    fn gen(src: &str) -> Datapack {
+        generate(assemble(src).unwrap().instructions(), "test").unwrap()
+    }
+
+    // tests the layout of the generated datapack
+    #[test]
+    fn test_layout() {
+        let pack = gen("mov 5, r0\nloop: chk r0\njs flag_Zero, done\njmp loop\ndone:\n");
+        let paths: Vec<&str> = pack.files().map(|(p, _)| p).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "data/test/functions/init.mcfunction",
+                "data/test/functions/l0.mcfunction",
+                "data/test/functions/l1.mcfunction",
+                "data/test/functions/l3.mcfunction",
+                "data/test/functions/l4.mcfunction",
+                "data/test/functions/main.mcfunction",
+                "pack.mcmeta",
+            ]
+        );
+        assert_eq!(
+            pack.file("data/test/functions/main.mcfunction").unwrap(),
+            "function test:init\nfunction test:l0\n"
+        );
+        assert!(pack.file("pack.mcmeta").unwrap().contains("\"pack_format\": 26"));
+        assert!(pack
+            .file("data/test/functions/init.mcfunction")
+            .unwrap()
+            .contains("scoreboard objectives add flag_GreaterThan dummy\n"));
+    }
+
+    // tests lowering branches into function calls
+    #[test]
+    fn test_branches() {
+        let pack = gen("mov 5, r0\nloop: chk r0\njs flag_Zero, done\njmp loop\ndone:\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "scoreboard players set #cpu r0 5\nfunction test:l1\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l1.mcfunction").unwrap(),
+            "execute store success score #cpu flag_Zero if score #cpu r0 matches 0\n\
+             execute store success score #cpu flag_Negative if score #cpu r0 matches ..-1\n\
+             execute if score #cpu flag_Zero matches 1 run return run function test:l4\n\
+             function test:l3\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l3.mcfunction").unwrap(),
+            "function test:l1\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l4.mcfunction").unwrap(),
+            "scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering condition-code jumps into single flag tests
+    #[test]
+    fn test_jcc() {
+        let pack = gen("cmp r0, r1\njge end\njle end\njnz end\nend:\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "execute store success score #cpu flag_Equal if score #cpu r0 = #cpu r1\n\
+             execute store success score #cpu flag_GreaterThan if score #cpu r0 > #cpu r1\n\
+             execute store success score #cpu flag_LessThan if score #cpu r0 < #cpu r1\n\
+             execute unless score #cpu flag_LessThan matches 1 run return run function test:l4\n\
+             function test:l2\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l2.mcfunction").unwrap(),
+            "execute unless score #cpu flag_GreaterThan matches 1 run return run function test:l4\n\
+             function test:l3\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l3.mcfunction").unwrap(),
+            "execute unless score #cpu flag_Zero matches 1 run return run function test:l4\n\
+             scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering subroutine calls and returns
+    #[test]
+    fn test_call_ret() {
+        let pack = gen("call sub\nmov 1, r0\njmp end\nsub: mov 2, r1\nret\nend:\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "function test:l3\n\
+             execute unless score #cpu status matches 0 run return 0\n\
+             function test:l1\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l1.mcfunction").unwrap(),
+            "scoreboard players set #cpu r0 1\nfunction test:l5\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l3.mcfunction").unwrap(),
+            "scoreboard players set #cpu r1 2\nreturn 0\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l5.mcfunction").unwrap(),
+            "scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering halts, no-ops, and the end of the program
+    #[test]
+    fn test_termination() {
+        let pack = gen("nop\nhlt\nmov 1, r0\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "scoreboard players set #cpu status 1\nreturn 0\n"
+        );
+        assert_eq!(
+            pack.file("data/test/functions/l2.mcfunction").unwrap(),
+            "scoreboard players set #cpu r0 1\nscoreboard players set #cpu status 2\n"
+        );
+        assert!(pack
+            .file("data/test/functions/init.mcfunction")
+            .unwrap()
+            .contains("scoreboard objectives add status dummy\nscoreboard players set #cpu status 0\n"));
+
+        // an empty program finishes immediately
+        let pack = generate(&[], "test").unwrap();
+        assert_eq!(
+            pack.file("data/test/functions/main.mcfunction").unwrap(),
+            "function test:init\nscoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests rejecting returns that can run outside of a subroutine
+    #[test]
+    fn test_unmatched_ret() {
+        let prog1 = assemble("mov 1, r0\nret\n").unwrap();
+        let prog2 = assemble("chk r0\njs flag_Zero, sub\njmp end\nsub: ret\nend:\n").unwrap();
+        let err1 = generate(prog1.instructions(), "test").unwrap_err();
+        let err2 = generate(prog2.instructions(), "test").unwrap_err();
+        assert_eq!(err1.pc(), Some(1));
+        assert_eq!(
+            err1.reason(),
+            "RET can run without a matching CALL, which underflows the call stack"
+        );
+        assert_eq!(err2.pc(), Some(3));
+
+        // halting keeps the program from reaching a subroutine
+        let prog3 = assemble("call sub\nhlt\nsub: ret\n").unwrap();
+        assert!(generate(prog3.instructions(), "test").is_ok());
+    }
+
+    // tests filling the stack with a program's data
+    #[test]
+    fn test_data() {
+        let prog = assemble(".data 1, -2, 0xFFFFFFFE\n.data 7\npop r0\n").unwrap();
+        let pack = generate_with_data(prog.instructions(), prog.data(), "test").unwrap();
+        assert!(pack
+            .file("data/test/functions/init.mcfunction")
+            .unwrap()
+            .ends_with("data modify storage test:cpu stack set value [7, -2, -2, 1]\nscoreboard players set #cpu sr 7\n"));
+    }
+
+    // tests lowering counting and negation in place
+    #[test]
+    fn test_inc_dec_neg() {
+        let pack = gen("inc r0\ndec r1\nneg r2\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "scoreboard players add #cpu r0 1\n\
+             scoreboard players remove #cpu r1 1\n\
+             scoreboard players set #lit swp -1\n\
+             scoreboard players operation #cpu r2 *= #lit swp\n\
+             scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering register swaps
+    #[test]
+    fn test_exchange() {
+        let pack = gen("exg r4, r7\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "scoreboard players operation #cpu swp = #cpu r4\n\
+             scoreboard players operation #cpu r4 = #cpu r7\n\
+             scoreboard players operation #cpu r7 = #cpu swp\n\
+             scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering math and stack instructions
+    #[test]
+    fn test_math_and_stack() {
+        let pack = gen("mov -1, r1\nsub r0, r1, r2\npush r2\npop r3\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "scoreboard players set #cpu r1 -1\n\
+             scoreboard players operation #cpu swp = #cpu r0\n\
+             scoreboard players operation #cpu swp -= #cpu r1\n\
+             scoreboard players operation #cpu r2 = #cpu swp\n\
+             data modify storage test:cpu stack prepend value 0\n\
+             execute store result storage test:cpu stack[0] int 1 run scoreboard players get #cpu r2\n\
+             scoreboard players operation #cpu sr = #cpu r2\n\
+             execute store result score #cpu r3 run data get storage test:cpu stack[0]\n\
+             data remove storage test:cpu stack[0]\n\
+             execute store result score #cpu sr run data get storage test:cpu stack[0]\n\
+             scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering block checks
+    #[test]
+    fn test_blk() {
+        let pack = gen("blk 1, ~-2, ~, stone\nblk ^, ^1, ^, oak_stairs[half=top]\nblk r0, 5, ~1, stone\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "execute store success score #cpu flag_Block if block 1 ~-2 ~ minecraft:stone\n\
+             execute store success score #cpu flag_Block rotated 0 0 if block ^ ^1 ^ minecraft:oak_stairs[half=top]\n\
+             summon minecraft:marker ~ 5 ~1 {Tags:[\"mcisa_pos\"]}\n\
+             execute store result entity @e[type=minecraft:marker,tag=mcisa_pos,limit=1] Pos[0] double 1 run scoreboard players get #cpu r0\n\
+             execute store success score #cpu flag_Block at @e[type=minecraft:marker,tag=mcisa_pos,limit=1] if block ~ ~ ~ minecraft:stone\n\
+             kill @e[type=minecraft:marker,tag=mcisa_pos]\n\
+             scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests that local coordinates face the same way as in the emulator
+    #[test]
+    fn test_blk_local() {
+        let pack = gen("blk ^1, ^, ^2, stone\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "execute store success score #cpu flag_Block rotated 0 0 if block ^1 ^ ^2 minecraft:stone\n\
+             scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering entity checks
+    #[test]
+    fn test_ent() {
+        let pack = gen("ent @e[type=zombie,tag=!boss,distance=..8,limit=1,sort=nearest]\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "execute store success score #cpu flag_Entity if entity @e[type=minecraft:zombie,tag=!boss,distance=..8,limit=1,sort=nearest]\nscoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering remainders, minimums, and maximums
+    #[test]
+    fn test_mod_min_max() {
+        let pack = gen("mod r0, r1, r2\nmin r0, 3, r0\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "scoreboard players operation #cpu swp = #cpu r0\n\
+             scoreboard players operation #cpu swp %= #cpu r1\n\
+             scoreboard players operation #cpu r2 = #cpu swp\n\
+             scoreboard players operation #cpu swp = #cpu r0\n\
+             scoreboard players set #lit swp 3\n\
+             scoreboard players operation #cpu swp < #lit swp\n\
+             scoreboard players operation #cpu r0 = #cpu swp\n\
+             scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering literal second arguments
+    #[test]
+    fn test_literal_operands() {
+        let pack = gen("add r0, 5, r0\nsub r1, 0x80000000, r2\ndiv r3, 7, r4\ncmp r5, 2147483647\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "scoreboard players operation #cpu swp = #cpu r0\n\
+             scoreboard players add #cpu swp 5\n\
+             scoreboard players operation #cpu r0 = #cpu swp\n\
+             scoreboard players operation #cpu swp = #cpu r1\n\
+             scoreboard players remove #cpu swp 2147483647\n\
+             scoreboard players remove #cpu swp 1\n\
+             scoreboard players operation #cpu r2 = #cpu swp\n\
+             scoreboard players operation #cpu swp = #cpu r3\n\
+             scoreboard players set #lit swp 7\n\
+             scoreboard players operation #cpu swp /= #lit swp\n\
+             scoreboard players operation #cpu r4 = #cpu swp\n\
+             execute store success score #cpu flag_Equal if score #cpu r5 matches 2147483647\n\
+             scoreboard players set #cpu flag_GreaterThan 0\n\
+             execute store success score #cpu flag_LessThan if score #cpu r5 matches ..2147483646\n\
+             scoreboard players set #cpu status 2\n"
+        );
+    }
+
+    // tests lowering bitwise instructions and their helpers
+    #[test]
+    fn test_bitwise() {
+        let pack = gen("and r0, r1, r2\nxor r0, 0xFF, r3\nnot r4, r5\nshl r0, 31, r6\nshr r0, 31, r7\n");
+        assert_eq!(
+            pack.file("data/test/functions/l0.mcfunction").unwrap(),
+            "scoreboard players operation #cpu swp = #cpu r0\n\
+             scoreboard players operation #bit_b swp = #cpu r1\n\
+             function test:bitwise/and\n\
+             scoreboard players operation #cpu r2 = #cpu swp\n\
+             scoreboard players operation #cpu swp = #cpu r0\n\
+             scoreboard players set #bit_b swp 255\n\
+             function test:bitwise/xor\n\
+             scoreboard players operation #cpu r3 = #cpu swp\n\
+             scoreboard players set #cpu swp -1\n\
+             scoreboard players operation #cpu swp -= #cpu r4\n\
+             scoreboard players operation #cpu r5 = #cpu swp\n\
+             scoreboard players operation #cpu swp = #cpu r0\n\
+             scoreboard players set #lit swp -2147483648\n\
+             scoreboard players operation #cpu swp *= #lit swp\n\
+             scoreboard players operation #cpu r6 = #cpu swp\n\
+             scoreboard players operation #cpu swp = #cpu r0\n\
+             scoreboard players set #lit swp 1073741824\n\
+             scoreboard players operation #cpu swp /= #lit swp\n\
+             scoreboard players set #lit swp 2\n\
+             scoreboard players operation #cpu swp /= #lit swp\n\
+             scoreboard players operation #cpu r7 = #cpu swp\n\
+             scoreboard players set #cpu status 2\n"
+        );
+
+        // only the helpers that are used are generated
+        assert!(pack.file("data/test/functions/bitwise/or.mcfunction").is_none());
+        let xor = pack.file("data/test/functions/bitwise/xor.mcfunction").unwrap();
+        assert_eq!(xor.lines().count(), 4 + 32 * 11);
+        assert!(xor.starts_with(
+            "scoreboard players operation #bit_a swp = #cpu swp\n\
+             scoreboard players set #cpu swp 0\n\
+             scoreboard players set #bit_p swp 1\n\
+             scoreboard players set #lit swp 2\n\
+             scoreboard players operation #bit_x swp = #bit_a swp\n"
+        ));
+        assert!(xor.contains(
+            "scoreboard players operation #bit_x swp += #bit_y swp\n\
+             scoreboard players operation #bit_x swp %= #lit swp\n"
+        ));
+    }
+
+    // tests generation errors
+    #[test]
+    fn test_errors() {
+        let prog = assemble("jmp L5").unwrap();
+        let err1 = generate(prog.instructions(), "Bad Name").unwrap_err();
+        let err2 = generate(prog.instructions(), "test").unwrap_err();
+        assert_eq!(err1.reason(), "invalid namespace `Bad Name`");
+        assert_eq!(err2.pc(), Some(0));
+        assert_eq!(err2.reason(), "label ID 5 is out of range");
+    }
+}
+
+// end of file
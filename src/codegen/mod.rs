@@ -0,0 +1,24 @@
+/*
+ * mod.rs
+ * Module header for mcisa's codegen module
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// module exports
+mod datapack;
+pub use datapack::Datapack;
+mod generator;
+pub use generator::generate;
+pub use generator::generate_with_data;
+pub use generator::HOLDER;
+pub use generator::LITERAL_HOLDER;
+pub use generator::PACK_FORMAT;
+pub use generator::POS_TAG;
+pub use generator::STATUS_OBJECTIVE;
+
+// end of file
@@ -0,0 +1,195 @@
+/*
+ * condition.rs
+ * Enumerates the conditions tested by conditional jumps
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::Flag;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use strum_macros::EnumIter;
+
+/// A condition tested by the `J<cc>` instructions
+///
+/// `CMP` sets exactly one of `Flag::Equal`, `Flag::GreaterThan`
+/// and `Flag::LessThan`, so every condition can be decided by
+/// testing a single flag. For example, greater-or-equal holds
+/// exactly when `Flag::LessThan` is unset.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
+pub enum Condition {
+    /// Set if `Flag::Equal` is set
+    Equal,
+
+    /// Set if `Flag::Equal` is unset
+    NotEqual,
+
+    /// Set if `Flag::GreaterThan` is set
+    Greater,
+
+    /// Set if `Flag::LessThan` is unset
+    GreaterOrEqual,
+
+    /// Set if `Flag::LessThan` is set
+    Less,
+
+    /// Set if `Flag::GreaterThan` is unset
+    LessOrEqual,
+
+    /// Set if `Flag::Zero` is set
+    Zero,
+
+    /// Set if `Flag::Zero` is unset
+    NotZero,
+}
+
+// implementation
+impl Condition {
+    /// Gets the flag test that decides the condition
+    ///
+    /// # Returns
+    ///
+    /// The `Flag` to test, and whether it
+    /// must be set for the condition to hold
+    pub fn flag_test(&self) -> (Flag, bool) {
+        match self {
+            Condition::Equal => (Flag::Equal, true),
+            Condition::NotEqual => (Flag::Equal, false),
+            Condition::Greater => (Flag::GreaterThan, true),
+            Condition::GreaterOrEqual => (Flag::LessThan, false),
+            Condition::Less => (Flag::LessThan, true),
+            Condition::LessOrEqual => (Flag::GreaterThan, false),
+            Condition::Zero => (Flag::Zero, true),
+            Condition::NotZero => (Flag::Zero, false),
+        }
+    }
+
+    /// Gets the condition that holds exactly when this one does not
+    ///
+    /// # Returns
+    ///
+    /// The negated condition
+    pub fn negated(&self) -> Condition {
+        match self {
+            Condition::Equal => Condition::NotEqual,
+            Condition::NotEqual => Condition::Equal,
+            Condition::Greater => Condition::LessOrEqual,
+            Condition::GreaterOrEqual => Condition::Less,
+            Condition::Less => Condition::GreaterOrEqual,
+            Condition::LessOrEqual => Condition::Greater,
+            Condition::Zero => Condition::NotZero,
+            Condition::NotZero => Condition::Zero,
+        }
+    }
+}
+
+// Display implementation
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // write the suffix used in mnemonics
+        let suffix = match self {
+            Condition::Equal => "eq",
+            Condition::NotEqual => "ne",
+            Condition::Greater => "gt",
+            Condition::GreaterOrEqual => "ge",
+            Condition::Less => "lt",
+            Condition::LessOrEqual => "le",
+            Condition::Zero => "z",
+            Condition::NotZero => "nz",
+        };
+        write!(f, "{}", suffix)
+    }
+}
+
+// FromStr implementation
+impl FromStr for Condition {
+    // create an error type
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "eq" => Ok(Condition::Equal),
+            "ne" => Ok(Condition::NotEqual),
+            "gt" => Ok(Condition::Greater),
+            "ge" => Ok(Condition::GreaterOrEqual),
+            "lt" => Ok(Condition::Less),
+            "le" => Ok(Condition::LessOrEqual),
+            "z" => Ok(Condition::Zero),
+            "nz" => Ok(Condition::NotZero),
+            _ => Err(()),
+        }
+    }
+}
+
+// TryFrom implementation
+impl TryFrom<u8> for Condition {
+    // create an error type
+    type Error = ();
+
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0x0 => Ok(Condition::Equal),
+            0x1 => Ok(Condition::NotEqual),
+            0x2 => Ok(Condition::Greater),
+            0x3 => Ok(Condition::GreaterOrEqual),
+            0x4 => Ok(Condition::Less),
+            0x5 => Ok(Condition::LessOrEqual),
+            0x6 => Ok(Condition::Zero),
+            0x7 => Ok(Condition::NotZero),
+            _ => Err(()),
+        }
+    }
+}
+
+// From implementation for u8
+impl From<Condition> for u8 {
+    fn from(c: Condition) -> u8 {
+        match c {
+            Condition::Equal => 0x0,
+            Condition::NotEqual => 0x1,
+            Condition::Greater => 0x2,
+            Condition::GreaterOrEqual => 0x3,
+            Condition::Less => 0x4,
+            Condition::LessOrEqual => 0x5,
+            Condition::Zero => 0x6,
+            Condition::NotZero => 0x7,
+        }
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // load the enum
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    // this test checks that conversions round-trip
+    #[test]
+    fn test_conversions() {
+        for cond in Condition::iter() {
+            assert_eq!(Condition::try_from(u8::from(cond)), Ok(cond));
+            assert_eq!(cond.to_string().parse(), Ok(cond));
+        }
+        assert!(Condition::try_from(0x8).is_err());
+        assert!("ez".parse::<Condition>().is_err());
+    }
+
+    // this test checks that negation flips the flag test
+    #[test]
+    fn test_negated() {
+        for cond in Condition::iter() {
+            let (flag, set) = cond.flag_test();
+            assert_eq!(cond.negated().flag_test(), (flag, !set));
+            assert_eq!(cond.negated().negated(), cond);
+        }
+    }
+}
+
+// end of file
@@ -14,5 +14,9 @@ mod register;
 pub use register::Register;
 mod flag;
 pub use flag::Flag;
+mod status;
+pub use status::Status;
+mod condition;
+pub use condition::Condition;
 
 // end of file
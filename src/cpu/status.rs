@@ -0,0 +1,88 @@
+/*
+ * status.rs
+ * Enumerates the run states of the Minecraft CPU
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The run state of the CPU, which generated
+/// datapacks store in the `status` objective
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The CPU is executing instructions
+    Running,
+
+    /// The CPU executed a `HLT` instruction
+    Halted,
+
+    /// The CPU fell off the end of the program,
+    /// either by executing its last instruction
+    /// or by jumping to the label at its end
+    Finished,
+}
+
+// Display implementation
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Status::Running => "running",
+            Status::Halted => "halted",
+            Status::Finished => "finished",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// TryFrom implementation
+impl TryFrom<i32> for Status {
+    // create an error type
+    type Error = ();
+
+    fn try_from(val: i32) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Status::Running),
+            1 => Ok(Status::Halted),
+            2 => Ok(Status::Finished),
+            _ => Err(()),
+        }
+    }
+}
+
+// From implementation for i32
+impl From<Status> for i32 {
+    fn from(s: Status) -> i32 {
+        match s {
+            Status::Running => 0,
+            Status::Halted => 1,
+            Status::Finished => 2,
+        }
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // load the enum
+    use super::*;
+
+    // this test checks that the score values round-trip
+    #[test]
+    fn test_score_values() {
+        for status in [Status::Running, Status::Halted, Status::Finished].iter() {
+            assert_eq!(Status::try_from(i32::from(*status)), Ok(*status));
+        }
+        assert_eq!(i32::from(Status::Halted), 1);
+        assert!(Status::try_from(3).is_err());
+        assert!(Status::try_from(-1).is_err());
+    }
+}
+
+// end of file
@@ -13,13 +13,16 @@
 use super::super::cpu::Register;
 
 /// Metadata for the `ADD` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ADDData {
     /// The first addend
     arg_0: Register,
 
-    /// The second addend
-    arg_1: Register,
+    /// The second addend (if a literal)
+    arg_1_lit: Option<u32>,
+
+    /// The second addend (if a register)
+    arg_1_reg: Option<Register>,
 
     /// The `Register` to store the sum in
     sum_dest: Register,
@@ -41,11 +44,76 @@ impl ADDData {
     pub fn new(arg0: Register, arg1: Register, dest: Register) -> Self {
         ADDData {
             arg_0: arg0,
-            arg_1: arg1,
+            arg_1_lit: None,
+            arg_1_reg: Some(arg1),
             sum_dest: dest 
         }
     }
 
+    /// Creates a new `ADDData` instance with a register
+    /// as the second argument, like `ADDData::new`
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first addend `Register`
+    /// * `arg1` - The second addend `Register`
+    /// * `dest` - The `Register` that the sum is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `ADDData` instance with the specified data
+    pub fn from_register(arg0: Register, arg1: Register, dest: Register) -> Self {
+        ADDData::new(arg0, arg1, dest)
+    }
+
+    /// Creates a new `ADDData` instance with a literal
+    /// as the second argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first addend `Register`
+    /// * `arg1` - The second addend, as a literal
+    /// * `dest` - The `Register` that the sum is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `ADDData` instance with the specified data
+    pub fn from_literal(arg0: Register, arg1: u32, dest: Register) -> Self {
+        ADDData {
+            arg_0: arg0,
+            arg_1_lit: Some(arg1),
+            arg_1_reg: None,
+            sum_dest: dest,
+        }
+    }
+
+    /// Returns whether the second argument is a literal
+    ///
+    /// # Returns
+    ///
+    /// Whether the associated instruction has a literal as its second argument
+    pub fn has_literal_arg1(&self) -> bool {
+        self.arg_1_lit.is_some()
+    }
+
+    /// Gets the second argument literal
+    ///
+    /// # Returns
+    ///
+    /// The second argument literal, wrapped in an `Option`
+    pub fn arg1_literal(&self) -> Option<u32> {
+        self.arg_1_lit
+    }
+
+    /// Gets the second argument register
+    ///
+    /// # Returns
+    ///
+    /// The second argument register, wrapped in an `Option`
+    pub fn arg1_register(&self) -> Option<Register> {
+        self.arg_1_reg
+    }
+
     /// Gets the first addend `Register`
     ///
     /// # Returns
@@ -60,8 +128,13 @@ impl ADDData {
     /// # Returns 
     ///
     /// The second addend register
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if called on an instance that does
+    /// not have a register as its second argument.
     pub fn arg1(&self) -> Register {
-        self.arg_1 
+        self.arg_1_reg.unwrap()
     }
 
     /// Gets the destination `Register`
@@ -74,4 +147,23 @@ impl ADDData {
     }
 }
 
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // tests the literal and register forms
+    #[test]
+    fn test_second_argument_forms() {
+        let d1 = ADDData::from_literal(Register::R0, 5, Register::R1);
+        let d2 = ADDData::from_register(Register::R0, Register::R2, Register::R1);
+        assert!(d1.has_literal_arg1());
+        assert_eq!(d1.arg1_literal(), Some(5));
+        assert_eq!(d1.arg1_register(), None);
+        assert!(!d2.has_literal_arg1());
+        assert_eq!(d2.arg1(), Register::R2);
+        assert_eq!(d2, ADDData::new(Register::R0, Register::R2, Register::R1));
+    }
+}
+
 // end of file
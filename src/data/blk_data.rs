@@ -0,0 +1,116 @@
+/*
+ * blk_data.rs
+ * Defines the metadata structure for the BLK instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::BlockState;
+use super::Coordinate;
+
+/// Contains metadata for the `BLK` instruction,
+/// which sets `Flag::Block` if the block at a
+/// position matches a `BlockState`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BLKData {
+    /// The x, y, and z coordinates of the block to check
+    pos: [Coordinate; 3],
+
+    /// The block to check for
+    block: BlockState,
+}
+
+// implementation
+impl BLKData {
+    /// Creates a new `BLKData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the block to check
+    /// * `y` - The y coordinate of the block to check
+    /// * `z` - The z coordinate of the block to check
+    /// * `block` - The block to check for
+    ///
+    /// # Returns
+    ///
+    /// A new `BLKData` instance with the given data
+    pub fn new(x: Coordinate, y: Coordinate, z: Coordinate, block: BlockState) -> Self {
+        BLKData {
+            pos: [x, y, z],
+            block,
+        }
+    }
+
+    /// Gets the coordinates of the block to check
+    ///
+    /// # Returns
+    ///
+    /// The x, y, and z coordinates, in that order
+    pub fn coordinates(&self) -> [Coordinate; 3] {
+        self.pos
+    }
+
+    /// Gets the block to check for
+    ///
+    /// # Returns
+    ///
+    /// The `BlockState` that the block must match
+    pub fn block(&self) -> &BlockState {
+        &self.block
+    }
+
+    /// Determines whether the coordinates form a valid position,
+    /// which is true unless local coordinates are mixed with
+    /// other kinds of coordinates
+    ///
+    /// # Returns
+    ///
+    /// Whether the coordinates can be used together
+    pub fn has_valid_coordinates(&self) -> bool {
+        let locals = self.pos.iter().filter(|c| c.is_local()).count();
+        locals == 0 || locals == 3
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the struct into scope
+    use super::*;
+    use super::super::super::cpu::Register;
+
+    // tests coordinate validation
+    #[test]
+    fn test_has_valid_coordinates() {
+        let stone = BlockState::new("stone");
+        let mixed = BLKData::new(
+            Coordinate::Absolute(0),
+            Coordinate::Relative(1),
+            Coordinate::Register(Register::R0),
+            stone.clone(),
+        );
+        let local = BLKData::new(
+            Coordinate::Local(0),
+            Coordinate::Local(1),
+            Coordinate::Local(2),
+            stone.clone(),
+        );
+        let bad = BLKData::new(
+            Coordinate::Local(0),
+            Coordinate::Relative(1),
+            Coordinate::Local(2),
+            stone,
+        );
+        assert!(mixed.has_valid_coordinates());
+        assert!(local.has_valid_coordinates());
+        assert!(!bad.has_valid_coordinates());
+        assert_eq!(mixed.coordinates()[2], Coordinate::Register(Register::R0));
+    }
+}
+
+// end of file
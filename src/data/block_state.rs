@@ -0,0 +1,196 @@
+/*
+ * block_state.rs
+ * Defines a struct that describes a block to detect
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::fmt;
+use std::str::FromStr;
+
+/// A namespaced block ID with optional block-state properties,
+/// such as `minecraft:oak_stairs[facing=north,half=top]`
+///
+/// A block in the world matches a `BlockState` if it has
+/// the same ID and every listed property has the listed value.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockState {
+    /// The namespaced block ID
+    id: String,
+
+    /// The block-state properties, in the order they were given
+    props: Vec<(String, String)>,
+}
+
+// implementation
+impl BlockState {
+    /// Creates a new `BlockState` instance with no properties
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The block ID, which gets the `minecraft`
+    ///   namespace if it does not have one
+    ///
+    /// # Returns
+    ///
+    /// A new `BlockState` instance with the given ID
+    pub fn new(id: &str) -> Self {
+        let id = if id.contains(':') {
+            id.to_owned()
+        } else {
+            format!("minecraft:{}", id)
+        };
+        BlockState {
+            id,
+            props: Vec::new(),
+        }
+    }
+
+    /// Adds a block-state property
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the property
+    /// * `val` - The value of the property
+    ///
+    /// # Returns
+    ///
+    /// The `BlockState` with the property added
+    pub fn with_property(mut self, key: &str, val: &str) -> Self {
+        self.props.push((key.to_owned(), val.to_owned()));
+        self
+    }
+
+    /// Gets the namespaced block ID
+    ///
+    /// # Returns
+    ///
+    /// The block ID, including its namespace
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Gets the block-state properties
+    ///
+    /// # Returns
+    ///
+    /// The name and value of each property
+    pub fn properties(&self) -> &[(String, String)] {
+        &self.props
+    }
+
+    /// Determines whether a block matches this one
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - The block to test, such as a block in the world
+    ///
+    /// # Returns
+    ///
+    /// Whether `block` has this block's ID and properties
+    pub fn matches(&self, block: &BlockState) -> bool {
+        self.id == block.id && self.props.iter().all(|p| block.props.contains(p))
+    }
+}
+
+// Display implementation
+impl fmt::Display for BlockState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.id)?;
+        if !self.props.is_empty() {
+            let props: Vec<String> = self
+                .props
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            write!(f, "[{}]", props.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+// FromStr implementation
+impl FromStr for BlockState {
+    // create an error type
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // split the ID from the properties
+        let (id, rest) = match s.find('[') {
+            Some(idx) => (&s[..idx], Some(&s[idx + 1..])),
+            None => (s, None),
+        };
+        let valid = |c: char| c.is_ascii_alphanumeric() || "_-./:".contains(c);
+        if id.is_empty() || !id.chars().all(valid) {
+            return Err(());
+        }
+        let mut state = BlockState::new(id);
+
+        if let Some(rest) = rest {
+            let inner = rest.strip_suffix(']').ok_or(())?;
+            for prop in inner.split(',').filter(|p| !p.is_empty()) {
+                let mut parts = prop.splitn(2, '=');
+                let key = parts.next().ok_or(())?;
+                let val = parts.next().ok_or(())?;
+                if key.is_empty() || val.is_empty() || !key.chars().all(valid) || !val.chars().all(valid) {
+                    return Err(());
+                }
+                state = state.with_property(key, val);
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the struct into scope
+    use super::*;
+
+    // tests display formatting
+    #[test]
+    fn test_display_formatting() {
+        let stone = BlockState::new("stone");
+        let stairs = BlockState::new("minecraft:oak_stairs")
+            .with_property("facing", "north")
+            .with_property("half", "top");
+        assert_eq!(format!("{}", stone), "minecraft:stone");
+        assert_eq!(
+            format!("{}", stairs),
+            "minecraft:oak_stairs[facing=north,half=top]"
+        );
+    }
+
+    // tests parsing from a string
+    #[test]
+    fn test_from_str() {
+        let stairs = BlockState::new("oak_stairs").with_property("facing", "north");
+        assert_eq!("oak_stairs[facing=north]".parse(), Ok(stairs));
+        assert_eq!("mod:thing".parse(), Ok(BlockState::new("mod:thing")));
+        assert_eq!("stone[facing]".parse::<BlockState>(), Err(()));
+        assert_eq!("stone[facing=north".parse::<BlockState>(), Err(()));
+        assert_eq!("".parse::<BlockState>(), Err(()));
+    }
+
+    // tests matching blocks
+    #[test]
+    fn test_matches() {
+        let pattern = BlockState::new("oak_stairs").with_property("facing", "north");
+        let block = BlockState::new("oak_stairs")
+            .with_property("half", "top")
+            .with_property("facing", "north");
+        assert!(pattern.matches(&block));
+        assert!(!block.matches(&pattern));
+        assert!(BlockState::new("oak_stairs").matches(&block));
+        assert!(!BlockState::new("stone").matches(&block));
+    }
+}
+
+// end of file
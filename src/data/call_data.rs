@@ -0,0 +1,50 @@
+/*
+ * call_data.rs
+ * Defines the metadata structure for the CALL instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// no usage statements
+
+/// Contains metadata for the `CALL` instruction, which
+/// saves the address of the next instruction on the call
+/// stack and then jumps to a subroutine
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CALLData {
+    /// The ID of the label that the subroutine starts at
+    label_id: u32,
+}
+
+// implementation
+impl CALLData {
+    /// Creates a new `CALLData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `lbl_id` - The ID of the label that the subroutine starts at
+    ///
+    /// # Returns
+    ///
+    /// A new `CALLData` instance with the specified data
+    pub fn new(lbl_id: u32) -> Self {
+        CALLData {
+            label_id: lbl_id,
+        }
+    }
+
+    /// Gets the label ID of the subroutine being called
+    ///
+    /// # Returns
+    ///
+    /// The label ID to jump to
+    pub fn jmp_label_id(&self) -> u32 {
+        self.label_id
+    }
+}
+
+// end of file
@@ -13,7 +13,7 @@
 use super::super::cpu::Register;
 
 /// Contains metadata for the `CHK` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CHKData {
     /// The `Register` to check
     reg: Register 
@@ -13,13 +13,16 @@
 use super::super::cpu::Register;
 
 /// Contains metadata for the `CMP` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CMPData {
     /// The first `Register` to compare
     arg_0: Register,
 
-    /// The second `Register` to compare
-    arg_1: Register 
+    /// The second value to compare (if a literal)
+    arg_1_lit: Option<u32>,
+
+    /// The second value to compare (if a register)
+    arg_1_reg: Option<Register>,
 }
 
 // implementation
@@ -37,10 +40,72 @@ impl CMPData {
     pub fn new(arg0: Register, arg1: Register) -> Self {
         CMPData {
             arg_0: arg0,
-            arg_1: arg1 
+            arg_1_lit: None,
+            arg_1_reg: Some(arg1),
+        }
+    }
+
+    /// Creates a new `CMPData` instance with a register
+    /// as the second argument, like `CMPData::new`
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` to compare
+    /// * `arg1` - The second `Register` to compare
+    ///
+    /// # Returns
+    ///
+    /// A new `CMPData` instance with the specified data
+    pub fn from_register(arg0: Register, arg1: Register) -> Self {
+        CMPData::new(arg0, arg1)
+    }
+
+    /// Creates a new `CMPData` instance with a literal
+    /// as the second argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` to compare
+    /// * `arg1` - The second value to compare, as a literal
+    ///
+    /// # Returns
+    ///
+    /// A new `CMPData` instance with the specified data
+    pub fn from_literal(arg0: Register, arg1: u32) -> Self {
+        CMPData {
+            arg_0: arg0,
+            arg_1_lit: Some(arg1),
+            arg_1_reg: None,
         }
     }
 
+    /// Returns whether the second argument is a literal
+    ///
+    /// # Returns
+    ///
+    /// Whether the associated instruction has a literal as its second argument
+    pub fn has_literal_arg1(&self) -> bool {
+        self.arg_1_lit.is_some()
+    }
+
+    /// Gets the second argument literal
+    ///
+    /// # Returns
+    ///
+    /// The second argument literal, wrapped in an `Option`
+    pub fn arg1_literal(&self) -> Option<u32> {
+        self.arg_1_lit
+    }
+
+    /// Gets the second argument register
+    ///
+    /// # Returns
+    ///
+    /// The second argument register, wrapped in an `Option`
+    pub fn arg1_register(&self) -> Option<Register> {
+        self.arg_1_reg
+    }
+
     /// Gets the first `Register` being compared
     ///
     /// # Returns
@@ -55,8 +120,13 @@ impl CMPData {
     /// # Returns
     ///
     /// The second `Register` being compared
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if called on an instance that does
+    /// not have a register as its second argument.
     pub fn arg1(&self) -> Register {
-        self.arg_1 
+        self.arg_1_reg.unwrap()
     }
 }
 
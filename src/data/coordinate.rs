@@ -0,0 +1,79 @@
+/*
+ * coordinate.rs
+ * Defines an enum that represents one axis of a world position
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Register;
+use std::fmt;
+
+/// One axis of a position in the Minecraft world
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Coordinate {
+    /// An absolute coordinate, such as `64`
+    Absolute(i32),
+
+    /// An offset from the executing position, such as `~-2`
+    Relative(i32),
+
+    /// An offset along the executor's local axes, such as `^1`
+    ///
+    /// Local coordinates cannot be mixed with
+    /// any other kind of coordinate
+    Local(i32),
+
+    /// An absolute coordinate read from a `Register`
+    Register(Register),
+}
+
+// implementation
+impl Coordinate {
+    /// Determines whether the coordinate is a local coordinate
+    ///
+    /// # Returns
+    ///
+    /// Whether the coordinate is `Local`
+    pub fn is_local(&self) -> bool {
+        matches!(self, Coordinate::Local(_))
+    }
+}
+
+// Display implementation
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Coordinate::Absolute(v) => write!(f, "{}", v),
+            Coordinate::Relative(0) => write!(f, "~"),
+            Coordinate::Relative(v) => write!(f, "~{}", v),
+            Coordinate::Local(0) => write!(f, "^"),
+            Coordinate::Local(v) => write!(f, "^{}", v),
+            Coordinate::Register(r) => write!(f, "{}", r),
+        }
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the enum into scope
+    use super::*;
+
+    // tests display formatting
+    #[test]
+    fn test_display_formatting() {
+        assert_eq!(format!("{}", Coordinate::Absolute(-64)), "-64");
+        assert_eq!(format!("{}", Coordinate::Relative(0)), "~");
+        assert_eq!(format!("{}", Coordinate::Relative(-2)), "~-2");
+        assert_eq!(format!("{}", Coordinate::Local(0)), "^");
+        assert_eq!(format!("{}", Coordinate::Local(3)), "^3");
+        assert_eq!(format!("{}", Coordinate::Register(Register::R4)), "r4");
+    }
+}
+
+// end of file
@@ -0,0 +1,49 @@
+/*
+ * dec_data.rs
+ * Defines the metadata structure for the DEC instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Register;
+
+/// Contains metadata for the `DEC` instruction
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DECData {
+    /// The `Register` being decremented in place
+    reg: Register,
+}
+
+// implementation
+impl DECData {
+    /// Creates a new `DECData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The `Register` to decrement
+    ///
+    /// # Returns
+    ///
+    /// A new `DECData` instance with the specified data
+    pub fn new(r: Register) -> Self {
+        DECData {
+            reg: r,
+        }
+    }
+
+    /// Gets the `Register` being decremented
+    ///
+    /// # Returns
+    ///
+    /// The `Register` being decremented in place
+    pub fn arg0(&self) -> Register {
+        self.reg
+    }
+}
+
+// end of file
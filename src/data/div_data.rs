@@ -13,13 +13,16 @@
 use super::super::cpu::Register;
 
 /// Metadata for the `DIV` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct DIVData {
     /// The `Register` being divided
     arg_0: Register,
 
-    /// The `Register` being divided by
-    arg_1: Register,
+    /// The value being divided by (if a literal)
+    arg_1_lit: Option<u32>,
+
+    /// The value being divided by (if a register)
+    arg_1_reg: Option<Register>,
 
     /// The `Register` to store the quotient in
     quo_dest: Register,
@@ -41,11 +44,76 @@ impl DIVData {
     pub fn new(arg0: Register, arg1: Register, dest: Register) -> Self {
         DIVData {
             arg_0: arg0,
-            arg_1: arg1,
+            arg_1_lit: None,
+            arg_1_reg: Some(arg1),
             quo_dest: dest 
         }
     }
 
+    /// Creates a new `DIVData` instance with a register
+    /// as the second argument, like `DIVData::new`
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` being divided
+    /// * `arg1` - The `Register` being divided by
+    /// * `dest` - The `Register` that the quotient is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `DIVData` instance with the specified data
+    pub fn from_register(arg0: Register, arg1: Register, dest: Register) -> Self {
+        DIVData::new(arg0, arg1, dest)
+    }
+
+    /// Creates a new `DIVData` instance with a literal
+    /// as the second argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` being divided
+    /// * `arg1` - The value being divided by, as a literal
+    /// * `dest` - The `Register` that the quotient is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `DIVData` instance with the specified data
+    pub fn from_literal(arg0: Register, arg1: u32, dest: Register) -> Self {
+        DIVData {
+            arg_0: arg0,
+            arg_1_lit: Some(arg1),
+            arg_1_reg: None,
+            quo_dest: dest,
+        }
+    }
+
+    /// Returns whether the second argument is a literal
+    ///
+    /// # Returns
+    ///
+    /// Whether the associated instruction has a literal as its second argument
+    pub fn has_literal_arg1(&self) -> bool {
+        self.arg_1_lit.is_some()
+    }
+
+    /// Gets the second argument literal
+    ///
+    /// # Returns
+    ///
+    /// The second argument literal, wrapped in an `Option`
+    pub fn arg1_literal(&self) -> Option<u32> {
+        self.arg_1_lit
+    }
+
+    /// Gets the second argument register
+    ///
+    /// # Returns
+    ///
+    /// The second argument register, wrapped in an `Option`
+    pub fn arg1_register(&self) -> Option<Register> {
+        self.arg_1_reg
+    }
+
     /// Gets the `Register` being divided
     ///
     /// # Returns
@@ -60,8 +128,13 @@ impl DIVData {
     /// # Returns 
     ///
     /// The second argument register
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if called on an instance that does
+    /// not have a register as its second argument.
     pub fn arg1(&self) -> Register {
-        self.arg_1 
+        self.arg_1_reg.unwrap()
     }
 
     /// Gets the destination `Register`
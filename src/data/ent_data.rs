@@ -0,0 +1,49 @@
+/*
+ * ent_data.rs
+ * Defines the metadata structure for the ENT instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statement
+use super::Selector;
+
+/// Contains metadata for the `ENT` instruction,
+/// which sets `Flag::Entity` if a `Selector`
+/// selects at least one entity
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ENTData {
+    /// The selector to test
+    selector: Selector,
+}
+
+// implementation
+impl ENTData {
+    /// Creates a new `ENTData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - The selector to test
+    ///
+    /// # Returns
+    ///
+    /// A new `ENTData` instance with the given data
+    pub fn new(selector: Selector) -> Self {
+        ENTData { selector }
+    }
+
+    /// Gets the selector to test
+    ///
+    /// # Returns
+    ///
+    /// The `Selector` that must select an entity
+    pub fn selector(&self) -> &Selector {
+        &self.selector
+    }
+}
+
+// end of file
@@ -0,0 +1,65 @@
+/*
+ * exg_data.rs
+ * Defines the metadata structure for the EXG instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statement
+use super::super::cpu::Register;
+
+/// Contains metadata for the `EXG` instruction,
+/// which swaps the values of two registers
+/// by way of `Register::SWP`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EXGData {
+    /// The first `Register` to swap
+    arg_0: Register,
+
+    /// The second `Register` to swap
+    arg_1: Register,
+}
+
+// implementation
+impl EXGData {
+    /// Creates a new `EXGData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` to swap
+    /// * `arg1` - The second `Register` to swap
+    ///
+    /// # Returns
+    ///
+    /// A new `EXGData` instance with the specified data
+    pub fn new(arg0: Register, arg1: Register) -> Self {
+        EXGData {
+            arg_0: arg0,
+            arg_1: arg1,
+        }
+    }
+
+    /// Gets the first `Register` being swapped
+    ///
+    /// # Returns
+    ///
+    /// The first `Register` being swapped
+    pub fn arg0(&self) -> Register {
+        self.arg_0
+    }
+
+    /// Gets the second `Register` being swapped
+    ///
+    /// # Returns
+    ///
+    /// The second `Register` being swapped
+    pub fn arg1(&self) -> Register {
+        self.arg_1
+    }
+}
+
+// end of file
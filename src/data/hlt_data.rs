@@ -0,0 +1,33 @@
+/*
+ * hlt_data.rs
+ * Defines the metadata structure for the HLT instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// no usage statements
+
+/// Contains metadata for the `HLT` instruction, which
+/// stops the CPU and sets its status to `Status::Halted`
+///
+/// `HLT` takes no operands, so this structure holds no data.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HLTData;
+
+// implementation
+impl HLTData {
+    /// Creates a new `HLTData` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `HLTData` instance
+    pub fn new() -> Self {
+        HLTData
+    }
+}
+
+// end of file
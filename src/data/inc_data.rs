@@ -0,0 +1,49 @@
+/*
+ * inc_data.rs
+ * Defines the metadata structure for the INC instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Register;
+
+/// Contains metadata for the `INC` instruction
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct INCData {
+    /// The `Register` being incremented in place
+    reg: Register,
+}
+
+// implementation
+impl INCData {
+    /// Creates a new `INCData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The `Register` to increment
+    ///
+    /// # Returns
+    ///
+    /// A new `INCData` instance with the specified data
+    pub fn new(r: Register) -> Self {
+        INCData {
+            reg: r,
+        }
+    }
+
+    /// Gets the `Register` being incremented
+    ///
+    /// # Returns
+    ///
+    /// The `Register` being incremented in place
+    pub fn arg0(&self) -> Register {
+        self.reg
+    }
+}
+
+// end of file
@@ -0,0 +1,579 @@
+/*
+ * instruction.rs
+ * Defines an enum that unifies all instruction metadata structures
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::ADDData;
+use super::ANDData;
+use super::BLKData;
+use super::CALLData;
+use super::CHKData;
+use super::CMPData;
+use super::DECData;
+use super::DIVData;
+use super::ENTData;
+use super::EXGData;
+use super::HLTData;
+use super::INCData;
+use super::JCCData;
+use super::JMPData;
+use super::JNSData;
+use super::JSData;
+use super::MAXData;
+use super::MINData;
+use super::MODData;
+use super::MOVData;
+use super::MULData;
+use super::NEGData;
+use super::NOPData;
+use super::NOTData;
+use super::ORData;
+use super::POPData;
+use super::PUSHData;
+use super::RETData;
+use super::SHLData;
+use super::SHRData;
+use super::SUBData;
+use super::XORData;
+use super::super::cpu::Condition;
+use super::super::cpu::Register;
+use std::fmt;
+
+/// A single Minecraft CPU instruction
+///
+/// Each variant carries the metadata
+/// structure for its mnemonic. Label IDs
+/// carried by branching instructions are
+/// the index of the target instruction
+/// within the program.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// A `MOV` instruction
+    Mov(MOVData),
+
+    /// An `ADD` instruction
+    Add(ADDData),
+
+    /// A `SUB` instruction
+    Sub(SUBData),
+
+    /// A `MUL` instruction
+    Mul(MULData),
+
+    /// A `DIV` instruction
+    Div(DIVData),
+
+    /// A `MOD` instruction
+    Mod(MODData),
+
+    /// A `MIN` instruction
+    Min(MINData),
+
+    /// A `MAX` instruction
+    Max(MAXData),
+
+    /// An `AND` instruction
+    And(ANDData),
+
+    /// An `OR` instruction
+    Or(ORData),
+
+    /// An `XOR` instruction
+    Xor(XORData),
+
+    /// A `NOT` instruction
+    Not(NOTData),
+
+    /// A `SHL` instruction
+    Shl(SHLData),
+
+    /// A `SHR` instruction
+    Shr(SHRData),
+
+    /// A `PUSH` instruction
+    Push(PUSHData),
+
+    /// A `POP` instruction
+    Pop(POPData),
+
+    /// A `JMP` instruction
+    Jmp(JMPData),
+
+    /// A `CHK` instruction
+    Chk(CHKData),
+
+    /// A `CMP` instruction
+    Cmp(CMPData),
+
+    /// A `JS` instruction
+    Js(JSData),
+
+    /// A `JNS` instruction
+    Jns(JNSData),
+
+    /// A `BLK` instruction
+    Blk(BLKData),
+
+    /// An `ENT` instruction
+    Ent(ENTData),
+
+    /// An `EXG` instruction
+    Exg(EXGData),
+
+    /// A `CALL` instruction
+    Call(CALLData),
+
+    /// A `RET` instruction
+    Ret(RETData),
+
+    /// A `HLT` instruction
+    Hlt(HLTData),
+
+    /// A `NOP` instruction
+    Nop(NOPData),
+
+    /// A condition-code jump (`JEQ`, `JNE`, and so on)
+    Jcc(JCCData),
+
+    /// An `INC` instruction
+    Inc(INCData),
+
+    /// A `DEC` instruction
+    Dec(DECData),
+
+    /// A `NEG` instruction
+    Neg(NEGData),
+}
+
+// implementation
+impl Instruction {
+    /// Gets the mnemonic of the instruction
+    ///
+    /// # Returns
+    ///
+    /// The mnemonic of the instruction, as
+    /// it is spelled in assembly source
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::Mov(_) => "mov",
+            Instruction::Add(_) => "add",
+            Instruction::Sub(_) => "sub",
+            Instruction::Mul(_) => "mul",
+            Instruction::Div(_) => "div",
+            Instruction::Mod(_) => "mod",
+            Instruction::Min(_) => "min",
+            Instruction::Max(_) => "max",
+            Instruction::And(_) => "and",
+            Instruction::Or(_) => "or",
+            Instruction::Xor(_) => "xor",
+            Instruction::Not(_) => "not",
+            Instruction::Shl(_) => "shl",
+            Instruction::Shr(_) => "shr",
+            Instruction::Push(_) => "push",
+            Instruction::Pop(_) => "pop",
+            Instruction::Jmp(_) => "jmp",
+            Instruction::Chk(_) => "chk",
+            Instruction::Cmp(_) => "cmp",
+            Instruction::Js(_) => "js",
+            Instruction::Jns(_) => "jns",
+            Instruction::Blk(_) => "blk",
+            Instruction::Ent(_) => "ent",
+            Instruction::Exg(_) => "exg",
+            Instruction::Call(_) => "call",
+            Instruction::Ret(_) => "ret",
+            Instruction::Hlt(_) => "hlt",
+            Instruction::Nop(_) => "nop",
+            Instruction::Jcc(d) => match d.condition() {
+                Condition::Equal => "jeq",
+                Condition::NotEqual => "jne",
+                Condition::Greater => "jgt",
+                Condition::GreaterOrEqual => "jge",
+                Condition::Less => "jlt",
+                Condition::LessOrEqual => "jle",
+                Condition::Zero => "jz",
+                Condition::NotZero => "jnz",
+            },
+            Instruction::Inc(_) => "inc",
+            Instruction::Dec(_) => "dec",
+            Instruction::Neg(_) => "neg",
+        }
+    }
+
+    /// Gets the label ID that the instruction may jump to
+    ///
+    /// # Returns
+    ///
+    /// The label ID referenced by the instruction, or
+    /// `None` if it is not a branching instruction
+    pub fn jmp_label_id(&self) -> Option<u32> {
+        match self {
+            Instruction::Jmp(d) => Some(d.jmp_label_id()),
+            Instruction::Js(d) => Some(d.jmp_label_id()),
+            Instruction::Jns(d) => Some(d.jmp_label_id()),
+            Instruction::Call(d) => Some(d.jmp_label_id()),
+            Instruction::Jcc(d) => Some(d.jmp_label_id()),
+            _ => None,
+        }
+    }
+}
+
+// Display implementation
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // write the mnemonic
+        write!(f, "{}", self.mnemonic())?;
+
+        // and then write the operands
+        match self {
+            Instruction::Mov(d) => match d.src_literal() {
+                Some(lit) => write!(f, " {}, {}", lit, d.dest()),
+                None => write!(f, " {}, {}", d.unwrap_src_register(), d.dest()),
+            },
+            Instruction::Add(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Sub(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Mul(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Div(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Mod(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Min(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Max(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::And(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Or(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Xor(d) => write!(f, " {}, {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register()), d.dest()),
+            Instruction::Not(d) => write!(f, " {}, {}", d.arg0(), d.dest()),
+            Instruction::Shl(d) => write!(f, " {}, {}, {}", d.arg0(), d.amount(), d.dest()),
+            Instruction::Shr(d) => write!(f, " {}, {}, {}", d.arg0(), d.amount(), d.dest()),
+            Instruction::Push(d) => write!(f, " {}", d.arg0()),
+            Instruction::Pop(d) => write!(f, " {}", d.arg0()),
+            Instruction::Jmp(d) => write!(f, " L{}", d.jmp_label_id()),
+            Instruction::Chk(d) => write!(f, " {}", d.chk_reg()),
+            Instruction::Cmp(d) => write!(f, " {}, {}", d.arg0(), operand(d.arg1_literal(), d.arg1_register())),
+            Instruction::Js(d) => write!(f, " {}, L{}", d.cond_flag(), d.jmp_label_id()),
+            Instruction::Jns(d) => write!(f, " {}, L{}", d.cond_flag(), d.jmp_label_id()),
+            Instruction::Blk(d) => {
+                let [x, y, z] = d.coordinates();
+                write!(f, " {}, {}, {}, {}", x, y, z, d.block())
+            }
+            Instruction::Ent(d) => write!(f, " {}", d.selector()),
+            Instruction::Exg(d) => write!(f, " {}, {}", d.arg0(), d.arg1()),
+            Instruction::Call(d) => write!(f, " L{}", d.jmp_label_id()),
+            Instruction::Ret(_) | Instruction::Hlt(_) | Instruction::Nop(_) => Ok(()),
+            Instruction::Jcc(d) => write!(f, " L{}", d.jmp_label_id()),
+            Instruction::Inc(d) => write!(f, " {}", d.arg0()),
+            Instruction::Dec(d) => write!(f, " {}", d.arg0()),
+            Instruction::Neg(d) => write!(f, " {}", d.arg0()),
+        }
+    }
+}
+
+/// [Internal use only]
+/// Formats an operand that is either a literal or a register
+///
+/// # Arguments
+///
+/// * `lit` - The operand, if it is a literal
+/// * `reg` - The operand, if it is a register
+///
+/// # Returns
+///
+/// The operand, as it is spelled in assembly source
+fn operand(lit: Option<u32>, reg: Option<Register>) -> String {
+    match (lit, reg) {
+        (Some(lit), _) => lit.to_string(),
+        (None, Some(reg)) => reg.to_string(),
+        (None, None) => unreachable!("operand has neither a literal nor a register"),
+    }
+}
+
+// From implementations for each metadata structure
+impl From<MOVData> for Instruction {
+    fn from(data: MOVData) -> Instruction {
+        Instruction::Mov(data)
+    }
+}
+
+impl From<ADDData> for Instruction {
+    fn from(data: ADDData) -> Instruction {
+        Instruction::Add(data)
+    }
+}
+
+impl From<SUBData> for Instruction {
+    fn from(data: SUBData) -> Instruction {
+        Instruction::Sub(data)
+    }
+}
+
+impl From<MULData> for Instruction {
+    fn from(data: MULData) -> Instruction {
+        Instruction::Mul(data)
+    }
+}
+
+impl From<DIVData> for Instruction {
+    fn from(data: DIVData) -> Instruction {
+        Instruction::Div(data)
+    }
+}
+
+impl From<MODData> for Instruction {
+    fn from(data: MODData) -> Instruction {
+        Instruction::Mod(data)
+    }
+}
+
+impl From<MINData> for Instruction {
+    fn from(data: MINData) -> Instruction {
+        Instruction::Min(data)
+    }
+}
+
+impl From<MAXData> for Instruction {
+    fn from(data: MAXData) -> Instruction {
+        Instruction::Max(data)
+    }
+}
+
+impl From<ANDData> for Instruction {
+    fn from(data: ANDData) -> Instruction {
+        Instruction::And(data)
+    }
+}
+
+impl From<ORData> for Instruction {
+    fn from(data: ORData) -> Instruction {
+        Instruction::Or(data)
+    }
+}
+
+impl From<XORData> for Instruction {
+    fn from(data: XORData) -> Instruction {
+        Instruction::Xor(data)
+    }
+}
+
+impl From<NOTData> for Instruction {
+    fn from(data: NOTData) -> Instruction {
+        Instruction::Not(data)
+    }
+}
+
+impl From<SHLData> for Instruction {
+    fn from(data: SHLData) -> Instruction {
+        Instruction::Shl(data)
+    }
+}
+
+impl From<SHRData> for Instruction {
+    fn from(data: SHRData) -> Instruction {
+        Instruction::Shr(data)
+    }
+}
+
+impl From<CALLData> for Instruction {
+    fn from(data: CALLData) -> Instruction {
+        Instruction::Call(data)
+    }
+}
+
+impl From<RETData> for Instruction {
+    fn from(data: RETData) -> Instruction {
+        Instruction::Ret(data)
+    }
+}
+
+impl From<PUSHData> for Instruction {
+    fn from(data: PUSHData) -> Instruction {
+        Instruction::Push(data)
+    }
+}
+
+impl From<POPData> for Instruction {
+    fn from(data: POPData) -> Instruction {
+        Instruction::Pop(data)
+    }
+}
+
+impl From<JMPData> for Instruction {
+    fn from(data: JMPData) -> Instruction {
+        Instruction::Jmp(data)
+    }
+}
+
+impl From<CHKData> for Instruction {
+    fn from(data: CHKData) -> Instruction {
+        Instruction::Chk(data)
+    }
+}
+
+impl From<CMPData> for Instruction {
+    fn from(data: CMPData) -> Instruction {
+        Instruction::Cmp(data)
+    }
+}
+
+impl From<JSData> for Instruction {
+    fn from(data: JSData) -> Instruction {
+        Instruction::Js(data)
+    }
+}
+
+impl From<JNSData> for Instruction {
+    fn from(data: JNSData) -> Instruction {
+        Instruction::Jns(data)
+    }
+}
+
+impl From<BLKData> for Instruction {
+    fn from(data: BLKData) -> Instruction {
+        Instruction::Blk(data)
+    }
+}
+
+impl From<ENTData> for Instruction {
+    fn from(data: ENTData) -> Instruction {
+        Instruction::Ent(data)
+    }
+}
+
+impl From<EXGData> for Instruction {
+    fn from(data: EXGData) -> Instruction {
+        Instruction::Exg(data)
+    }
+}
+
+impl From<HLTData> for Instruction {
+    fn from(data: HLTData) -> Instruction {
+        Instruction::Hlt(data)
+    }
+}
+
+impl From<NOPData> for Instruction {
+    fn from(data: NOPData) -> Instruction {
+        Instruction::Nop(data)
+    }
+}
+
+impl From<JCCData> for Instruction {
+    fn from(data: JCCData) -> Instruction {
+        Instruction::Jcc(data)
+    }
+}
+
+impl From<INCData> for Instruction {
+    fn from(data: INCData) -> Instruction {
+        Instruction::Inc(data)
+    }
+}
+
+impl From<DECData> for Instruction {
+    fn from(data: DECData) -> Instruction {
+        Instruction::Dec(data)
+    }
+}
+
+impl From<NEGData> for Instruction {
+    fn from(data: NEGData) -> Instruction {
+        Instruction::Neg(data)
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the enum into scope
+    use super::*;
+    use super::super::super::cpu::Flag;
+    use super::super::super::cpu::Register;
+    use super::super::BlockState;
+    use super::super::Coordinate;
+    use std::collections::HashSet;
+
+    // tests the mnemonic method
+    #[test]
+    fn test_mnemonic() {
+        let mov = Instruction::from(MOVData::from_literal(0x32, Register::R0));
+        let js = Instruction::from(JSData::new(Flag::Zero, 3));
+        assert_eq!(mov.mnemonic(), "mov");
+        assert_eq!(js.mnemonic(), "js");
+        let jge = Instruction::from(JCCData::new(Condition::GreaterOrEqual, 3));
+        let jnz = Instruction::from(JCCData::new(Condition::NotZero, 3));
+        assert_eq!(jge.mnemonic(), "jge");
+        assert_eq!(format!("{}", jnz), "jnz L3");
+    }
+
+    // tests the jmp_label_id method
+    #[test]
+    fn test_jmp_label_id() {
+        let mov = Instruction::from(MOVData::from_literal(0x32, Register::R0));
+        let js = Instruction::from(JSData::new(Flag::Zero, 3));
+        assert_eq!(mov.jmp_label_id(), None);
+        assert_eq!(js.jmp_label_id(), Some(3));
+        assert_eq!(Instruction::from(CALLData::new(9)).jmp_label_id(), Some(9));
+        assert_eq!(Instruction::from(RETData::new()).jmp_label_id(), None);
+    }
+
+    // tests display formatting
+    #[test]
+    fn test_display_formatting() {
+        let mov1 = Instruction::Mov(MOVData::from_literal(50, Register::R0));
+        let mov2 = Instruction::Mov(MOVData::from_register(Register::R1, Register::R0));
+        let add = Instruction::Add(ADDData::new(Register::R0, Register::R1, Register::R2));
+        let push = Instruction::Push(PUSHData::new(Register::R15));
+        let cmp = Instruction::Cmp(CMPData::new(Register::R3, Register::R4));
+        let jmp = Instruction::Jmp(JMPData::new(7));
+        let jns = Instruction::Jns(JNSData::new(Flag::Equal, 2));
+        assert_eq!(format!("{}", mov1), "mov 50, r0");
+        assert_eq!(format!("{}", mov2), "mov r1, r0");
+        assert_eq!(format!("{}", add), "add r0, r1, r2");
+        assert_eq!(format!("{}", push), "push r15");
+        assert_eq!(format!("{}", cmp), "cmp r3, r4");
+        let sub = Instruction::Sub(SUBData::from_literal(Register::R0, 1, Register::R0));
+        let cmp_lit = Instruction::Cmp(CMPData::from_literal(Register::R3, 100));
+        assert_eq!(format!("{}", sub), "sub r0, 1, r0");
+        assert_eq!(format!("{}", cmp_lit), "cmp r3, 100");
+        let and = Instruction::And(ANDData::from_literal(Register::R0, 255, Register::R1));
+        let not = Instruction::Not(NOTData::new(Register::R2, Register::R3));
+        let shr = Instruction::Shr(SHRData::new(Register::R4, 3, Register::R5));
+        assert_eq!(format!("{}", and), "and r0, 255, r1");
+        assert_eq!(format!("{}", not), "not r2, r3");
+        assert_eq!(format!("{}", shr), "shr r4, 3, r5");
+        assert_eq!(format!("{}", jmp), "jmp L7");
+        assert_eq!(format!("{}", jns), "jns flag_Equal, L2");
+        let blk = Instruction::Blk(BLKData::new(
+            Coordinate::Absolute(10),
+            Coordinate::Relative(-1),
+            Coordinate::Register(Register::R2),
+            BlockState::new("oak_stairs").with_property("facing", "north"),
+        ));
+        assert_eq!(
+            format!("{}", blk),
+            "blk 10, ~-1, r2, minecraft:oak_stairs[facing=north]"
+        );
+    }
+
+    // tests equality and hashing
+    #[test]
+    fn test_equality_and_hashing() {
+        let i1 = Instruction::Chk(CHKData::new(Register::R0));
+        let i2 = Instruction::Chk(CHKData::new(Register::R0));
+        let i3 = Instruction::Push(PUSHData::new(Register::R0));
+        assert_eq!(i1, i2);
+        assert_ne!(i1, i3);
+
+        // equal instructions should hash identically
+        let mut set = HashSet::new();
+        set.insert(i1);
+        set.insert(i2);
+        set.insert(i3);
+        assert_eq!(set.len(), 2);
+    }
+}
+
+// end of file
@@ -0,0 +1,64 @@
+/*
+ * jcc_data.rs
+ * Defines the metadata structure for the J<cc> instructions
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statement
+use super::super::cpu::Condition;
+
+/// Contains metadata for the condition-code jumps
+/// (`JEQ`, `JNE`, `JGT`, `JGE`, `JLT`, `JLE`, `JZ` and `JNZ`)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct JCCData {
+    /// The `Condition` to test
+    cond: Condition,
+
+    /// The ID of the label to jump to if `cond` holds
+    label_id: u32,
+}
+
+// implementation
+impl JCCData {
+    /// Creates a new `JCCData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `cond` - The `Condition` to test
+    /// * `lbl_id` - The ID of the label to jump to if `cond` holds
+    ///
+    /// # Returns
+    ///
+    /// A new `JCCData` instance with the specified data
+    pub fn new(cond: Condition, lbl_id: u32) -> Self {
+        JCCData {
+            cond,
+            label_id: lbl_id,
+        }
+    }
+
+    /// Gets the condition
+    ///
+    /// # Returns
+    ///
+    /// The `Condition` to test
+    pub fn condition(&self) -> Condition {
+        self.cond
+    }
+
+    /// Gets the label ID to jump to if the condition holds
+    ///
+    /// # Returns
+    ///
+    /// The label ID to jump to
+    pub fn jmp_label_id(&self) -> u32 {
+        self.label_id
+    }
+}
+
+// end of file
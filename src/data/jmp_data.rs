@@ -12,7 +12,7 @@
 // no usage statements
 
 /// Contains metadata for the `JMP` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct JMPData {
     /// The ID of the label to jump unconditionally to
     label_id: u32
@@ -13,7 +13,7 @@
 use super::super::cpu::Flag;
 
 /// Contains metadata for the `JNS` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct JNSData {
     /// The condition `Flag` 
     cond_flg: Flag, 
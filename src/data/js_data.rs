@@ -13,7 +13,7 @@
 use super::super::cpu::Flag;
 
 /// Contains metadata for the `JS` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct JSData {
     /// The condition `Flag` 
     cond_flg: Flag, 
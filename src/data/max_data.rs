@@ -0,0 +1,150 @@
+/*
+ * max_data.rs
+ * Defines the metadata structure for the MAX instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Register;
+
+/// Metadata for the `MAX` instruction
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MAXData {
+    /// The first `Register` being compared
+    arg_0: Register,
+
+    /// The second value being compared (if a literal)
+    arg_1_lit: Option<u32>,
+
+    /// The second value being compared (if a register)
+    arg_1_reg: Option<Register>,
+
+    /// The `Register` to store the maximum in
+    max_dest: Register,
+}
+
+// implementation
+impl MAXData {
+    /// Creates a new `MAXData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` being compared
+    /// * `arg1` - The second `Register` being compared
+    /// * `dest` - The `Register` that the maximum is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `MAXData` instance with the specified data
+    pub fn new(arg0: Register, arg1: Register, dest: Register) -> Self {
+        MAXData {
+            arg_0: arg0,
+            arg_1_lit: None,
+            arg_1_reg: Some(arg1),
+            max_dest: dest
+        }
+    }
+
+    /// Creates a new `MAXData` instance with a register
+    /// as the second argument, like `MAXData::new`
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` being compared
+    /// * `arg1` - The second `Register` being compared
+    /// * `dest` - The `Register` that the maximum is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `MAXData` instance with the specified data
+    pub fn from_register(arg0: Register, arg1: Register, dest: Register) -> Self {
+        MAXData::new(arg0, arg1, dest)
+    }
+
+    /// Creates a new `MAXData` instance with a literal
+    /// as the second argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` being compared
+    /// * `arg1` - The second value being compared, as a literal
+    /// * `dest` - The `Register` that the maximum is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `MAXData` instance with the specified data
+    pub fn from_literal(arg0: Register, arg1: u32, dest: Register) -> Self {
+        MAXData {
+            arg_0: arg0,
+            arg_1_lit: Some(arg1),
+            arg_1_reg: None,
+            max_dest: dest,
+        }
+    }
+
+    /// Returns whether the second argument is a literal
+    ///
+    /// # Returns
+    ///
+    /// Whether the associated instruction has a literal as its second argument
+    pub fn has_literal_arg1(&self) -> bool {
+        self.arg_1_lit.is_some()
+    }
+
+    /// Gets the second argument literal
+    ///
+    /// # Returns
+    ///
+    /// The second argument literal, wrapped in an `Option`
+    pub fn arg1_literal(&self) -> Option<u32> {
+        self.arg_1_lit
+    }
+
+    /// Gets the second argument register
+    ///
+    /// # Returns
+    ///
+    /// The second argument register, wrapped in an `Option`
+    pub fn arg1_register(&self) -> Option<Register> {
+        self.arg_1_reg
+    }
+
+    /// Gets the first `Register` being compared
+    ///
+    /// # Returns
+    ///
+    /// The first argument register
+    pub fn arg0(&self) -> Register {
+        self.arg_0
+    }
+
+    /// Gets the second `Register` being compared
+    ///
+    /// # Returns
+    ///
+    /// The second argument register
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if called on an instance that does
+    /// not have a register as its second argument.
+    pub fn arg1(&self) -> Register {
+        self.arg_1_reg.unwrap()
+    }
+
+    /// Gets the destination `Register`
+    ///
+    /// # Returns
+    ///
+    /// The destination register for the maximum
+    pub fn dest(&self) -> Register {
+        self.max_dest
+    }
+}
+
+// end of file
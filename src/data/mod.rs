@@ -20,6 +20,24 @@ mod mul_data;
 pub use mul_data::MULData;
 mod div_data;
 pub use div_data::DIVData;
+mod mod_data;
+pub use mod_data::MODData;
+mod min_data;
+pub use min_data::MINData;
+mod max_data;
+pub use max_data::MAXData;
+mod and_data;
+pub use and_data::ANDData;
+mod or_data;
+pub use or_data::ORData;
+mod xor_data;
+pub use xor_data::XORData;
+mod not_data;
+pub use not_data::NOTData;
+mod shl_data;
+pub use shl_data::SHLData;
+mod shr_data;
+pub use shr_data::SHRData;
 mod push_data;
 pub use push_data::PUSHData;
 mod pop_data;
@@ -34,5 +52,38 @@ mod js_data;
 pub use js_data::JSData;
 mod jns_data;
 pub use jns_data::JNSData;
+mod jcc_data;
+pub use jcc_data::JCCData;
+mod call_data;
+pub use call_data::CALLData;
+mod ret_data;
+pub use ret_data::RETData;
+mod hlt_data;
+pub use hlt_data::HLTData;
+mod nop_data;
+pub use nop_data::NOPData;
+mod inc_data;
+pub use inc_data::INCData;
+mod dec_data;
+pub use dec_data::DECData;
+mod neg_data;
+pub use neg_data::NEGData;
+mod coordinate;
+pub use coordinate::Coordinate;
+mod block_state;
+pub use block_state::BlockState;
+mod blk_data;
+pub use blk_data::BLKData;
+mod selector;
+pub use selector::Selector;
+pub use selector::SelectorArg;
+pub use selector::SelectorKind;
+pub use selector::SortOrder;
+mod ent_data;
+pub use ent_data::ENTData;
+mod exg_data;
+pub use exg_data::EXGData;
+mod instruction;
+pub use instruction::Instruction;
 
 // end of file
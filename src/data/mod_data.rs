@@ -0,0 +1,150 @@
+/*
+ * mod_data.rs
+ * Defines the metadata structure for the MOD instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Register;
+
+/// Metadata for the `MOD` instruction
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MODData {
+    /// The `Register` being divided
+    arg_0: Register,
+
+    /// The value being divided by (if a literal)
+    arg_1_lit: Option<u32>,
+
+    /// The value being divided by (if a register)
+    arg_1_reg: Option<Register>,
+
+    /// The `Register` to store the remainder in
+    rem_dest: Register,
+}
+
+// implementation
+impl MODData {
+    /// Creates a new `MODData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` being divided
+    /// * `arg1` - The `Register` being divided by
+    /// * `dest` - The `Register` that the remainder is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `MODData` instance with the specified data
+    pub fn new(arg0: Register, arg1: Register, dest: Register) -> Self {
+        MODData {
+            arg_0: arg0,
+            arg_1_lit: None,
+            arg_1_reg: Some(arg1),
+            rem_dest: dest
+        }
+    }
+
+    /// Creates a new `MODData` instance with a register
+    /// as the second argument, like `MODData::new`
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` being divided
+    /// * `arg1` - The `Register` being divided by
+    /// * `dest` - The `Register` that the remainder is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `MODData` instance with the specified data
+    pub fn from_register(arg0: Register, arg1: Register, dest: Register) -> Self {
+        MODData::new(arg0, arg1, dest)
+    }
+
+    /// Creates a new `MODData` instance with a literal
+    /// as the second argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` being divided
+    /// * `arg1` - The value being divided by, as a literal
+    /// * `dest` - The `Register` that the remainder is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `MODData` instance with the specified data
+    pub fn from_literal(arg0: Register, arg1: u32, dest: Register) -> Self {
+        MODData {
+            arg_0: arg0,
+            arg_1_lit: Some(arg1),
+            arg_1_reg: None,
+            rem_dest: dest,
+        }
+    }
+
+    /// Returns whether the second argument is a literal
+    ///
+    /// # Returns
+    ///
+    /// Whether the associated instruction has a literal as its second argument
+    pub fn has_literal_arg1(&self) -> bool {
+        self.arg_1_lit.is_some()
+    }
+
+    /// Gets the second argument literal
+    ///
+    /// # Returns
+    ///
+    /// The second argument literal, wrapped in an `Option`
+    pub fn arg1_literal(&self) -> Option<u32> {
+        self.arg_1_lit
+    }
+
+    /// Gets the second argument register
+    ///
+    /// # Returns
+    ///
+    /// The second argument register, wrapped in an `Option`
+    pub fn arg1_register(&self) -> Option<Register> {
+        self.arg_1_reg
+    }
+
+    /// Gets the `Register` being divided
+    ///
+    /// # Returns
+    ///
+    /// The first argument register
+    pub fn arg0(&self) -> Register {
+        self.arg_0
+    }
+
+    /// Gets the `Register` being divided by
+    ///
+    /// # Returns
+    ///
+    /// The second argument register
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if called on an instance that does
+    /// not have a register as its second argument.
+    pub fn arg1(&self) -> Register {
+        self.arg_1_reg.unwrap()
+    }
+
+    /// Gets the destination `Register`
+    ///
+    /// # Returns
+    ///
+    /// The destination register for the remainder
+    pub fn dest(&self) -> Register {
+        self.rem_dest
+    }
+}
+
+// end of file
@@ -13,7 +13,7 @@
 use super::super::cpu::Register;
 
 /// Metadata for the `MOV` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MOVData {
     /// The source data (if a literal)
     src_lit: Option<u32>,
@@ -163,7 +163,7 @@ mod tests {
         let d1 = MOVData::from_literal(0x32, Register::R0);
         let d2 = MOVData::from_register(Register::R0, Register::R1);
         assert!(d1.has_literal_source());
-        assert_eq!(d2.has_literal_source(), false);
+        assert!(!d2.has_literal_source());
     }
 
     // Tests the has_register_source method
@@ -172,7 +172,7 @@ mod tests {
         let d1 = MOVData::from_register(Register::R0, Register::R1);
         let d2 = MOVData::from_literal(0x32, Register::R0);
         assert!(d1.has_register_source());
-        assert_eq!(d2.has_register_source(), false);
+        assert!(!d2.has_register_source());
     }
 }
 
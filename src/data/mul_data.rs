@@ -13,13 +13,16 @@
 use super::super::cpu::Register;
 
 /// Metadata for the `MUL` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MULData {
     /// The first `Register` being multiplied
     arg_0: Register,
 
-    /// The second `Register` being multiplied
-    arg_1: Register,
+    /// The second value being multiplied (if a literal)
+    arg_1_lit: Option<u32>,
+
+    /// The second value being multiplied (if a register)
+    arg_1_reg: Option<Register>,
 
     /// The `Register` to store the product in
     prod_dest: Register,
@@ -41,11 +44,76 @@ impl MULData {
     pub fn new(arg0: Register, arg1: Register, dest: Register) -> Self {
         MULData {
             arg_0: arg0,
-            arg_1: arg1,
+            arg_1_lit: None,
+            arg_1_reg: Some(arg1),
             prod_dest: dest 
         }
     }
 
+    /// Creates a new `MULData` instance with a register
+    /// as the second argument, like `MULData::new`
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` being multiplied
+    /// * `arg1` - The second `Register` being multiplied
+    /// * `dest` - The `Register` that the product is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `MULData` instance with the specified data
+    pub fn from_register(arg0: Register, arg1: Register, dest: Register) -> Self {
+        MULData::new(arg0, arg1, dest)
+    }
+
+    /// Creates a new `MULData` instance with a literal
+    /// as the second argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` being multiplied
+    /// * `arg1` - The second value being multiplied, as a literal
+    /// * `dest` - The `Register` that the product is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `MULData` instance with the specified data
+    pub fn from_literal(arg0: Register, arg1: u32, dest: Register) -> Self {
+        MULData {
+            arg_0: arg0,
+            arg_1_lit: Some(arg1),
+            arg_1_reg: None,
+            prod_dest: dest,
+        }
+    }
+
+    /// Returns whether the second argument is a literal
+    ///
+    /// # Returns
+    ///
+    /// Whether the associated instruction has a literal as its second argument
+    pub fn has_literal_arg1(&self) -> bool {
+        self.arg_1_lit.is_some()
+    }
+
+    /// Gets the second argument literal
+    ///
+    /// # Returns
+    ///
+    /// The second argument literal, wrapped in an `Option`
+    pub fn arg1_literal(&self) -> Option<u32> {
+        self.arg_1_lit
+    }
+
+    /// Gets the second argument register
+    ///
+    /// # Returns
+    ///
+    /// The second argument register, wrapped in an `Option`
+    pub fn arg1_register(&self) -> Option<Register> {
+        self.arg_1_reg
+    }
+
     /// Gets the first `Register` being multiplied
     ///
     /// # Returns
@@ -60,8 +128,13 @@ impl MULData {
     /// # Returns 
     ///
     /// The second argument register
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if called on an instance that does
+    /// not have a register as its second argument.
     pub fn arg1(&self) -> Register {
-        self.arg_1 
+        self.arg_1_reg.unwrap()
     }
 
     /// Gets the destination `Register`
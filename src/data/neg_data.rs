@@ -0,0 +1,49 @@
+/*
+ * neg_data.rs
+ * Defines the metadata structure for the NEG instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Register;
+
+/// Contains metadata for the `NEG` instruction
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NEGData {
+    /// The `Register` being negated in place
+    reg: Register,
+}
+
+// implementation
+impl NEGData {
+    /// Creates a new `NEGData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The `Register` to negate
+    ///
+    /// # Returns
+    ///
+    /// A new `NEGData` instance with the specified data
+    pub fn new(r: Register) -> Self {
+        NEGData {
+            reg: r,
+        }
+    }
+
+    /// Gets the `Register` being negated
+    ///
+    /// # Returns
+    ///
+    /// The `Register` being negated in place
+    pub fn arg0(&self) -> Register {
+        self.reg
+    }
+}
+
+// end of file
@@ -0,0 +1,33 @@
+/*
+ * nop_data.rs
+ * Defines the metadata structure for the NOP instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// no usage statements
+
+/// Contains metadata for the `NOP` instruction,
+/// which does nothing
+///
+/// `NOP` takes no operands, so this structure holds no data.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NOPData;
+
+// implementation
+impl NOPData {
+    /// Creates a new `NOPData` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `NOPData` instance
+    pub fn new() -> Self {
+        NOPData
+    }
+}
+
+// end of file
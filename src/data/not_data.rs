@@ -0,0 +1,64 @@
+/*
+ * not_data.rs
+ * Defines the metadata structure for the NOT instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statement
+use super::super::cpu::Register;
+
+/// Metadata for the `NOT` instruction, which
+/// stores the bitwise complement of a register
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NOTData {
+    /// The `Register` being complemented
+    arg_0: Register,
+
+    /// The `Register` to store the complement in
+    not_dest: Register,
+}
+
+// implementation
+impl NOTData {
+    /// Creates a new `NOTData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` being complemented
+    /// * `dest` - The `Register` that the complement is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `NOTData` instance with the specified data
+    pub fn new(arg0: Register, dest: Register) -> Self {
+        NOTData {
+            arg_0: arg0,
+            not_dest: dest,
+        }
+    }
+
+    /// Gets the `Register` being complemented
+    ///
+    /// # Returns
+    ///
+    /// The argument register
+    pub fn arg0(&self) -> Register {
+        self.arg_0
+    }
+
+    /// Gets the destination `Register`
+    ///
+    /// # Returns
+    ///
+    /// The destination register for the complement
+    pub fn dest(&self) -> Register {
+        self.not_dest
+    }
+}
+
+// end of file
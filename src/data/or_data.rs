@@ -0,0 +1,150 @@
+/*
+ * or_data.rs
+ * Defines the metadata structure for the OR instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Register;
+
+/// Metadata for the `OR` instruction
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ORData {
+    /// The first `Register` being combined
+    arg_0: Register,
+
+    /// The second value being combined (if a literal)
+    arg_1_lit: Option<u32>,
+
+    /// The second value being combined (if a register)
+    arg_1_reg: Option<Register>,
+
+    /// The `Register` to store the bitwise OR in
+    or_dest: Register,
+}
+
+// implementation
+impl ORData {
+    /// Creates a new `ORData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` being combined
+    /// * `arg1` - The second `Register` being combined
+    /// * `dest` - The `Register` that the bitwise OR is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `ORData` instance with the specified data
+    pub fn new(arg0: Register, arg1: Register, dest: Register) -> Self {
+        ORData {
+            arg_0: arg0,
+            arg_1_lit: None,
+            arg_1_reg: Some(arg1),
+            or_dest: dest
+        }
+    }
+
+    /// Creates a new `ORData` instance with a register
+    /// as the second argument, like `ORData::new`
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` being combined
+    /// * `arg1` - The second `Register` being combined
+    /// * `dest` - The `Register` that the bitwise OR is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `ORData` instance with the specified data
+    pub fn from_register(arg0: Register, arg1: Register, dest: Register) -> Self {
+        ORData::new(arg0, arg1, dest)
+    }
+
+    /// Creates a new `ORData` instance with a literal
+    /// as the second argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first `Register` being combined
+    /// * `arg1` - The second value being combined, as a literal
+    /// * `dest` - The `Register` that the bitwise OR is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `ORData` instance with the specified data
+    pub fn from_literal(arg0: Register, arg1: u32, dest: Register) -> Self {
+        ORData {
+            arg_0: arg0,
+            arg_1_lit: Some(arg1),
+            arg_1_reg: None,
+            or_dest: dest,
+        }
+    }
+
+    /// Returns whether the second argument is a literal
+    ///
+    /// # Returns
+    ///
+    /// Whether the associated instruction has a literal as its second argument
+    pub fn has_literal_arg1(&self) -> bool {
+        self.arg_1_lit.is_some()
+    }
+
+    /// Gets the second argument literal
+    ///
+    /// # Returns
+    ///
+    /// The second argument literal, wrapped in an `Option`
+    pub fn arg1_literal(&self) -> Option<u32> {
+        self.arg_1_lit
+    }
+
+    /// Gets the second argument register
+    ///
+    /// # Returns
+    ///
+    /// The second argument register, wrapped in an `Option`
+    pub fn arg1_register(&self) -> Option<Register> {
+        self.arg_1_reg
+    }
+
+    /// Gets the first `Register` being combined
+    ///
+    /// # Returns
+    ///
+    /// The first argument register
+    pub fn arg0(&self) -> Register {
+        self.arg_0
+    }
+
+    /// Gets the second `Register` being combined
+    ///
+    /// # Returns
+    ///
+    /// The second argument register
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if called on an instance that does
+    /// not have a register as its second argument.
+    pub fn arg1(&self) -> Register {
+        self.arg_1_reg.unwrap()
+    }
+
+    /// Gets the destination `Register`
+    ///
+    /// # Returns
+    ///
+    /// The destination register for the bitwise OR
+    pub fn dest(&self) -> Register {
+        self.or_dest
+    }
+}
+
+// end of file
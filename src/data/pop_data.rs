@@ -13,7 +13,7 @@
 use super::super::cpu::Register;
 
 /// Contains metadata for the `POP` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct POPData {
     /// The `Register` being popped into from the stack
     reg: Register 
@@ -13,7 +13,7 @@
 use super::super::cpu::Register;
 
 /// Contains metadata for the `PUSH` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PUSHData {
     /// The `Register` being pushed onto the stack
     reg: Register 
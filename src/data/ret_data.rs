@@ -0,0 +1,36 @@
+/*
+ * ret_data.rs
+ * Defines the metadata structure for the RET instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// no usage statements
+
+/// Contains metadata for the `RET` instruction, which
+/// returns from a subroutine to the address on top of
+/// the call stack
+///
+/// `RET` takes no operands, so this structure holds no
+/// data and exists so that every `Instruction` variant
+/// has a metadata structure.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RETData;
+
+// implementation
+impl RETData {
+    /// Creates a new `RETData` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `RETData` instance
+    pub fn new() -> Self {
+        RETData
+    }
+}
+
+// end of file
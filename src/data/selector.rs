@@ -0,0 +1,394 @@
+/*
+ * selector.rs
+ * Defines a struct that models a target selector
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::fmt;
+use std::str::FromStr;
+
+/// The variable of a target selector
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SelectorKind {
+    /// `@e`, which selects every entity
+    AllEntities,
+
+    /// `@a`, which selects every player
+    AllPlayers,
+
+    /// `@p`, which selects the nearest player
+    NearestPlayer,
+
+    /// `@s`, which selects the executing entity
+    Executor,
+
+    /// `@r`, which selects a random player
+    RandomPlayer,
+}
+
+/// The order in which a target selector sorts entities
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// `sort=nearest`
+    Nearest,
+
+    /// `sort=furthest`
+    Furthest,
+
+    /// `sort=random`
+    Random,
+
+    /// `sort=arbitrary`
+    Arbitrary,
+}
+
+/// An argument of a target selector
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SelectorArg {
+    /// `type=<id>` or `type=!<id>`, which filters by entity type
+    Type {
+        /// The namespaced entity type
+        id: String,
+
+        /// Whether entities of the type are excluded instead
+        negated: bool,
+    },
+
+    /// `tag=<name>` or `tag=!<name>`, which filters by tag
+    Tag {
+        /// The name of the tag
+        name: String,
+
+        /// Whether entities with the tag are excluded instead
+        negated: bool,
+    },
+
+    /// `distance=<min>..<max>`, which filters by distance
+    /// in blocks from the executing position
+    Distance {
+        /// The minimum distance, if any
+        min: Option<u32>,
+
+        /// The maximum distance, if any
+        max: Option<u32>,
+    },
+
+    /// `limit=<count>`, which caps the number of entities selected
+    Limit(u32),
+
+    /// `sort=<order>`, which orders the entities selected
+    Sort(SortOrder),
+}
+
+/// A target selector, such as `@e[type=minecraft:zombie,distance=..8]`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Selector {
+    /// The selector variable
+    kind: SelectorKind,
+
+    /// The selector arguments, in the order they were given
+    args: Vec<SelectorArg>,
+}
+
+// implementation
+impl Selector {
+    /// Creates a new `Selector` instance with no arguments
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The selector variable
+    ///
+    /// # Returns
+    ///
+    /// A new `Selector` instance with the given variable
+    pub fn new(kind: SelectorKind) -> Self {
+        Selector {
+            kind,
+            args: Vec::new(),
+        }
+    }
+
+    /// Adds an argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg` - The argument to add
+    ///
+    /// # Returns
+    ///
+    /// The `Selector` with the argument added
+    pub fn with_arg(mut self, arg: SelectorArg) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    /// Gets the selector variable
+    ///
+    /// # Returns
+    ///
+    /// The variable of the selector
+    pub fn kind(&self) -> SelectorKind {
+        self.kind
+    }
+
+    /// Gets the selector arguments
+    ///
+    /// # Returns
+    ///
+    /// The arguments of the selector, in the order they were given
+    pub fn args(&self) -> &[SelectorArg] {
+        &self.args
+    }
+}
+
+/// [Internal use only]
+/// Gives a name the `minecraft` namespace if it does not have one
+///
+/// # Arguments
+///
+/// * `id` - The name to namespace
+///
+/// # Returns
+///
+/// The namespaced name
+fn namespaced(id: &str) -> String {
+    if id.contains(':') {
+        id.to_owned()
+    } else {
+        format!("minecraft:{}", id)
+    }
+}
+
+/// [Internal use only]
+/// Parses a distance range such as `2..8`, `..8`, or `5`
+///
+/// # Arguments
+///
+/// * `s` - The text of the range
+///
+/// # Returns
+///
+/// The minimum and maximum of the range, or `Err` if it is invalid
+fn parse_range(s: &str) -> Result<(Option<u32>, Option<u32>), ()> {
+    let bound = |b: &str| -> Result<Option<u32>, ()> {
+        if b.is_empty() {
+            Ok(None)
+        } else {
+            b.parse().map(Some).map_err(|_| ())
+        }
+    };
+    let (min, max) = match s.find("..") {
+        Some(idx) => (bound(&s[..idx])?, bound(&s[idx + 2..])?),
+        None => {
+            let val = bound(s)?;
+            (val, val)
+        }
+    };
+    match (min, max) {
+        (None, None) => Err(()),
+        (Some(lo), Some(hi)) if lo > hi => Err(()),
+        _ => Ok((min, max)),
+    }
+}
+
+// Display implementations
+impl fmt::Display for SelectorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let var = match self {
+            SelectorKind::AllEntities => "@e",
+            SelectorKind::AllPlayers => "@a",
+            SelectorKind::NearestPlayer => "@p",
+            SelectorKind::Executor => "@s",
+            SelectorKind::RandomPlayer => "@r",
+        };
+        write!(f, "{}", var)
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let order = match self {
+            SortOrder::Nearest => "nearest",
+            SortOrder::Furthest => "furthest",
+            SortOrder::Random => "random",
+            SortOrder::Arbitrary => "arbitrary",
+        };
+        write!(f, "{}", order)
+    }
+}
+
+impl fmt::Display for SelectorArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bang = |negated: &bool| if *negated { "!" } else { "" };
+        match self {
+            SelectorArg::Type { id, negated } => write!(f, "type={}{}", bang(negated), id),
+            SelectorArg::Tag { name, negated } => write!(f, "tag={}{}", bang(negated), name),
+            SelectorArg::Distance { min, max } if min == max && min.is_some() => {
+                write!(f, "distance={}", min.unwrap())
+            }
+            SelectorArg::Distance { min, max } => {
+                write!(f, "distance=")?;
+                if let Some(min) = min {
+                    write!(f, "{}", min)?;
+                }
+                write!(f, "..")?;
+                if let Some(max) = max {
+                    write!(f, "{}", max)?;
+                }
+                Ok(())
+            }
+            SelectorArg::Limit(n) => write!(f, "limit={}", n),
+            SelectorArg::Sort(order) => write!(f, "sort={}", order),
+        }
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if !self.args.is_empty() {
+            let args: Vec<String> = self.args.iter().map(|a| a.to_string()).collect();
+            write!(f, "[{}]", args.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+// FromStr implementation
+impl FromStr for Selector {
+    // create an error type
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // split the variable from the arguments
+        let (var, rest) = match s.find('[') {
+            Some(idx) => (&s[..idx], Some(&s[idx + 1..])),
+            None => (s, None),
+        };
+        let kind = match var {
+            "@e" => SelectorKind::AllEntities,
+            "@a" => SelectorKind::AllPlayers,
+            "@p" => SelectorKind::NearestPlayer,
+            "@s" => SelectorKind::Executor,
+            "@r" => SelectorKind::RandomPlayer,
+            _ => return Err(()),
+        };
+        let mut selector = Selector::new(kind);
+
+        if let Some(rest) = rest {
+            let inner = rest.strip_suffix(']').ok_or(())?;
+            let valid = |c: char| c.is_ascii_alphanumeric() || "_-.+:".contains(c);
+            for arg in inner.split(',').filter(|a| !a.is_empty()) {
+                let mut parts = arg.splitn(2, '=');
+                let key = parts.next().ok_or(())?;
+                let val = parts.next().ok_or(())?;
+                let (negated, name) = match val.strip_prefix('!') {
+                    Some(name) => (true, name),
+                    None => (false, val),
+                };
+                let arg = match key {
+                    "type" | "tag" if name.is_empty() || !name.chars().all(valid) => return Err(()),
+                    "type" => SelectorArg::Type {
+                        id: namespaced(name),
+                        negated,
+                    },
+                    "tag" => SelectorArg::Tag {
+                        name: name.to_owned(),
+                        negated,
+                    },
+                    "distance" => {
+                        let (min, max) = parse_range(val)?;
+                        SelectorArg::Distance { min, max }
+                    }
+                    "limit" => match val.parse() {
+                        Ok(n) if n > 0 => SelectorArg::Limit(n),
+                        _ => return Err(()),
+                    },
+                    "sort" => SelectorArg::Sort(match val {
+                        "nearest" => SortOrder::Nearest,
+                        "furthest" => SortOrder::Furthest,
+                        "random" => SortOrder::Random,
+                        "arbitrary" => SortOrder::Arbitrary,
+                        _ => return Err(()),
+                    }),
+                    _ => return Err(()),
+                };
+                selector = selector.with_arg(arg);
+            }
+        }
+
+        Ok(selector)
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the struct into scope
+    use super::*;
+
+    // tests display formatting
+    #[test]
+    fn test_display_formatting() {
+        let sel = Selector::new(SelectorKind::AllEntities)
+            .with_arg(SelectorArg::Type {
+                id: "minecraft:zombie".to_owned(),
+                negated: false,
+            })
+            .with_arg(SelectorArg::Tag {
+                name: "boss".to_owned(),
+                negated: true,
+            })
+            .with_arg(SelectorArg::Distance {
+                min: None,
+                max: Some(8),
+            })
+            .with_arg(SelectorArg::Limit(1))
+            .with_arg(SelectorArg::Sort(SortOrder::Nearest));
+        assert_eq!(
+            format!("{}", sel),
+            "@e[type=minecraft:zombie,tag=!boss,distance=..8,limit=1,sort=nearest]"
+        );
+        assert_eq!(format!("{}", Selector::new(SelectorKind::Executor)), "@s");
+    }
+
+    // tests parsing from a string
+    #[test]
+    fn test_from_str() {
+        let sel: Selector = "@a[type=!cow,distance=2..,distance=5]".parse().unwrap();
+        assert_eq!(sel.kind(), SelectorKind::AllPlayers);
+        assert_eq!(
+            sel.args(),
+            &[
+                SelectorArg::Type {
+                    id: "minecraft:cow".to_owned(),
+                    negated: true,
+                },
+                SelectorArg::Distance {
+                    min: Some(2),
+                    max: None,
+                },
+                SelectorArg::Distance {
+                    min: Some(5),
+                    max: Some(5),
+                },
+            ]
+        );
+        assert_eq!(format!("{}", sel), "@a[type=!minecraft:cow,distance=2..,distance=5]");
+        assert_eq!("@r".parse(), Ok(Selector::new(SelectorKind::RandomPlayer)));
+        assert_eq!("@x".parse::<Selector>(), Err(()));
+        assert_eq!("@e[limit=0]".parse::<Selector>(), Err(()));
+        assert_eq!("@e[distance=..]".parse::<Selector>(), Err(()));
+        assert_eq!("@e[distance=5..2]".parse::<Selector>(), Err(()));
+        assert_eq!("@e[sort=up]".parse::<Selector>(), Err(()));
+        assert_eq!("@e[name=bob]".parse::<Selector>(), Err(()));
+    }
+}
+
+// end of file
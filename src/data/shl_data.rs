@@ -0,0 +1,82 @@
+/*
+ * shl_data.rs
+ * Defines the metadata structure for the SHL instruction
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statement
+use super::super::cpu::Register;
+
+/// Metadata for the `SHL` instruction,
+/// which multiplies a register by a power of two
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SHLData {
+    /// The `Register` being shifted
+    arg_0: Register,
+
+    /// The number of bits to shift by, from 0 to `MAX_AMOUNT`
+    amount: u32,
+
+    /// The `Register` to store the shifted value in
+    shift_dest: Register,
+}
+
+// implementation
+impl SHLData {
+    /// The largest number of bits that a value can be shifted by
+    pub const MAX_AMOUNT: u32 = 31;
+
+    /// Creates a new `SHLData` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` being shifted
+    /// * `amount` - The number of bits to shift left by
+    /// * `dest` - The `Register` that the shifted value is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `SHLData` instance with the specified data
+    pub fn new(arg0: Register, amount: u32, dest: Register) -> Self {
+        SHLData {
+            arg_0: arg0,
+            amount,
+            shift_dest: dest,
+        }
+    }
+
+    /// Gets the `Register` being shifted
+    ///
+    /// # Returns
+    ///
+    /// The argument register
+    pub fn arg0(&self) -> Register {
+        self.arg_0
+    }
+
+    /// Gets the number of bits to shift by
+    ///
+    /// # Returns
+    ///
+    /// The shift amount, which is only valid
+    /// if it is at most `MAX_AMOUNT`
+    pub fn amount(&self) -> u32 {
+        self.amount
+    }
+
+    /// Gets the destination `Register`
+    ///
+    /// # Returns
+    ///
+    /// The destination register for the shifted value
+    pub fn dest(&self) -> Register {
+        self.shift_dest
+    }
+}
+
+// end of file
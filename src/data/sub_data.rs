@@ -13,13 +13,16 @@
 use super::super::cpu::Register;
 
 /// Metadata for the `SUB` instruction
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SUBData {
     /// The `Register` to subtract from
     arg_0: Register,
 
-    /// The `Register` being subtracted
-    arg_1: Register,
+    /// The value being subtracted (if a literal)
+    arg_1_lit: Option<u32>,
+
+    /// The value being subtracted (if a register)
+    arg_1_reg: Option<Register>,
 
     /// The `Register` to store the difference in
     diff_dest: Register,
@@ -41,11 +44,76 @@ impl SUBData {
     pub fn new(arg0: Register, arg1: Register, dest: Register) -> Self {
         SUBData {
             arg_0: arg0,
-            arg_1: arg1,
+            arg_1_lit: None,
+            arg_1_reg: Some(arg1),
             diff_dest: dest 
         }
     }
 
+    /// Creates a new `SUBData` instance with a register
+    /// as the second argument, like `SUBData::new`
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` to subtract from
+    /// * `arg1` - The `Register` being subtracted
+    /// * `dest` - The `Register` that the difference is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `SUBData` instance with the specified data
+    pub fn from_register(arg0: Register, arg1: Register, dest: Register) -> Self {
+        SUBData::new(arg0, arg1, dest)
+    }
+
+    /// Creates a new `SUBData` instance with a literal
+    /// as the second argument
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The `Register` to subtract from
+    /// * `arg1` - The value being subtracted, as a literal
+    /// * `dest` - The `Register` that the difference is stored in
+    ///
+    /// # Returns
+    ///
+    /// A new `SUBData` instance with the specified data
+    pub fn from_literal(arg0: Register, arg1: u32, dest: Register) -> Self {
+        SUBData {
+            arg_0: arg0,
+            arg_1_lit: Some(arg1),
+            arg_1_reg: None,
+            diff_dest: dest,
+        }
+    }
+
+    /// Returns whether the second argument is a literal
+    ///
+    /// # Returns
+    ///
+    /// Whether the associated instruction has a literal as its second argument
+    pub fn has_literal_arg1(&self) -> bool {
+        self.arg_1_lit.is_some()
+    }
+
+    /// Gets the second argument literal
+    ///
+    /// # Returns
+    ///
+    /// The second argument literal, wrapped in an `Option`
+    pub fn arg1_literal(&self) -> Option<u32> {
+        self.arg_1_lit
+    }
+
+    /// Gets the second argument register
+    ///
+    /// # Returns
+    ///
+    /// The second argument register, wrapped in an `Option`
+    pub fn arg1_register(&self) -> Option<Register> {
+        self.arg_1_reg
+    }
+
     /// Gets the `Register` being subtracted from
     ///
     /// # Returns
@@ -60,8 +128,13 @@ impl SUBData {
     /// # Returns 
     ///
     /// The second argument register
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if called on an instance that does
+    /// not have a register as its second argument.
     pub fn arg1(&self) -> Register {
-        self.arg_1
+        self.arg_1_reg.unwrap()
     }
 
     /// Gets the destination `Register`
@@ -0,0 +1,152 @@
+/*
+ * disassembler.rs
+ * Turns opcode streams back into assembly text
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::opcode::Decoder;
+use super::super::opcode::Opcode;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Disassembles an opcode stream into assembly text
+///
+/// Each instruction is printed on its own line in the
+/// syntax accepted by `asm::assemble`, and an `L<id>:`
+/// label is synthesized for every label ID referenced by
+/// a branching instruction. Words that fail to decode are
+/// printed as a placeholder `nop` followed by a comment holding
+/// the `DecodeError` reason, so they still count as one
+/// instruction when the text is assembled again.
+///
+/// # Arguments
+///
+/// * `words` - The opcode stream to disassemble
+///
+/// # Returns
+///
+/// The disassembled text
+pub fn disassemble(words: &[Opcode]) -> String {
+    let results: Vec<_> = Decoder::new(words).collect();
+
+    // find every label that is jumped to
+    let labels: BTreeSet<u32> = results
+        .iter()
+        .filter_map(|res| res.as_ref().ok())
+        .filter_map(|instr| instr.jmp_label_id())
+        .collect();
+
+    let mut text = String::new();
+    for (idx, res) in results.iter().enumerate() {
+        if labels.contains(&(idx as u32)) {
+            writeln!(text, "L{}:", idx).unwrap();
+        }
+        match res {
+            Ok(instr) => writeln!(text, "    {}", instr).unwrap(),
+            Err(err) => match err.reason() {
+                Some(r) => writeln!(text, "    nop ; {}: {}", err.op(), r).unwrap(),
+                None => writeln!(text, "    nop ; {}", err.op()).unwrap(),
+            },
+        }
+    }
+
+    // a label may also mark the end of the program
+    if labels.contains(&(results.len() as u32)) {
+        writeln!(text, "L{}:", results.len()).unwrap();
+    }
+
+    text
+}
+
+/// Disassembles a raw byte buffer into assembly text
+///
+/// The buffer holds big-endian 32-bit words. Any
+/// trailing bytes that do not form a whole word are
+/// noted in a comment at the end of the text.
+///
+/// # Arguments
+///
+/// * `bytes` - The byte buffer to disassemble
+///
+/// # Returns
+///
+/// The disassembled text
+pub fn disassemble_bytes(bytes: &[u8]) -> String {
+    let chunks = bytes.chunks_exact(4);
+    let rest = chunks.remainder();
+    let words: Vec<Opcode> = chunks
+        .map(|c| u32::from_be_bytes([c[0], c[1], c[2], c[3]]).into())
+        .collect();
+
+    let mut text = disassemble(&words);
+    if !rest.is_empty() {
+        writeln!(text, "    ; {} trailing byte(s) ignored", rest.len()).unwrap();
+    }
+    text
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the functions into scope
+    use super::*;
+    use super::super::super::asm::assemble;
+    use super::super::super::data::JMPData;
+    use super::super::super::data::NOPData;
+    use super::super::super::opcode::encode_all;
+
+    // tests disassembling a program with labels
+    #[test]
+    fn test_disassemble() {
+        let prog = assemble("top: mov 70000, r0\nchk r0\njs flag_Zero, done\njmp top\ndone:\n").unwrap();
+        let words = encode_all(prog.instructions()).unwrap();
+        let text = disassemble(&words);
+        assert_eq!(
+            text,
+            "L0:\n    mov 70000, r0\n    chk r0\n    js flag_Zero, L4\n    jmp L0\nL4:\n"
+        );
+
+        // and the text should assemble back into the same program
+        assert_eq!(assemble(&text).unwrap().instructions(), prog.instructions());
+    }
+
+    // tests disassembling undecodable words
+    #[test]
+    fn test_disassemble_errors() {
+        let words: Vec<Opcode> = vec![0xff000000.into(), 0x40000002.into(), 0x20100000.into()];
+        assert_eq!(
+            disassemble(&words),
+            "    nop ; 0xff000000: unknown mnemonic 0xff\n    jmp L2\nL2:\n    nop ; 0x20100000: source register field (field 0) holds reserved register 0x10\n"
+        );
+
+        // bad words keep their place, so labels after them stay numbered correctly
+        let words: Vec<Opcode> = vec![0xff000000.into(), 0x40000002.into(), 0x40000000.into()];
+        let text = disassemble(&words);
+        assert_eq!(
+            text,
+            "L0:\n    nop ; 0xff000000: unknown mnemonic 0xff\n    jmp L2\nL2:\n    jmp L0\n"
+        );
+        let prog = assemble(&text).unwrap();
+        assert_eq!(prog.instructions()[0], NOPData::new().into());
+        assert_eq!(prog.instructions()[1], JMPData::new(2).into());
+        assert_eq!(prog.instructions()[2], JMPData::new(0).into());
+    }
+
+    // tests disassembling a byte buffer
+    #[test]
+    fn test_disassemble_bytes() {
+        let bytes = [0x20, 0x01, 0x00, 0x00, 0xAB];
+        assert_eq!(
+            disassemble_bytes(&bytes),
+            "    push r1\n    ; 1 trailing byte(s) ignored\n"
+        );
+    }
+}
+
+// end of file
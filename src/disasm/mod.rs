@@ -0,0 +1,17 @@
+/*
+ * mod.rs
+ * Module header for mcisa's disasm module
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// module exports
+mod disassembler;
+pub use disassembler::disassemble;
+pub use disassembler::disassemble_bytes;
+
+// end of file
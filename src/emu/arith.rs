@@ -0,0 +1,387 @@
+/*
+ * arith.rs
+ * Implements scoreboard-accurate integer arithmetic
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// Each function below models one `scoreboard players operation`
+// operator acting on signed 32-bit scores, where `a` is the score
+// being operated on and `b` is the source score.
+//
+// Scoreboards have no bitwise operators, so the bitwise functions
+// instead model the command sequences that the code generator emits
+// for them, built out of the operators above:
+//
+// * `SHL` by `n` multiplies by 2^n, which wraps exactly like a
+//   two's complement left shift (2^31 is stored as `i32::MIN`)
+// * `SHR` by `n` floor-divides by 2^n, which rounds toward negative
+//   infinity like an arithmetic right shift (2^31 does not fit in a
+//   score, so a shift by 31 divides by 2^30 and then by 2)
+// * `NOT` subtracts the score from -1
+// * `AND`, `OR` and `XOR` peel off one bit of each score per round
+//   with `%= 2` and `/= 2`, combine the two bits with `<`, `>` or a
+//   sum modulo 2, and add the result times the bit's place value
+//   (which wraps to `i32::MIN` for the sign bit) into the result
+
+/// Adds two scores like the `+=` scoreboard operation
+///
+/// # Arguments
+///
+/// * `a` - The score being added to
+/// * `b` - The score being added
+///
+/// # Returns
+///
+/// The sum, wrapping on overflow
+pub fn score_add(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
+/// Subtracts two scores like the `-=` scoreboard operation
+///
+/// # Arguments
+///
+/// * `a` - The score being subtracted from
+/// * `b` - The score being subtracted
+///
+/// # Returns
+///
+/// The difference, wrapping on overflow
+pub fn score_sub(a: i32, b: i32) -> i32 {
+    a.wrapping_sub(b)
+}
+
+/// Multiplies two scores like the `*=` scoreboard operation
+///
+/// # Arguments
+///
+/// * `a` - The score being multiplied
+/// * `b` - The score being multiplied by
+///
+/// # Returns
+///
+/// The product, wrapping on overflow
+pub fn score_mul(a: i32, b: i32) -> i32 {
+    a.wrapping_mul(b)
+}
+
+/// Divides two scores like the `/=` scoreboard operation
+///
+/// # Arguments
+///
+/// * `a` - The score being divided
+/// * `b` - The score being divided by
+///
+/// # Returns
+///
+/// The quotient rounded toward negative infinity, wrapping
+/// on overflow, or `a` unchanged if `b` is zero
+pub fn score_div(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        return a;
+    }
+
+    // truncating division rounds toward zero, so step down
+    // when the quotient is negative and inexact
+    let quo = a.wrapping_div(b);
+    if a.wrapping_rem(b) != 0 && ((a < 0) != (b < 0)) {
+        quo - 1
+    } else {
+        quo
+    }
+}
+
+/// Takes the remainder of two scores like the `%=` scoreboard operation
+///
+/// # Arguments
+///
+/// * `a` - The score being divided
+/// * `b` - The score being divided by
+///
+/// # Returns
+///
+/// The remainder of floored division, which has the sign
+/// of `b`, or `a` unchanged if `b` is zero
+pub fn score_mod(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        return a;
+    }
+
+    // the truncating remainder has the sign of `a`, so shift
+    // it by `b` when the signs disagree
+    let rem = a.wrapping_rem(b);
+    if rem != 0 && ((rem < 0) != (b < 0)) {
+        rem + b
+    } else {
+        rem
+    }
+}
+
+/// Takes the smaller of two scores like the `<` scoreboard operation
+///
+/// # Arguments
+///
+/// * `a` - The score being replaced
+/// * `b` - The score being compared against
+///
+/// # Returns
+///
+/// The smaller of the two scores
+pub fn score_min(a: i32, b: i32) -> i32 {
+    a.min(b)
+}
+
+/// Takes the larger of two scores like the `>` scoreboard operation
+///
+/// # Arguments
+///
+/// * `a` - The score being replaced
+/// * `b` - The score being compared against
+///
+/// # Returns
+///
+/// The larger of the two scores
+pub fn score_max(a: i32, b: i32) -> i32 {
+    a.max(b)
+}
+
+/// Takes the bitwise AND of two scores
+///
+/// # Arguments
+///
+/// * `a` - The first score
+/// * `b` - The second score
+///
+/// # Returns
+///
+/// The bitwise AND of the scores' two's complement representations
+pub fn score_and(a: i32, b: i32) -> i32 {
+    bitwise(a, b, score_min)
+}
+
+/// Takes the bitwise OR of two scores
+///
+/// # Arguments
+///
+/// * `a` - The first score
+/// * `b` - The second score
+///
+/// # Returns
+///
+/// The bitwise OR of the scores' two's complement representations
+pub fn score_or(a: i32, b: i32) -> i32 {
+    bitwise(a, b, score_max)
+}
+
+/// Takes the bitwise XOR of two scores
+///
+/// # Arguments
+///
+/// * `a` - The first score
+/// * `b` - The second score
+///
+/// # Returns
+///
+/// The bitwise XOR of the scores' two's complement representations
+pub fn score_xor(a: i32, b: i32) -> i32 {
+    bitwise(a, b, |x, y| score_mod(score_add(x, y), 2))
+}
+
+/// Takes the bitwise complement of a score
+///
+/// # Arguments
+///
+/// * `a` - The score to complement
+///
+/// # Returns
+///
+/// The bitwise complement of the score's two's complement representation
+pub fn score_not(a: i32) -> i32 {
+    score_sub(-1, a)
+}
+
+/// Shifts a score left by multiplying it by a power of two
+///
+/// # Arguments
+///
+/// * `a` - The score to shift
+/// * `n` - The number of bits to shift by, from 0 to 31
+///
+/// # Returns
+///
+/// The shifted score, with bits shifted past bit 31 discarded
+///
+/// # Panics
+///
+/// Panics if `n` is greater than 31
+pub fn score_shl(a: i32, n: u32) -> i32 {
+    assert!(n <= 31, "shift amount {} is out of range", n);
+    score_mul(a, (1u32 << n) as i32)
+}
+
+/// Shifts a score right by floor-dividing it by a power of two
+///
+/// # Arguments
+///
+/// * `a` - The score to shift
+/// * `n` - The number of bits to shift by, from 0 to 31
+///
+/// # Returns
+///
+/// The shifted score, with its sign bit copied into the vacated bits
+///
+/// # Panics
+///
+/// Panics if `n` is greater than 31
+pub fn score_shr(a: i32, n: u32) -> i32 {
+    assert!(n <= 31, "shift amount {} is out of range", n);
+    if n == 31 {
+        score_div(score_div(a, 1 << 30), 2)
+    } else {
+        score_div(a, 1 << n)
+    }
+}
+
+/// [Internal use only]
+/// Combines two scores one bit at a time
+///
+/// # Arguments
+///
+/// * `a` - The first score
+/// * `b` - The second score
+/// * `combine` - Combines a bit of `a` with the matching bit of `b`
+///
+/// # Returns
+///
+/// The score whose bits are the combined bits of `a` and `b`
+fn bitwise(a: i32, b: i32, combine: fn(i32, i32) -> i32) -> i32 {
+    let (mut a, mut b) = (a, b);
+    let mut place = 1;
+    let mut result = 0;
+    for _ in 0..32 {
+        let bit = combine(score_mod(a, 2), score_mod(b, 2));
+        result = score_add(result, score_mul(bit, place));
+        a = score_div(a, 2);
+        b = score_div(b, 2);
+        place = score_mul(place, 2);
+    }
+    result
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the functions into scope
+    use super::*;
+
+    // tests addition and subtraction at the edges of the range
+    #[test]
+    fn test_add_sub() {
+        let cases = [
+            (1, 2, 3, -1),
+            (i32::MAX, 1, i32::MIN, i32::MAX - 1),
+            (i32::MIN, -1, i32::MAX, i32::MIN + 1),
+            (i32::MIN, i32::MIN, 0, 0),
+        ];
+        for &(a, b, sum, diff) in cases.iter() {
+            assert_eq!(score_add(a, b), sum, "{} + {}", a, b);
+            assert_eq!(score_sub(a, b), diff, "{} - {}", a, b);
+        }
+    }
+
+    // tests multiplication at the edges of the range
+    #[test]
+    fn test_mul() {
+        let cases = [
+            (6, -7, -42),
+            (i32::MAX, 2, -2),
+            (i32::MIN, -1, i32::MIN),
+            (65536, 65536, 0),
+            (-1, -1, 1),
+        ];
+        for &(a, b, prod) in cases.iter() {
+            assert_eq!(score_mul(a, b), prod, "{} * {}", a, b);
+        }
+    }
+
+    // tests division rounding and division by zero
+    #[test]
+    fn test_div() {
+        let cases = [
+            (7, 2, 3),
+            (-7, 2, -4),
+            (7, -2, -4),
+            (-7, -2, 3),
+            (-8, 2, -4),
+            (-1, 3, -1),
+            (0, -5, 0),
+            (i32::MIN, -1, i32::MIN),
+            (i32::MIN, 1, i32::MIN),
+            (i32::MAX, -1, -i32::MAX),
+            (i32::MIN, i32::MAX, -2),
+            (42, 0, 42),
+            (-42, 0, -42),
+            (0, 0, 0),
+        ];
+        for &(a, b, quo) in cases.iter() {
+            assert_eq!(score_div(a, b), quo, "{} / {}", a, b);
+        }
+    }
+
+    // tests remainders, which must agree with floored division
+    #[test]
+    fn test_mod() {
+        let cases = [
+            (7, 3, 1),
+            (-7, 3, 2),
+            (7, -3, -2),
+            (-7, -3, -1),
+            (6, -3, 0),
+            (i32::MIN, -1, 0),
+            (i32::MIN, i32::MAX, i32::MAX - 1),
+            (i32::MAX, i32::MIN, -1),
+            (42, 0, 42),
+        ];
+        for &(a, b, rem) in cases.iter() {
+            assert_eq!(score_mod(a, b), rem, "{} % {}", a, b);
+            if b != 0 {
+                let quo = score_div(a, b);
+                assert_eq!(quo.wrapping_mul(b).wrapping_add(rem), a, "{} / {}", a, b);
+            }
+        }
+    }
+
+    // tests that the bitwise lowerings agree with native two's complement
+    #[test]
+    fn test_bitwise() {
+        let vals = [0, 1, -1, 2, -2, 5, -6, 0x55AA, -0x1234_5678, i32::MAX, i32::MIN];
+        for &a in vals.iter() {
+            for &b in vals.iter() {
+                assert_eq!(score_and(a, b), a & b, "{} & {}", a, b);
+                assert_eq!(score_or(a, b), a | b, "{} | {}", a, b);
+                assert_eq!(score_xor(a, b), a ^ b, "{} ^ {}", a, b);
+            }
+            assert_eq!(score_not(a), !a, "!{}", a);
+            for n in 0..32 {
+                assert_eq!(score_shl(a, n), a << n, "{} << {}", a, n);
+                assert_eq!(score_shr(a, n), a >> n, "{} >> {}", a, n);
+            }
+        }
+    }
+
+    // tests the minimum and maximum
+    #[test]
+    fn test_min_max() {
+        assert_eq!(score_min(-3, 2), -3);
+        assert_eq!(score_max(-3, 2), 2);
+        assert_eq!(score_min(i32::MIN, i32::MAX), i32::MIN);
+        assert_eq!(score_max(i32::MIN, i32::MAX), i32::MAX);
+    }
+}
+
+// end of file
@@ -0,0 +1,901 @@
+/*
+ * cpu.rs
+ * Defines a reference emulator for the Minecraft CPU
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Flag;
+use super::super::cpu::Register;
+use super::super::cpu::Status;
+use super::super::data::Coordinate;
+use super::super::data::Instruction;
+use super::super::data::SHLData;
+use super::super::data::SHRData;
+use super::super::error::EmuError;
+use super::arith;
+use super::SimpleWorld;
+use super::World;
+
+/// The state of an emulated Minecraft CPU
+///
+/// Register values are signed 32-bit integers, like
+/// the scoreboard objectives that implement them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cpu {
+    /// The values of every register, including `SR` and `SWP`
+    regs: [i32; 18],
+
+    /// The values of every flag
+    flags: [bool; 7],
+
+    /// The CPU stack, with the top of the stack at the end
+    stack: Vec<i32>,
+
+    /// The return addresses of the active subroutine
+    /// calls, with the innermost call at the end
+    calls: Vec<usize>,
+
+    /// The index of the next instruction to execute
+    pc: usize,
+
+    /// Whether the CPU is running, halted, or finished
+    status: Status,
+}
+
+// implementation
+impl Cpu {
+    /// The maximum number of nested subroutine calls
+    pub const MAX_CALL_DEPTH: usize = 256;
+
+    /// Creates a new `Cpu` instance
+    ///
+    /// # Returns
+    ///
+    /// A new running `Cpu` with all registers zeroed, all flags
+    /// unset, empty data and call stacks, and the program counter at 0
+    pub fn new() -> Self {
+        Cpu {
+            regs: [0; 18],
+            flags: [false; 7],
+            stack: Vec::new(),
+            calls: Vec::new(),
+            pc: 0,
+            status: Status::Running,
+        }
+    }
+
+    /// Gets the value of a register
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The `Register` to read
+    ///
+    /// # Returns
+    ///
+    /// The value of the register
+    pub fn reg(&self, reg: Register) -> i32 {
+        self.regs[reg as usize]
+    }
+
+    /// Sets the value of a register
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The `Register` to write
+    /// * `val` - The new value of the register
+    pub fn set_reg(&mut self, reg: Register, val: i32) {
+        self.regs[reg as usize] = val;
+    }
+
+    /// Gets the value of a flag
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - The `Flag` to read
+    ///
+    /// # Returns
+    ///
+    /// Whether the flag is set
+    pub fn flag(&self, flag: Flag) -> bool {
+        self.flags[u8::from(flag) as usize]
+    }
+
+    /// Sets the value of a flag
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - The `Flag` to write
+    /// * `val` - Whether the flag is set
+    pub fn set_flag(&mut self, flag: Flag, val: bool) {
+        self.flags[u8::from(flag) as usize] = val;
+    }
+
+    /// Gets the contents of the stack
+    ///
+    /// # Returns
+    ///
+    /// The stack, from bottom to top
+    pub fn stack(&self) -> &[i32] {
+        &self.stack
+    }
+
+    /// Pushes a program's data onto the stack
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The values to push, from the bottom
+    ///   of the stack to the top, such as `Program::data`
+    pub fn load_data(&mut self, data: &[u32]) {
+        self.stack.extend(data.iter().map(|&val| val as i32));
+        self.sync_sr();
+    }
+
+    /// Gets the call stack
+    ///
+    /// The call stack is separate from the data stack, so
+    /// `PUSH`, `POP` and `SR` never see return addresses.
+    ///
+    /// # Returns
+    ///
+    /// The return address of every active subroutine call,
+    /// from the outermost call to the innermost
+    pub fn call_stack(&self) -> &[usize] {
+        &self.calls
+    }
+
+    /// Gets the program counter
+    ///
+    /// # Returns
+    ///
+    /// The index of the next instruction to execute
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Sets the program counter
+    ///
+    /// # Arguments
+    ///
+    /// * `pc` - The index of the next instruction to execute
+    pub fn set_pc(&mut self, pc: usize) {
+        self.pc = pc;
+    }
+
+    /// Gets the run state of the CPU
+    ///
+    /// # Returns
+    ///
+    /// Whether the CPU is running, halted, or finished
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Sets the run state of the CPU
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The new run state, such as `Status::Running`
+    ///   to resume a CPU after moving its program counter
+    pub fn set_status(&mut self, status: Status) {
+        self.status = status;
+    }
+
+    /// Runs a program in an empty world until the CPU
+    /// halts or falls off the end of the program
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The instructions of the program
+    /// * `max_steps` - The maximum number of instructions to execute
+    ///
+    /// # Returns
+    ///
+    /// The number of instructions executed, or an `EmuError` if
+    /// an instruction fails or the step limit is reached
+    pub fn run(&mut self, program: &[Instruction], max_steps: usize) -> Result<usize, EmuError> {
+        self.run_in_world(program, max_steps, &SimpleWorld::new())
+    }
+
+    /// Runs a program until the CPU halts or
+    /// falls off the end of the program
+    ///
+    /// Afterwards, `status` reports which of the two happened,
+    /// and an empty program finishes without executing anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The instructions of the program
+    /// * `max_steps` - The maximum number of instructions to execute
+    /// * `world` - The `World` that the program observes
+    ///
+    /// # Returns
+    ///
+    /// The number of instructions executed, or an `EmuError` if
+    /// an instruction fails or the step limit is reached
+    pub fn run_in_world(&mut self, program: &[Instruction], max_steps: usize, world: &dyn World) -> Result<usize, EmuError> {
+        let mut steps = 0;
+        if self.status == Status::Running && self.pc == program.len() {
+            self.status = Status::Finished;
+        }
+        while self.status == Status::Running {
+            if steps == max_steps {
+                return Err(EmuError::new(
+                    self.pc,
+                    &format!("step limit of {} reached", max_steps),
+                ));
+            }
+            self.step_in_world(program, world)?;
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    /// Executes the instruction at the program counter in an empty world
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The instructions of the program
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `EmuError` if the instruction fails
+    pub fn step(&mut self, program: &[Instruction]) -> Result<(), EmuError> {
+        self.step_in_world(program, &SimpleWorld::new())
+    }
+
+    /// Executes the instruction at the program counter
+    ///
+    /// If the instruction moves the program counter to
+    /// the end of the program, the CPU's status becomes
+    /// `Status::Finished`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The instructions of the program
+    /// * `world` - The `World` that the instruction observes
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `EmuError` if the instruction
+    /// fails or the CPU is not running
+    pub fn step_in_world(&mut self, program: &[Instruction], world: &dyn World) -> Result<(), EmuError> {
+        if self.status != Status::Running {
+            return Err(EmuError::new(
+                self.pc,
+                &format!("the CPU has stopped ({})", self.status),
+            ));
+        }
+        let instr = match program.get(self.pc) {
+            Some(i) => i,
+            None => {
+                return Err(EmuError::new(
+                    self.pc,
+                    "program counter is past the end of the program",
+                ));
+            }
+        };
+        let mut next = self.pc + 1;
+
+        match instr {
+            Instruction::Mov(d) => {
+                let val = match d.src_literal() {
+                    Some(lit) => lit as i32,
+                    None => self.read(d.unwrap_src_register())?,
+                };
+                self.write(d.dest(), val)?;
+            }
+            Instruction::Exg(d) => {
+                let a = self.read(d.arg0())?;
+                let b = self.read(d.arg1())?;
+                self.set_reg(Register::SWP, a);
+                self.write(d.arg0(), b)?;
+                self.write(d.arg1(), self.reg(Register::SWP))?;
+            }
+            Instruction::Add(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_add)?
+            }
+            Instruction::Sub(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_sub)?
+            }
+            Instruction::Mul(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_mul)?
+            }
+            Instruction::Div(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_div)?
+            }
+            Instruction::Mod(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_mod)?
+            }
+            Instruction::Min(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_min)?
+            }
+            Instruction::Max(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_max)?
+            }
+            Instruction::And(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_and)?
+            }
+            Instruction::Or(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_or)?
+            }
+            Instruction::Xor(d) => {
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.math(d.arg0(), b, d.dest(), arith::score_xor)?
+            }
+            Instruction::Not(d) => self.math(d.arg0(), 0, d.dest(), |a, _| arith::score_not(a))?,
+            Instruction::Shl(d) => {
+                let n = self.shift_amount(d.amount(), SHLData::MAX_AMOUNT)?;
+                self.math(d.arg0(), n, d.dest(), |a, n| arith::score_shl(a, n as u32))?
+            }
+            Instruction::Shr(d) => {
+                let n = self.shift_amount(d.amount(), SHRData::MAX_AMOUNT)?;
+                self.math(d.arg0(), n, d.dest(), |a, n| arith::score_shr(a, n as u32))?
+            }
+            Instruction::Inc(d) => {
+                let val = self.read(d.arg0())?;
+                self.write(d.arg0(), arith::score_add(val, 1))?;
+            }
+            Instruction::Dec(d) => {
+                let val = self.read(d.arg0())?;
+                self.write(d.arg0(), arith::score_sub(val, 1))?;
+            }
+            Instruction::Neg(d) => {
+                let val = self.read(d.arg0())?;
+                self.write(d.arg0(), arith::score_mul(val, -1))?;
+            }
+            Instruction::Push(d) => {
+                let val = self.read(d.arg0())?;
+                self.stack.push(val);
+                self.sync_sr();
+            }
+            Instruction::Pop(d) => {
+                let val = match self.stack.pop() {
+                    Some(v) => v,
+                    None => return Err(EmuError::new(self.pc, "stack underflow")),
+                };
+                self.write(d.arg0(), val)?;
+                self.sync_sr();
+            }
+            Instruction::Jmp(d) => next = self.target(program, d.jmp_label_id())?,
+            Instruction::Jcc(d) => {
+                let (flag, set) = d.condition().flag_test();
+                if self.flag(flag) == set {
+                    next = self.target(program, d.jmp_label_id())?;
+                }
+            }
+            Instruction::Call(d) => {
+                if self.calls.len() == Cpu::MAX_CALL_DEPTH {
+                    return Err(EmuError::new(
+                        self.pc,
+                        &format!("call stack overflow (more than {} nested calls)", Cpu::MAX_CALL_DEPTH),
+                    ));
+                }
+                next = self.target(program, d.jmp_label_id())?;
+                self.calls.push(self.pc + 1);
+            }
+            Instruction::Ret(_) => {
+                next = match self.calls.pop() {
+                    Some(addr) => addr,
+                    None => return Err(EmuError::new(self.pc, "call stack underflow")),
+                };
+            }
+            Instruction::Hlt(_) => {
+                // the program counter stays on the HLT
+                self.status = Status::Halted;
+                next = self.pc;
+            }
+            Instruction::Nop(_) => {}
+            Instruction::Chk(d) => {
+                let val = self.read(d.chk_reg())?;
+                self.set_flag(Flag::Zero, val == 0);
+                self.set_flag(Flag::Negative, val < 0);
+            }
+            Instruction::Cmp(d) => {
+                let a = self.read(d.arg0())?;
+                let b = self.operand(d.arg1_literal(), d.arg1_register())?;
+                self.set_flag(Flag::Equal, a == b);
+                self.set_flag(Flag::GreaterThan, a > b);
+                self.set_flag(Flag::LessThan, a < b);
+            }
+            Instruction::Js(d) => {
+                if self.flag(d.cond_flag()) {
+                    next = self.target(program, d.jmp_label_id())?;
+                }
+            }
+            Instruction::Jns(d) => {
+                if !self.flag(d.cond_flag()) {
+                    next = self.target(program, d.jmp_label_id())?;
+                }
+            }
+            Instruction::Blk(d) => {
+                let pos = self.position(world, d.coordinates())?;
+                let found = world.block(pos).is_some_and(|b| d.block().matches(b));
+                self.set_flag(Flag::Block, found);
+            }
+            Instruction::Ent(d) => {
+                let origin = world.origin();
+                let found = world.entities().iter().enumerate().any(|(i, e)| {
+                    e.is_selected_by(d.selector(), origin, world.executor() == Some(i))
+                });
+                self.set_flag(Flag::Entity, found);
+            }
+        }
+
+        self.pc = next;
+        if self.pc == program.len() {
+            self.status = Status::Finished;
+        }
+        Ok(())
+    }
+
+    /// [Internal use only]
+    /// Reads a register operand
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The `Register` to read
+    ///
+    /// # Returns
+    ///
+    /// The value of the register, or an `EmuError`
+    /// if it cannot be read from userspace code
+    fn read(&self, reg: Register) -> Result<i32, EmuError> {
+        self.check_userspace(reg)?;
+        Ok(self.reg(reg))
+    }
+
+    /// [Internal use only]
+    /// Writes a register operand
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The `Register` to write
+    /// * `val` - The new value of the register
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `EmuError` if the register
+    /// cannot be written from userspace code
+    fn write(&mut self, reg: Register, val: i32) -> Result<(), EmuError> {
+        self.check_userspace(reg)?;
+        self.set_reg(reg, val);
+        Ok(())
+    }
+
+    /// [Internal use only]
+    /// Reads an operand that is either a literal or a register
+    ///
+    /// Literals are reinterpreted as signed 32-bit integers.
+    ///
+    /// # Arguments
+    ///
+    /// * `lit` - The operand, if it is a literal
+    /// * `reg` - The operand, if it is a register
+    ///
+    /// # Returns
+    ///
+    /// The value of the operand, or an `EmuError` if its register is invalid
+    fn operand(&self, lit: Option<u32>, reg: Option<Register>) -> Result<i32, EmuError> {
+        match (lit, reg) {
+            (Some(lit), _) => Ok(lit as i32),
+            (None, Some(reg)) => self.read(reg),
+            (None, None) => unreachable!("operand has neither a literal nor a register"),
+        }
+    }
+
+    /// [Internal use only]
+    /// Validates the amount of a shift instruction
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The number of bits to shift by
+    /// * `max` - The largest valid shift amount
+    ///
+    /// # Returns
+    ///
+    /// The shift amount, or an `EmuError` if it is out of range
+    fn shift_amount(&self, amount: u32, max: u32) -> Result<i32, EmuError> {
+        if amount > max {
+            Err(EmuError::new(
+                self.pc,
+                &format!("shift amount {} is out of range (expected 0 to {})", amount, max),
+            ))
+        } else {
+            Ok(amount as i32)
+        }
+    }
+
+    /// [Internal use only]
+    /// Ensures that a register may be used as an operand
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The `Register` being used
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `EmuError` if the register is `SR` or `SWP`
+    fn check_userspace(&self, reg: Register) -> Result<(), EmuError> {
+        match reg {
+            Register::SR | Register::SWP => Err(EmuError::new(
+                self.pc,
+                &format!("{} cannot be accessed from userspace code", reg),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// [Internal use only]
+    /// Executes a math instruction through the swap register
+    ///
+    /// The first argument is copied into `SWP`, the
+    /// operation is applied to `SWP`, and the result is
+    /// copied into the destination, mirroring the
+    /// scoreboard commands the instruction lowers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `arg0` - The first argument `Register`
+    /// * `b` - The value of the second argument
+    /// * `dest` - The destination `Register`
+    /// * `op` - The scoreboard operation from the `arith` module
+    ///
+    /// # Returns
+    ///
+    /// Nothing, or an `EmuError` if a register is invalid
+    fn math(&mut self, arg0: Register, b: i32, dest: Register, op: fn(i32, i32) -> i32) -> Result<(), EmuError> {
+        let a = self.read(arg0)?;
+        self.set_reg(Register::SWP, a);
+        self.set_reg(Register::SWP, op(self.reg(Register::SWP), b));
+        self.write(dest, self.reg(Register::SWP))
+    }
+
+    /// [Internal use only]
+    /// Resolves coordinates into an absolute position
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - The `World` whose origin relative coordinates are measured from
+    /// * `pos` - The x, y, and z coordinates to resolve
+    ///
+    /// # Returns
+    ///
+    /// The absolute position, or an `EmuError` if a coordinate register is invalid
+    fn position(&self, world: &dyn World, pos: [Coordinate; 3]) -> Result<[i32; 3], EmuError> {
+        let origin = world.origin();
+        let mut abs = [0; 3];
+        for axis in 0..3 {
+            abs[axis] = match pos[axis] {
+                Coordinate::Absolute(v) => v,
+                Coordinate::Relative(v) | Coordinate::Local(v) => origin[axis].wrapping_add(v),
+                Coordinate::Register(r) => self.read(r)?,
+            };
+        }
+        Ok(abs)
+    }
+
+    /// [Internal use only]
+    /// Updates the stack register to mirror the top of the stack
+    fn sync_sr(&mut self) {
+        let top = self.stack.last().copied().unwrap_or(0);
+        self.set_reg(Register::SR, top);
+    }
+
+    /// [Internal use only]
+    /// Gets the program counter for a jump to a label
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The instructions of the program
+    /// * `id` - The ID of the label being jumped to
+    ///
+    /// # Returns
+    ///
+    /// The new program counter, or an `EmuError` if the label is out of range
+    fn target(&self, program: &[Instruction], id: u32) -> Result<usize, EmuError> {
+        let id = id as usize;
+        if id > program.len() {
+            Err(EmuError::new(
+                self.pc,
+                &format!("label ID {} is out of range", id),
+            ))
+        } else {
+            Ok(id)
+        }
+    }
+}
+
+// Default implementation
+impl Default for Cpu {
+    fn default() -> Self {
+        Cpu::new()
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the struct into scope
+    use super::*;
+    use super::super::super::asm::assemble;
+    use super::super::super::data::BlockState;
+    use super::super::Entity;
+
+    // assembles and runs a program
+    fn run(src: &str) -> Cpu {
+        let prog = assemble(src).unwrap();
+        let mut cpu = Cpu::new();
+        cpu.run(prog.instructions(), 10_000).unwrap();
+        cpu
+    }
+
+    // tests moves and arithmetic
+    #[test]
+    fn test_arithmetic() {
+        let cpu = run("mov 7, r0\nmov -3, r1\nadd r0, r1, r2\nsub r0, r1, r3\nmul r0, r1, r4\ndiv r0, r1, r5\nmov r5, r6\n");
+        assert_eq!(cpu.reg(Register::R2), 4);
+        assert_eq!(cpu.reg(Register::R3), 10);
+        assert_eq!(cpu.reg(Register::R4), -21);
+        assert_eq!(cpu.reg(Register::R5), -3);
+        assert_eq!(cpu.reg(Register::R6), -3);
+        assert_eq!(cpu.reg(Register::SWP), -3);
+    }
+
+    // tests swapping registers
+    #[test]
+    fn test_exchange() {
+        let cpu = run("mov 3, r0\nmov -8, r1\nexg r0, r1\nexg r2, r2\n");
+        assert_eq!(cpu.reg(Register::R0), -8);
+        assert_eq!(cpu.reg(Register::R1), 3);
+        assert_eq!(cpu.reg(Register::R2), 0);
+        assert_eq!(cpu.reg(Register::SWP), 0);
+    }
+
+    // tests that division follows scoreboard semantics
+    #[test]
+    fn test_division() {
+        let cpu = run("mov -7, r0\nmov 2, r1\nmov 0, r2\ndiv r0, r1, r3\ndiv r0, r2, r4\nmov 2147483647, r5\nadd r5, r1, r6\n");
+        assert_eq!(cpu.reg(Register::R3), -4);
+        assert_eq!(cpu.reg(Register::R4), -7);
+        assert_eq!(cpu.reg(Register::R6), i32::MIN + 1);
+    }
+
+    // tests literal second arguments
+    #[test]
+    fn test_literal_operands() {
+        let cpu = run("mov 10, r0\nadd r0, 5, r1\nsub r0, -2, r2\nmul r0, 0xFFFFFFFF, r3\ndiv r0, 4, r4\ncmp r0, 10\n");
+        assert_eq!(cpu.reg(Register::R1), 15);
+        assert_eq!(cpu.reg(Register::R2), 12);
+        assert_eq!(cpu.reg(Register::R3), -10);
+        assert_eq!(cpu.reg(Register::R4), 2);
+        assert!(cpu.flag(Flag::Equal));
+    }
+
+    // tests remainders, minimums, and maximums
+    #[test]
+    fn test_mod_min_max() {
+        let cpu = run("mov -7, r0\nmov 3, r1\nmod r0, r1, r2\nmod r0, 0, r3\nmin r0, r1, r4\nmax r0, 5, r5\n");
+        assert_eq!(cpu.reg(Register::R2), 2);
+        assert_eq!(cpu.reg(Register::R3), -7);
+        assert_eq!(cpu.reg(Register::R4), -7);
+        assert_eq!(cpu.reg(Register::R5), 5);
+    }
+
+    // tests bitwise instructions on negative numbers
+    #[test]
+    fn test_bitwise() {
+        let cpu = run("mov -6, r0\nmov 0x0F0F, r1\nand r0, r1, r2\nor r0, 1, r3\nxor r0, -1, r4\nnot r0, r5\nshl r0, 30, r6\nshr r0, 1, r7\nshr r0, 31, r8\n");
+        assert_eq!(cpu.reg(Register::R2), -6 & 0x0F0F);
+        assert_eq!(cpu.reg(Register::R3), -5);
+        assert_eq!(cpu.reg(Register::R4), 5);
+        assert_eq!(cpu.reg(Register::R5), 5);
+        assert_eq!(cpu.reg(Register::R6), i32::MIN);
+        assert_eq!(cpu.reg(Register::R7), -3);
+        assert_eq!(cpu.reg(Register::R8), -1);
+        assert_eq!(cpu.reg(Register::SWP), -1);
+    }
+
+    // tests counting and negation, which wrap like scores and leave SWP alone
+    #[test]
+    fn test_inc_dec_neg() {
+        let src = "mov 3, r0\nloop: dec r0\ninc r1\nchk r0\njns flag_Zero, loop\n\
+                   mov 2147483647, r2\ninc r2\nmov -2147483648, r3\ndec r3\nneg r1\nmov -2147483648, r4\nneg r4\n";
+        let cpu = run(src);
+        assert_eq!(cpu.reg(Register::R0), 0);
+        assert_eq!(cpu.reg(Register::R1), -3);
+        assert_eq!(cpu.reg(Register::R2), i32::MIN);
+        assert_eq!(cpu.reg(Register::R3), i32::MAX);
+        assert_eq!(cpu.reg(Register::R4), i32::MIN);
+        assert_eq!(cpu.reg(Register::SWP), 0);
+    }
+
+    // tests the stack and the stack register
+    #[test]
+    fn test_stack() {
+        let cpu = run("mov 1, r0\nmov 2, r1\npush r0\npush r1\npop r2\n");
+        assert_eq!(cpu.reg(Register::R2), 2);
+        assert_eq!(cpu.stack(), &[1]);
+        assert_eq!(cpu.reg(Register::SR), 1);
+    }
+
+    // tests starting with a program's data on the stack
+    #[test]
+    fn test_data() {
+        let prog = assemble(".const BASE, 0x10\n.data BASE, BASE * 2, -1\npop r0\npop r1\n").unwrap();
+        let mut cpu = Cpu::new();
+        cpu.load_data(prog.data());
+        assert_eq!(cpu.stack(), &[16, 32, -1]);
+        assert_eq!(cpu.reg(Register::SR), -1);
+        cpu.run(prog.instructions(), 10).unwrap();
+        assert_eq!(cpu.reg(Register::R0), -1);
+        assert_eq!(cpu.reg(Register::R1), 32);
+        assert_eq!(cpu.stack(), &[16]);
+    }
+
+    // tests that subroutines return past their call, leaving the data stack alone
+    #[test]
+    fn test_call_ret() {
+        let src = "mov 3, r0\npush r0\ncall double\ncall double\njmp end\n\
+                   double: add r0, r0, r0\ncall inc\nret\n\
+                   inc: add r0, 1, r0\nret\nend:\n";
+        let cpu = run(src);
+        assert_eq!(cpu.reg(Register::R0), 15);
+        assert_eq!(cpu.stack(), &[3]);
+        assert_eq!(cpu.reg(Register::SR), 3);
+        assert!(cpu.call_stack().is_empty());
+    }
+
+    // tests halting, and falling off the end of the program
+    #[test]
+    fn test_termination() {
+        let prog = assemble("mov 1, r0\nnop\nhlt\nmov 2, r0\n").unwrap();
+        let mut cpu = Cpu::new();
+        assert_eq!(cpu.run(prog.instructions(), 100).unwrap(), 3);
+        assert_eq!(cpu.status(), Status::Halted);
+        assert_eq!(cpu.pc(), 2);
+        assert_eq!(cpu.reg(Register::R0), 1);
+        let err = cpu.step(prog.instructions()).unwrap_err();
+        assert_eq!(err.reason(), "the CPU has stopped (halted)");
+
+        // resuming past the HLT runs to the end
+        cpu.set_pc(3);
+        cpu.set_status(Status::Running);
+        cpu.step(prog.instructions()).unwrap();
+        assert_eq!(cpu.status(), Status::Finished);
+        assert_eq!(cpu.reg(Register::R0), 2);
+
+        // jumping to the end label also finishes, as does an empty program
+        let cpu = run("jmp end\nhlt\nend:\n");
+        assert_eq!(cpu.status(), Status::Finished);
+        assert_eq!(cpu.pc(), 2);
+        let mut cpu = Cpu::new();
+        assert_eq!(cpu.run(&[], 100).unwrap(), 0);
+        assert_eq!(cpu.status(), Status::Finished);
+    }
+
+    // tests that every condition-code jump agrees with the comparison it follows
+    #[test]
+    fn test_jcc() {
+        let conds = ["eq", "ne", "gt", "ge", "lt", "le"];
+        for &(a, b) in [(1, 2), (2, 2), (3, 2), (-5, i32::MAX)].iter() {
+            for cond in conds.iter() {
+                let src = format!("mov {}, r0\ncmp r0, {}\nj{} yes\nmov 0, r1\njmp end\nyes: mov 1, r1\nend:\n", a, b, cond);
+                let taken = run(&src).reg(Register::R1) == 1;
+                let expected = match *cond {
+                    "eq" => a == b,
+                    "ne" => a != b,
+                    "gt" => a > b,
+                    "ge" => a >= b,
+                    "lt" => a < b,
+                    _ => a <= b,
+                };
+                assert_eq!(taken, expected, "{} j{} {}", a, cond, b);
+            }
+        }
+
+        let cpu = run("mov 0, r0\nchk r0\njnz end\nmov 7, r1\njz end\nmov 8, r1\nend:\n");
+        assert_eq!(cpu.reg(Register::R1), 7);
+    }
+
+    // tests CHK and CMP
+    #[test]
+    fn test_flags() {
+        let cpu = run("mov -1, r0\nmov 4, r1\nchk r0\ncmp r0, r1\n");
+        assert!(!cpu.flag(Flag::Zero));
+        assert!(cpu.flag(Flag::Negative));
+        assert!(!cpu.flag(Flag::Equal));
+        assert!(!cpu.flag(Flag::GreaterThan));
+        assert!(cpu.flag(Flag::LessThan));
+    }
+
+    // tests a counting loop
+    #[test]
+    fn test_loop() {
+        let src = "mov 5, r0\nmov 1, r1\nmov 0, r2\nloop: chk r0\njs flag_Zero, done\nadd r2, r0, r2\nsub r0, r1, r0\njmp loop\ndone:\n";
+        let cpu = run(src);
+        assert_eq!(cpu.reg(Register::R2), 15);
+        assert_eq!(cpu.pc(), 8);
+    }
+
+    // tests checking blocks in a world
+    #[test]
+    fn test_blk() {
+        let mut world = SimpleWorld::new();
+        world.set_origin([10, 64, 10]);
+        world.set_block(
+            [10, 63, 12],
+            BlockState::new("oak_stairs")
+                .with_property("facing", "north")
+                .with_property("half", "top"),
+        );
+        world.set_block([-5, 0, 7], BlockState::new("stone"));
+
+        let src = "blk ~, ~-1, ~2, oak_stairs[facing=north]\njns flag_Block, fail\nmov -5, r0\nblk r0, 0, 7, minecraft:stone\njns flag_Block, fail\nblk ^, ^-1, ^2, stone\njs flag_Block, fail\nmov 1, r1\nfail:\n";
+        let prog = assemble(src).unwrap();
+        let mut cpu = Cpu::new();
+        cpu.run_in_world(prog.instructions(), 100, &world).unwrap();
+        assert_eq!(cpu.reg(Register::R1), 1);
+        assert!(!cpu.flag(Flag::Block));
+    }
+
+    // tests checking for entities in a world
+    #[test]
+    fn test_ent() {
+        let mut world = SimpleWorld::new();
+        world.add_entity(Entity::new("zombie", [0, 0, 6]));
+        let me = world.add_entity(Entity::new("player", [0, 0, 0]).with_tag("me"));
+        world.set_executor(Some(me));
+
+        let src = "ent @e[type=zombie,distance=..5]\njs flag_Entity, fail\nent @s[tag=me]\njns flag_Entity, fail\nmov 1, r0\nfail:\n";
+        let prog = assemble(src).unwrap();
+        let mut cpu = Cpu::new();
+        cpu.run_in_world(prog.instructions(), 100, &world).unwrap();
+        assert_eq!(cpu.reg(Register::R0), 1);
+        assert!(cpu.flag(Flag::Entity));
+    }
+
+    // tests runtime errors
+    #[test]
+    fn test_errors() {
+        let prog = assemble("mov 1, r0\npop r1").unwrap();
+        let err = Cpu::new().run(prog.instructions(), 100).unwrap_err();
+        assert_eq!(err.pc(), 1);
+        assert_eq!(err.reason(), "stack underflow");
+
+        let prog = assemble("loop: jmp loop").unwrap();
+        let err = Cpu::new().run(prog.instructions(), 100).unwrap_err();
+        assert_eq!(err.reason(), "step limit of 100 reached");
+
+        let prog = assemble("jmp L9").unwrap();
+        let err = Cpu::new().run(prog.instructions(), 100).unwrap_err();
+        assert_eq!(err.reason(), "label ID 9 is out of range");
+
+        let prog = assemble("mov 1, r0\nret").unwrap();
+        let err = Cpu::new().run(prog.instructions(), 100).unwrap_err();
+        assert_eq!(err.pc(), 1);
+        assert_eq!(err.reason(), "call stack underflow");
+
+        let prog = assemble("deep: call deep").unwrap();
+        let mut cpu = Cpu::new();
+        let err = cpu.run(prog.instructions(), 1000).unwrap_err();
+        assert_eq!(err.reason(), "call stack overflow (more than 256 nested calls)");
+        assert_eq!(cpu.call_stack().len(), Cpu::MAX_CALL_DEPTH);
+    }
+}
+
+// end of file
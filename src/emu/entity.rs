@@ -0,0 +1,166 @@
+/*
+ * entity.rs
+ * Defines an entity in an emulated world
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::data::Selector;
+use super::super::data::SelectorArg;
+use super::super::data::SelectorKind;
+
+/// An entity in a `World`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Entity {
+    /// The namespaced type of the entity
+    id: String,
+
+    /// The tags of the entity
+    tags: Vec<String>,
+
+    /// The block position of the entity
+    pos: [i32; 3],
+}
+
+// implementation
+impl Entity {
+    /// Creates a new `Entity` instance with no tags
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The type of the entity, which gets the
+    ///   `minecraft` namespace if it does not have one
+    /// * `pos` - The x, y, and z coordinates of the entity
+    ///
+    /// # Returns
+    ///
+    /// A new `Entity` instance with the given data
+    pub fn new(id: &str, pos: [i32; 3]) -> Self {
+        let id = if id.contains(':') {
+            id.to_owned()
+        } else {
+            format!("minecraft:{}", id)
+        };
+        Entity {
+            id,
+            tags: Vec::new(),
+            pos,
+        }
+    }
+
+    /// Adds a tag
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to add
+    ///
+    /// # Returns
+    ///
+    /// The `Entity` with the tag added
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_owned());
+        self
+    }
+
+    /// Gets the type of the entity
+    ///
+    /// # Returns
+    ///
+    /// The namespaced type of the entity
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Gets the tags of the entity
+    ///
+    /// # Returns
+    ///
+    /// The tags of the entity
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Gets the position of the entity
+    ///
+    /// # Returns
+    ///
+    /// The x, y, and z coordinates of the entity
+    pub fn pos(&self) -> [i32; 3] {
+        self.pos
+    }
+
+    /// Determines whether a selector would select the entity
+    ///
+    /// The `limit` and `sort` arguments only choose among
+    /// the entities that match, so they are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - The `Selector` to test
+    /// * `origin` - The position that distances are measured from
+    /// * `is_executor` - Whether the entity is the executing entity
+    ///
+    /// # Returns
+    ///
+    /// Whether the selector matches the entity
+    pub fn is_selected_by(&self, selector: &Selector, origin: [i32; 3], is_executor: bool) -> bool {
+        let kind_matches = match selector.kind() {
+            SelectorKind::AllEntities => true,
+            SelectorKind::Executor => is_executor,
+            _ => self.id == "minecraft:player",
+        };
+
+        // distances are compared squared to stay in integers
+        let dist_sq: i64 = (0..3)
+            .map(|i| (self.pos[i] as i64 - origin[i] as i64).pow(2))
+            .sum();
+
+        kind_matches
+            && selector.args().iter().all(|arg| match arg {
+                SelectorArg::Type { id, negated } => (&self.id == id) != *negated,
+                SelectorArg::Tag { name, negated } => self.tags.contains(name) != *negated,
+                SelectorArg::Distance { min, max } => {
+                    min.is_none_or(|m| dist_sq >= (m as i64).pow(2))
+                        && max.is_none_or(|m| dist_sq <= (m as i64).pow(2))
+                }
+                SelectorArg::Limit(_) | SelectorArg::Sort(_) => true,
+            })
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the struct into scope
+    use super::*;
+
+    // tests selector matching
+    #[test]
+    fn test_is_selected_by() {
+        let zombie = Entity::new("zombie", [3, 0, 4]).with_tag("boss");
+        let player = Entity::new("player", [0, 0, 0]);
+        let sel = |s: &str| s.parse::<Selector>().unwrap();
+        let origin = [0, 0, 0];
+
+        assert!(zombie.is_selected_by(&sel("@e[type=zombie,tag=boss]"), origin, false));
+        assert!(!zombie.is_selected_by(&sel("@e[tag=!boss]"), origin, false));
+        assert!(!zombie.is_selected_by(&sel("@a"), origin, false));
+        assert!(player.is_selected_by(&sel("@p[limit=1,sort=furthest]"), origin, false));
+        assert!(!player.is_selected_by(&sel("@s"), origin, false));
+        assert!(player.is_selected_by(&sel("@s"), origin, true));
+
+        // the zombie is exactly 5 blocks away
+        assert!(zombie.is_selected_by(&sel("@e[distance=5]"), origin, false));
+        assert!(zombie.is_selected_by(&sel("@e[distance=..5]"), origin, false));
+        assert!(!zombie.is_selected_by(&sel("@e[distance=..4]"), origin, false));
+        assert!(!zombie.is_selected_by(&sel("@e[distance=6..]"), [0, 0, 0], false));
+        assert!(zombie.is_selected_by(&sel("@e[distance=..1]"), [3, 1, 4], false));
+    }
+}
+
+// end of file
@@ -0,0 +1,22 @@
+/*
+ * mod.rs
+ * Module header for mcisa's emu module
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// module exports
+pub mod arith;
+mod cpu;
+pub use cpu::Cpu;
+mod entity;
+pub use entity::Entity;
+mod world;
+pub use world::SimpleWorld;
+pub use world::World;
+
+// end of file
@@ -0,0 +1,156 @@
+/*
+ * world.rs
+ * Defines the world that an emulated CPU can observe
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::data::BlockState;
+use super::Entity;
+use std::collections::HashMap;
+
+/// The parts of a Minecraft world that
+/// instructions such as `BLK` and `ENT` can query
+///
+/// The CPU executes at the world's origin, facing
+/// south with no pitch, so local coordinates
+/// `^left ^up ^forward` are offsets along
+/// the +x, +y, and +z axes respectively.
+pub trait World {
+    /// Gets the position that the CPU executes at
+    ///
+    /// # Returns
+    ///
+    /// The x, y, and z coordinates of the executing position
+    fn origin(&self) -> [i32; 3];
+
+    /// Gets the block at a position
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - The x, y, and z coordinates of the block
+    ///
+    /// # Returns
+    ///
+    /// The block at the position, or `None` if it is air
+    fn block(&self, pos: [i32; 3]) -> Option<&BlockState>;
+
+    /// Gets every entity in the world
+    ///
+    /// # Returns
+    ///
+    /// The entities in the world, which are none by default
+    fn entities(&self) -> &[Entity] {
+        &[]
+    }
+
+    /// Gets the entity that the CPU executes as
+    ///
+    /// # Returns
+    ///
+    /// The index of the executing entity within `entities`, or
+    /// `None` if the CPU is not executing as an entity (the default)
+    fn executor(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// A `World` that holds its blocks in memory
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimpleWorld {
+    /// The position that the CPU executes at
+    origin: [i32; 3],
+
+    /// The blocks of the world, keyed by position
+    blocks: HashMap<[i32; 3], BlockState>,
+
+    /// The entities of the world
+    entities: Vec<Entity>,
+
+    /// The index of the executing entity
+    executor: Option<usize>,
+}
+
+// implementation
+impl SimpleWorld {
+    /// Creates a new `SimpleWorld` instance
+    ///
+    /// # Returns
+    ///
+    /// A new `SimpleWorld` filled with air, with its origin at 0, 0, 0
+    pub fn new() -> Self {
+        SimpleWorld::default()
+    }
+
+    /// Sets the position that the CPU executes at
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The x, y, and z coordinates of the executing position
+    pub fn set_origin(&mut self, origin: [i32; 3]) {
+        self.origin = origin;
+    }
+
+    /// Places a block, replacing any block at the same position
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - The x, y, and z coordinates of the block
+    /// * `block` - The block to place
+    pub fn set_block(&mut self, pos: [i32; 3], block: BlockState) {
+        self.blocks.insert(pos, block);
+    }
+}
+
+// entity implementation
+impl SimpleWorld {
+    /// Adds an entity
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to add
+    ///
+    /// # Returns
+    ///
+    /// The index of the entity
+    pub fn add_entity(&mut self, entity: Entity) -> usize {
+        self.entities.push(entity);
+        self.entities.len() - 1
+    }
+
+    /// Sets the entity that the CPU executes as
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` - The index of the executing entity, as
+    ///   returned by `add_entity`, or `None` for no entity
+    pub fn set_executor(&mut self, executor: Option<usize>) {
+        self.executor = executor;
+    }
+}
+
+// World implementation
+impl World for SimpleWorld {
+    fn origin(&self) -> [i32; 3] {
+        self.origin
+    }
+
+    fn block(&self, pos: [i32; 3]) -> Option<&BlockState> {
+        self.blocks.get(&pos)
+    }
+
+    fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    fn executor(&self) -> Option<usize> {
+        self.executor
+    }
+}
+
+// end of file
@@ -0,0 +1,157 @@
+/*
+ * asm_error.rs
+ * Defines an error generated when assembling source text fails
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::asm::Span;
+use std::fmt;
+
+/// An error resulting from a failure to assemble source text
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsmError {
+    /// The location of the offending token
+    span: Span,
+
+    /// A description of the error
+    msg: String,
+
+    /// The path of the source file holding the offending
+    /// token, or `None` if the source had no file name
+    file: Option<String>,
+
+    /// Notes that explain where the error came from,
+    /// such as the macro expansions that produced it
+    notes: Vec<String>,
+}
+
+// implementation
+impl AsmError {
+    /// Creates a new `AsmError` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The location of the offending token
+    /// * `msg` - A description of the error
+    ///
+    /// # Returns
+    ///
+    /// A new `AsmError` instance
+    pub fn new(span: Span, msg: &str) -> Self {
+        AsmError {
+            span,
+            msg: msg.to_owned(),
+            file: None,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Sets the source file that the error occurred in
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path of the file holding the offending token
+    ///
+    /// # Returns
+    ///
+    /// The error with the given file
+    pub fn with_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+
+    /// Adds a note to the error
+    ///
+    /// # Arguments
+    ///
+    /// * `note` - A note explaining where the error came from
+    ///
+    /// # Returns
+    ///
+    /// The error with the note added after any others
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.notes.push(note.to_owned());
+        self
+    }
+
+    /// Gets the location of the offending token
+    ///
+    /// # Returns
+    ///
+    /// The `Span` of the token that triggered the error
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Gets the description of the error
+    ///
+    /// # Returns
+    ///
+    /// The description of the error
+    pub fn message(&self) -> &str {
+        &self.msg
+    }
+
+    /// Gets the source file that the error occurred in
+    ///
+    /// # Returns
+    ///
+    /// The path of the file holding the offending token,
+    /// or `None` if the source had no file name
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// Gets the notes attached to the error
+    ///
+    /// # Returns
+    ///
+    /// The notes, from the innermost cause outward
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+}
+
+// Display implementation
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}: {}", self.span, self.msg)?;
+        for note in &self.notes {
+            write!(f, "\n  {}", note)?;
+        }
+        Ok(())
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring struct into scope
+    use super::*;
+
+    // tests formatting
+    #[test]
+    fn test_display_formatting() {
+        let err = AsmError::new(Span::new(3, 5, 2), "unknown mnemonic `mvo`");
+        assert_eq!(format!("{}", err), "3:5: unknown mnemonic `mvo`");
+        let err = err.with_note("in macro `m` (defined at 1:8), called at 4:1");
+        assert_eq!(
+            format!("{}", err),
+            "3:5: unknown mnemonic `mvo`\n  in macro `m` (defined at 1:8), called at 4:1"
+        );
+        let err = AsmError::new(Span::new(1, 2, 3), "undefined label `x`").with_file("src/lib.asm");
+        assert_eq!(err.file(), Some("src/lib.asm"));
+        assert_eq!(format!("{}", err), "src/lib.asm:1:2: undefined label `x`");
+    }
+}
+
+// end of file
@@ -0,0 +1,115 @@
+/*
+ * codegen_error.rs
+ * Defines an error generated when datapack generation fails
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::fmt;
+
+/// An error resulting from a failure to generate a datapack
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodegenError {
+    /// The index of the instruction that failed to lower, if any
+    pc: Option<usize>,
+
+    /// The reason for the failure
+    err_reason: String,
+}
+
+// implementation
+impl CodegenError {
+    /// Creates a new `CodegenError` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - The reason that the error was triggered
+    ///
+    /// # Returns
+    ///
+    /// A new `CodegenError` instance
+    pub fn new(reason: &str) -> Self {
+        CodegenError {
+            pc: None,
+            err_reason: reason.to_owned(),
+        }
+    }
+
+    /// Creates a new `CodegenError` instance for an instruction
+    ///
+    /// # Arguments
+    ///
+    /// * `pc` - The index of the instruction that failed to lower
+    /// * `reason` - The reason that the error was triggered
+    ///
+    /// # Returns
+    ///
+    /// A new `CodegenError` instance
+    pub fn at(pc: usize, reason: &str) -> Self {
+        CodegenError {
+            pc: Some(pc),
+            err_reason: reason.to_owned(),
+        }
+    }
+
+    /// Gets the index of the instruction that failed to lower
+    ///
+    /// # Returns
+    ///
+    /// The index of the instruction, wrapped in an `Option`
+    pub fn pc(&self) -> Option<usize> {
+        self.pc
+    }
+
+    /// Gets the reason for the error
+    ///
+    /// # Returns
+    ///
+    /// The reason for the error
+    pub fn reason(&self) -> &str {
+        &self.err_reason
+    }
+}
+
+// Display implementation
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.pc {
+            Some(pc) => write!(
+                f,
+                "Couldn't lower instruction {}! Reason: {}",
+                pc, self.err_reason
+            ),
+            None => write!(f, "Couldn't generate datapack! Reason: {}", self.err_reason),
+        }
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring struct into scope
+    use super::*;
+
+    // tests formatting
+    #[test]
+    fn test_display_formatting() {
+        let err1 = CodegenError::new("Example reason");
+        let err2 = CodegenError::at(3, "Example reason");
+        assert_eq!(
+            format!("{}", err1),
+            "Couldn't generate datapack! Reason: Example reason"
+        );
+        assert_eq!(
+            format!("{}", err2),
+            "Couldn't lower instruction 3! Reason: Example reason"
+        );
+    }
+}
+
+// end of file
@@ -111,7 +111,7 @@ mod tests {
     fn test_has_reason() {
         let err1 = DecodeError::new(0x00000000.into());
         let err2 = DecodeError::with_reason(0x00000000.into(), "Example reason");
-        assert_eq!(err1.has_reason(), false);
+        assert!(!err1.has_reason());
         assert!(err2.has_reason());
     }
 
@@ -0,0 +1,91 @@
+/*
+ * emu_error.rs
+ * Defines an error generated when emulating an instruction fails
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::fmt;
+
+/// An error resulting from a failure to emulate an instruction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmuError {
+    /// The program counter of the failing instruction
+    pc: usize,
+
+    /// The reason for the failure
+    err_reason: String,
+}
+
+// implementation
+impl EmuError {
+    /// Creates a new `EmuError` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `pc` - The program counter of the failing instruction
+    /// * `reason` - The reason that the error was triggered
+    ///
+    /// # Returns
+    ///
+    /// A new `EmuError` instance
+    pub fn new(pc: usize, reason: &str) -> Self {
+        EmuError {
+            pc,
+            err_reason: reason.to_owned(),
+        }
+    }
+
+    /// Gets the program counter of the failing instruction
+    ///
+    /// # Returns
+    ///
+    /// The index of the instruction that triggered the error
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Gets the reason for the error
+    ///
+    /// # Returns
+    ///
+    /// The reason for the error
+    pub fn reason(&self) -> &str {
+        &self.err_reason
+    }
+}
+
+// Display implementation
+impl fmt::Display for EmuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Couldn't execute instruction {}! Reason: {}",
+            self.pc, self.err_reason
+        )
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring struct into scope
+    use super::*;
+
+    // tests formatting
+    #[test]
+    fn test_display_formatting() {
+        let err = EmuError::new(4, "Example reason");
+        assert_eq!(
+            format!("{}", err),
+            "Couldn't execute instruction 4! Reason: Example reason"
+        );
+    }
+}
+
+// end of file
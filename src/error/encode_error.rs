@@ -0,0 +1,135 @@
+/*
+ * encode_error.rs
+ * Defines an error generated when instruction encoding fails
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::data::Instruction;
+use std::fmt;
+
+/// An error resulting from instruction-to-opcode translation failure
+#[derive(Clone, Debug)]
+pub struct EncodeError {
+    /// The `Instruction` that failed to encode
+    instr: Instruction,
+
+    /// The reason for the failure
+    err_reason: Option<String>,
+}
+
+// implementation
+impl EncodeError {
+    /// Creates a new `EncodeError` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `instr` - The `Instruction` that triggered the error
+    ///
+    /// # Returns
+    ///
+    /// A new `EncodeError` instance
+    pub fn new(instr: Instruction) -> Self {
+        EncodeError {
+            instr,
+            err_reason: None,
+        }
+    }
+
+    /// Creates a new `EncodeError` instance with a reason
+    ///
+    /// # Arguments
+    ///
+    /// * `instr` - The `Instruction` that triggered the error
+    /// * `reason` - The reason that the error was triggered
+    ///
+    /// # Returns
+    ///
+    /// A new `EncodeError` instance
+    pub fn with_reason(instr: Instruction, reason: &str) -> Self {
+        EncodeError {
+            instr,
+            err_reason: Some(reason.to_owned()),
+        }
+    }
+
+    /// Gets the `Instruction` that triggered the error
+    ///
+    /// # Returns
+    ///
+    /// The instruction that triggered the error
+    pub fn instruction(&self) -> &Instruction {
+        &self.instr
+    }
+
+    /// Gets the reason for the error
+    ///
+    /// # Returns
+    ///
+    /// The reason for the error, wrapped in an `Option`
+    pub fn reason(&self) -> Option<String> {
+        self.err_reason.clone()
+    }
+
+    /// Determines whether the error has a known reason
+    ///
+    /// # Returns
+    ///
+    /// Whether there is a reason behind the error
+    pub fn has_reason(&self) -> bool {
+        self.err_reason.is_some()
+    }
+}
+
+// Display implementation
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.err_reason.clone() {
+            Some(r) => {
+                write!(f, "Couldn't encode instruction `{}`! Reason: {}", self.instr, r)
+            }
+            None => {
+                write!(f, "Couldn't encode instruction `{}`!", self.instr)
+            }
+        }
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring struct into scope
+    use super::*;
+    use super::super::super::cpu::Register;
+    use super::super::super::data::PUSHData;
+
+    // tests the has_reason method
+    #[test]
+    fn test_has_reason() {
+        let instr = Instruction::Push(PUSHData::new(Register::SR));
+        let err1 = EncodeError::new(instr.clone());
+        let err2 = EncodeError::with_reason(instr, "Example reason");
+        assert!(!err1.has_reason());
+        assert!(err2.has_reason());
+    }
+
+    // tests formatting
+    #[test]
+    fn test_display_formatting() {
+        let instr = Instruction::Push(PUSHData::new(Register::SR));
+        let err1 = EncodeError::new(instr.clone());
+        let err2 = EncodeError::with_reason(instr, "Example reason");
+        assert_eq!(format!("{}", err1), "Couldn't encode instruction `push sr`!");
+        assert_eq!(
+            format!("{}", err2),
+            "Couldn't encode instruction `push sr`! Reason: Example reason"
+        );
+    }
+}
+
+// end of file
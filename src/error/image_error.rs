@@ -0,0 +1,73 @@
+/*
+ * image_error.rs
+ * Defines an error generated when reading or writing a program image fails
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::fmt;
+
+/// An error resulting from a failure to read or write a program image
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageError {
+    /// The reason for the failure
+    err_reason: String,
+}
+
+// implementation
+impl ImageError {
+    /// Creates a new `ImageError` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - The reason that the error was triggered
+    ///
+    /// # Returns
+    ///
+    /// A new `ImageError` instance
+    pub fn new(reason: &str) -> Self {
+        ImageError {
+            err_reason: reason.to_owned(),
+        }
+    }
+
+    /// Gets the reason for the error
+    ///
+    /// # Returns
+    ///
+    /// The reason for the error
+    pub fn reason(&self) -> &str {
+        &self.err_reason
+    }
+}
+
+// Display implementation
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid program image! Reason: {}", self.err_reason)
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring struct into scope
+    use super::*;
+
+    // tests formatting
+    #[test]
+    fn test_display_formatting() {
+        let err = ImageError::new("Example reason");
+        assert_eq!(
+            format!("{}", err),
+            "Invalid program image! Reason: Example reason"
+        );
+    }
+}
+
+// end of file
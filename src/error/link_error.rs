@@ -0,0 +1,112 @@
+/*
+ * link_error.rs
+ * Defines an error generated when reading or linking object files fails
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::fmt;
+
+/// An error resulting from a failure to read or link object files
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkError {
+    /// The name of the object file that the error came from, if any
+    file: Option<String>,
+
+    /// The reason for the failure
+    err_reason: String,
+}
+
+// implementation
+impl LinkError {
+    /// Creates a new `LinkError` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - The reason that the error was triggered
+    ///
+    /// # Returns
+    ///
+    /// A new `LinkError` instance
+    pub fn new(reason: &str) -> Self {
+        LinkError {
+            file: None,
+            err_reason: reason.to_owned(),
+        }
+    }
+
+    /// Creates a new `LinkError` instance for an object file
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The name of the object file that triggered the error
+    /// * `reason` - The reason that the error was triggered
+    ///
+    /// # Returns
+    ///
+    /// A new `LinkError` instance
+    pub fn in_file(file: &str, reason: &str) -> Self {
+        LinkError {
+            file: Some(file.to_owned()),
+            err_reason: reason.to_owned(),
+        }
+    }
+
+    /// Gets the name of the object file that triggered the error
+    ///
+    /// # Returns
+    ///
+    /// The name of the object file, wrapped in an `Option`
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// Gets the reason for the error
+    ///
+    /// # Returns
+    ///
+    /// The reason for the error
+    pub fn reason(&self) -> &str {
+        &self.err_reason
+    }
+}
+
+// Display implementation
+impl fmt::Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "Couldn't link `{}`! Reason: {}", file, self.err_reason),
+            None => write!(f, "Couldn't link program! Reason: {}", self.err_reason),
+        }
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring struct into scope
+    use super::*;
+
+    // tests formatting
+    #[test]
+    fn test_display_formatting() {
+        let err1 = LinkError::new("Example reason");
+        let err2 = LinkError::in_file("main.asm", "Example reason");
+        assert_eq!(err2.file(), Some("main.asm"));
+        assert_eq!(
+            format!("{}", err1),
+            "Couldn't link program! Reason: Example reason"
+        );
+        assert_eq!(
+            format!("{}", err2),
+            "Couldn't link `main.asm`! Reason: Example reason"
+        );
+    }
+}
+
+// end of file
@@ -12,5 +12,17 @@
 // module exports
 mod decode_error;
 pub use decode_error::DecodeError;
+mod encode_error;
+pub use encode_error::EncodeError;
+mod asm_error;
+pub use asm_error::AsmError;
+mod emu_error;
+pub use emu_error::EmuError;
+mod codegen_error;
+pub use codegen_error::CodegenError;
+mod link_error;
+pub use link_error::LinkError;
+mod image_error;
+pub use image_error::ImageError;
 
 // end of file
@@ -23,4 +23,19 @@ pub mod error;
 /// Code relating to instruction data
 pub mod data;
 
+/// Code relating to assembling source text
+pub mod asm;
+
+/// Code relating to object files, linking and program images
+pub mod link;
+
+/// Code relating to disassembling opcode streams
+pub mod disasm;
+
+/// Code relating to emulating the Minecraft CPU
+pub mod emu;
+
+/// Code relating to generating Minecraft datapacks
+pub mod codegen;
+
 // end of file
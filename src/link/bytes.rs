@@ -0,0 +1,155 @@
+/*
+ * bytes.rs
+ * Reads and writes the big-endian values of binary files
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use std::convert::TryFrom;
+
+/// [Internal use only]
+/// Reads big-endian values from the bytes of a file
+pub(crate) struct Reader<'a, E> {
+    /// The bytes being read
+    bytes: &'a [u8],
+
+    /// The index of the next byte to read
+    pos: usize,
+
+    /// Creates the error for a malformed file
+    err: fn(&str) -> E,
+}
+
+// implementation
+impl<'a, E> Reader<'a, E> {
+    /// Creates a new `Reader` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to read
+    /// * `err` - Creates the error for a malformed file, given its reason
+    ///
+    /// # Returns
+    ///
+    /// A new `Reader` at the start of the bytes
+    pub(crate) fn new(bytes: &'a [u8], err: fn(&str) -> E) -> Self {
+        Reader { bytes, pos: 0, err }
+    }
+
+    /// Checks whether every byte has been read
+    ///
+    /// # Returns
+    ///
+    /// Whether the reader is at the end of the bytes
+    pub(crate) fn at_end(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    /// Reads a run of bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of bytes to read
+    ///
+    /// # Returns
+    ///
+    /// The bytes, or an error if the file ends first
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8], E> {
+        let bytes = self
+            .bytes
+            .get(self.pos..self.pos.saturating_add(len))
+            .ok_or_else(|| (self.err)("unexpected end of file"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Reads a byte
+    ///
+    /// # Returns
+    ///
+    /// The byte, or an error if the file ends first
+    pub(crate) fn u8(&mut self) -> Result<u8, E> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Reads a big-endian `u16`
+    ///
+    /// # Returns
+    ///
+    /// The value, or an error if the file ends first
+    pub(crate) fn u16(&mut self) -> Result<u16, E> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Reads a big-endian `u32`
+    ///
+    /// # Returns
+    ///
+    /// The value, or an error if the file ends first
+    pub(crate) fn u32(&mut self) -> Result<u32, E> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads a string with a `u16` byte length
+    ///
+    /// # Returns
+    ///
+    /// The string, or an error if the file ends
+    /// first or the string is not valid UTF-8
+    pub(crate) fn string(&mut self) -> Result<String, E> {
+        let len = self.u16()? as usize;
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|_| (self.err)("name is not valid UTF-8"))
+    }
+}
+
+/// [Internal use only]
+/// Writes a big-endian `u32`
+///
+/// # Arguments
+///
+/// * `out` - The bytes being written
+/// * `val` - The value to write
+pub(crate) fn push_u32(out: &mut Vec<u8>, val: u32) {
+    out.extend_from_slice(&val.to_be_bytes());
+}
+
+/// [Internal use only]
+/// Writes a count or a location as a big-endian `u32`
+///
+/// # Arguments
+///
+/// * `out` - The bytes being written
+/// * `len` - The value to write
+///
+/// # Panics
+///
+/// This function will panic if `len` does not fit in a `u32`.
+pub(crate) fn push_len(out: &mut Vec<u8>, len: usize) {
+    push_u32(out, u32::try_from(len).expect("length does not fit in 32 bits"));
+}
+
+/// [Internal use only]
+/// Writes a string with a `u16` byte length
+///
+/// # Arguments
+///
+/// * `out` - The bytes being written
+/// * `s` - The string to write
+///
+/// # Panics
+///
+/// This function will panic if `s` is longer than 65535 bytes.
+pub(crate) fn push_str(out: &mut Vec<u8>, s: &str) {
+    let len = u16::try_from(s.len()).expect("name is longer than 65535 bytes");
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(s.as_bytes());
+}
+
+// end of file
@@ -0,0 +1,357 @@
+/*
+ * image.rs
+ * Defines the binary program image format
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::asm::Program;
+use super::super::asm::Span;
+use super::super::asm::SymbolTable;
+use super::super::error::ImageError;
+use super::super::opcode::decode_all;
+use super::super::opcode::encode;
+use super::super::opcode::Opcode;
+use super::super::opcode::ISA_MAJOR_VERSION;
+use super::super::opcode::ISA_MINOR_VERSION;
+use super::bytes::push_len;
+use super::bytes::push_str;
+use super::bytes::push_u32;
+use super::bytes::Reader;
+
+/// The magic number that starts every program image
+pub const IMAGE_MAGIC: [u8; 4] = *b"MCIM";
+
+/// The version of the program image format written by this crate
+pub const IMAGE_VERSION: u16 = 1;
+
+/// A program ready to be loaded, along with the
+/// label that execution starts at
+///
+/// A program image is stored as big-endian bytes:
+///
+/// | Section  | Contents                                                  |
+/// |----------|-----------------------------------------------------------|
+/// | Header   | `MCIM`, `u16` format version, `u16` ISA major version, `u16` ISA minor version |
+/// | Entry    | `u32` entry label ID                                      |
+/// | Code     | `u32` instruction count, `u32` word count, words          |
+/// | Data     | `u32` value count, values                                 |
+/// | Labels   | `u32` count, then per label: `u32` ID, `u32` line, `u32` column, `u32` length, name |
+/// | Checksum | `u32` CRC-32 of every byte before it                      |
+///
+/// Names are a `u16` byte length followed by UTF-8 bytes.
+/// The checksum uses the IEEE polynomial, like zip and PNG.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramImage {
+    /// The program
+    program: Program,
+
+    /// The ID of the label that execution starts at
+    entry: u32,
+}
+
+// implementation
+impl ProgramImage {
+    /// Creates a new `ProgramImage` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The program
+    /// * `entry` - The ID of the label that execution starts at
+    ///
+    /// # Returns
+    ///
+    /// A new `ProgramImage` instance with the given data
+    pub fn new(program: Program, entry: u32) -> Self {
+        ProgramImage { program, entry }
+    }
+
+    /// Gets the program
+    ///
+    /// # Returns
+    ///
+    /// The program stored in the image
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Gets the entry label
+    ///
+    /// # Returns
+    ///
+    /// The ID of the label that execution starts at,
+    /// which is the index of its first instruction
+    pub fn entry(&self) -> u32 {
+        self.entry
+    }
+
+    /// Converts the image into its program
+    ///
+    /// # Returns
+    ///
+    /// The program stored in the image
+    pub fn into_program(self) -> Program {
+        self.program
+    }
+
+    /// Serializes the image, tagged with the ISA revision of this crate
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the image, or an `ImageError` if the entry
+    /// label is out of range or an instruction can't be encoded
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a label name is longer than
+    /// 65535 bytes or a section has more than `u32::MAX` entries.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ImageError> {
+        let instrs = self.program.instructions();
+        if self.entry as usize > instrs.len() {
+            return Err(ImageError::new(&format!(
+                "entry label ID {} is out of range for {} instructions",
+                self.entry,
+                instrs.len()
+            )));
+        }
+
+        let mut out = IMAGE_MAGIC.to_vec();
+        out.extend_from_slice(&IMAGE_VERSION.to_be_bytes());
+        out.extend_from_slice(&ISA_MAJOR_VERSION.to_be_bytes());
+        out.extend_from_slice(&ISA_MINOR_VERSION.to_be_bytes());
+        push_u32(&mut out, self.entry);
+
+        let mut code = Vec::new();
+        for (idx, instr) in instrs.iter().enumerate() {
+            code.extend(encode(instr).map_err(|err| {
+                let reason = err.reason().unwrap_or_else(|| "unknown reason".to_owned());
+                ImageError::new(&format!("cannot encode instruction {} (`{}`): {}", idx, instr, reason))
+            })?);
+        }
+        push_len(&mut out, instrs.len());
+        push_len(&mut out, code.len());
+        for word in code {
+            push_u32(&mut out, word.value());
+        }
+        push_len(&mut out, self.program.data().len());
+        for val in self.program.data() {
+            push_u32(&mut out, *val);
+        }
+
+        let symbols = self.program.symbols();
+        push_len(&mut out, symbols.len());
+        for (name, id) in symbols.iter() {
+            let span = symbols.span(name).unwrap();
+            push_u32(&mut out, id);
+            push_len(&mut out, span.line());
+            push_len(&mut out, span.col());
+            push_len(&mut out, span.len());
+            push_str(&mut out, name);
+        }
+
+        let checksum = crc32(&out);
+        push_u32(&mut out, checksum);
+        Ok(out)
+    }
+
+    /// Deserializes an image
+    ///
+    /// Images built for a different major ISA revision, or a later
+    /// minor revision, are rejected rather than decoded, since their
+    /// encodings may mean something else to this crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes of the image
+    ///
+    /// # Returns
+    ///
+    /// The image, or an `ImageError` if the bytes are not a well-formed
+    /// image, are corrupt, or were built for an incompatible ISA revision
+    pub fn from_bytes(bytes: &[u8]) -> Result<ProgramImage, ImageError> {
+        let mut reader = Reader::new(bytes, ImageError::new);
+        if reader.take(4)? != IMAGE_MAGIC {
+            return Err(ImageError::new("not a program image (bad magic number)"));
+        }
+        let version = reader.u16()?;
+        if version != IMAGE_VERSION {
+            return Err(ImageError::new(&format!(
+                "unsupported image format version {} (expected {})",
+                version, IMAGE_VERSION
+            )));
+        }
+
+        // check the checksum before trusting anything else
+        if bytes.len() < 10 {
+            return Err(ImageError::new("unexpected end of file"));
+        }
+        let (body, tail) = bytes.split_at(bytes.len() - 4);
+        let stored = u32::from_be_bytes([tail[0], tail[1], tail[2], tail[3]]);
+        let computed = crc32(body);
+        if stored != computed {
+            return Err(ImageError::new(&format!(
+                "checksum mismatch (stored {:#010x}, computed {:#010x})",
+                stored, computed
+            )));
+        }
+
+        let mut reader = Reader::new(&body[6..], ImageError::new);
+        let major = reader.u16()?;
+        let minor = reader.u16()?;
+        if major != ISA_MAJOR_VERSION || minor > ISA_MINOR_VERSION {
+            return Err(ImageError::new(&format!(
+                "image was built for ISA revision {}.{}, but this crate implements {}.{}",
+                major, minor, ISA_MAJOR_VERSION, ISA_MINOR_VERSION
+            )));
+        }
+        let entry = reader.u32()?;
+
+        let instr_count = reader.u32()?;
+        let code = (0..reader.u32()?)
+            .map(|_| reader.u32().map(Opcode::from))
+            .collect::<Result<Vec<Opcode>, ImageError>>()?;
+        let data = (0..reader.u32()?)
+            .map(|_| reader.u32())
+            .collect::<Result<Vec<u32>, ImageError>>()?;
+        let mut labels = Vec::new();
+        for _ in 0..reader.u32()? {
+            let id = reader.u32()?;
+            let line = reader.u32()? as usize;
+            let col = reader.u32()? as usize;
+            let len = reader.u32()? as usize;
+            labels.push((reader.string()?, id, Span::new(line, col, len)));
+        }
+        if !reader.at_end() {
+            return Err(ImageError::new("unexpected bytes before the checksum"));
+        }
+
+        let instrs = decode_all(&code).map_err(|err| match err.reason() {
+            Some(reason) => ImageError::new(&format!("cannot decode opcode {}: {}", err.op(), reason)),
+            None => ImageError::new(&format!("cannot decode opcode {}", err.op())),
+        })?;
+        if instrs.len() != instr_count as usize {
+            return Err(ImageError::new(&format!(
+                "code section holds {} instructions, but the header says {}",
+                instrs.len(),
+                instr_count
+            )));
+        }
+        if entry > instr_count {
+            return Err(ImageError::new(&format!(
+                "entry label ID {} is out of range for {} instructions",
+                entry, instr_count
+            )));
+        }
+
+        let mut symbols = SymbolTable::new();
+        for (name, id, span) in labels {
+            if id > instr_count {
+                return Err(ImageError::new(&format!(
+                    "label `{}` has ID {}, which is out of range for {} instructions",
+                    name, id, instr_count
+                )));
+            }
+            symbols
+                .define(&name, id, span)
+                .map_err(|err| ImageError::new(err.message()))?;
+        }
+        Ok(ProgramImage::new(Program::new(instrs, symbols).with_data(data), entry))
+    }
+}
+
+/// [Internal use only]
+/// Computes the CRC-32 of a run of bytes, using the IEEE polynomial
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to check
+///
+/// # Returns
+///
+/// The checksum of the bytes
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB88320 & mask);
+        }
+    }
+    !crc
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the types into scope
+    use super::*;
+    use super::super::super::asm::assemble;
+
+    // replaces the checksum of modified image bytes
+    fn reseal(bytes: &mut Vec<u8>) {
+        let len = bytes.len() - 4;
+        bytes.truncate(len);
+        let checksum = crc32(bytes);
+        bytes.extend_from_slice(&checksum.to_be_bytes());
+    }
+
+    // tests the checksum against the standard check value
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF43926);
+    }
+
+    // tests writing and reading back an image
+    #[test]
+    fn test_round_trip() {
+        let program = assemble("mov 0x12345678, r0\nstart: dec r0\njmp start\n.data 3, 4\n").unwrap();
+        let image = ProgramImage::new(program, 1);
+        let bytes = image.to_bytes().unwrap();
+        assert_eq!(&bytes[..10], b"MCIM\x00\x01\x00\x01\x00\x00");
+        let read = ProgramImage::from_bytes(&bytes).unwrap();
+        assert_eq!(read, image);
+        assert_eq!(read.entry(), 1);
+        assert_eq!(read.into_program().symbols().id("start"), Some(1));
+    }
+
+    // tests rejecting corrupt and incompatible images
+    #[test]
+    fn test_rejected() {
+        let bytes = ProgramImage::new(assemble("hlt\n").unwrap(), 0).to_bytes().unwrap();
+        let mut corrupt = bytes.clone();
+        corrupt[16] ^= 1;
+        let mut major = bytes.clone();
+        major[7] = 2;
+        reseal(&mut major);
+        let mut minor = bytes.clone();
+        minor[9] = 1;
+        reseal(&mut minor);
+        let mut entry = bytes.clone();
+        entry[13] = 2;
+        reseal(&mut entry);
+
+        let err1 = ProgramImage::from_bytes(b"MCOB\x00\x01").unwrap_err();
+        let err2 = ProgramImage::from_bytes(&corrupt).unwrap_err();
+        let err3 = ProgramImage::from_bytes(&major).unwrap_err();
+        let err4 = ProgramImage::from_bytes(&minor).unwrap_err();
+        let err5 = ProgramImage::from_bytes(&entry).unwrap_err();
+        let err6 = ProgramImage::from_bytes(&bytes[..8]).unwrap_err();
+        let err7 = ProgramImage::new(assemble("hlt\n").unwrap(), 2).to_bytes().unwrap_err();
+        assert_eq!(err1.reason(), "not a program image (bad magic number)");
+        assert!(err2.reason().starts_with("checksum mismatch (stored "));
+        assert_eq!(err3.reason(), "image was built for ISA revision 2.0, but this crate implements 1.0");
+        assert_eq!(err4.reason(), "image was built for ISA revision 1.1, but this crate implements 1.0");
+        assert_eq!(err5.reason(), "entry label ID 2 is out of range for 1 instructions");
+        assert_eq!(err6.reason(), "unexpected end of file");
+        assert_eq!(err7.reason(), "entry label ID 2 is out of range for 1 instructions");
+    }
+}
+
+// end of file
@@ -0,0 +1,256 @@
+/*
+ * linker.rs
+ * Defines a function that links object files into a program
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::asm::Program;
+use super::super::asm::SymbolTable;
+use super::super::error::LinkError;
+use super::super::opcode::decode_all;
+use super::super::opcode::Opcode;
+use super::Binding;
+use super::ObjectFile;
+use super::RelocationField;
+use std::collections::HashMap;
+
+/// Links object files into a program
+///
+/// The objects' code is laid out in the order they are given,
+/// so the program starts with the first object's code. Every
+/// label ID is renumbered by adding the index of the first
+/// instruction of its object, and every reference to a `.extern`
+/// label is resolved to the label that another object exports
+/// with `.global`. The objects' data is concatenated in the
+/// same order.
+///
+/// The program's `SymbolTable` lists the exported labels, each
+/// with its location in the source of the object that defines it.
+///
+/// # Arguments
+///
+/// * `objects` - The objects to link
+///
+/// # Returns
+///
+/// The linked program, or a `LinkError` naming the object
+/// with an undefined, duplicate or out-of-range label
+pub fn link(objects: &[ObjectFile]) -> Result<Program, LinkError> {
+    // lay out the objects, and find the exported labels
+    let mut bases = Vec::new();
+    let mut next = 0u32;
+    let mut exports: HashMap<&str, (u32, &str)> = HashMap::new();
+    let mut symbols = SymbolTable::new();
+    for obj in objects {
+        bases.push(next);
+        for sym in obj.symbols().iter().filter(|sym| sym.binding() == Binding::Global) {
+            if let Some((_, file)) = exports.get(sym.name()) {
+                return Err(LinkError::in_file(
+                    obj.name(),
+                    &format!("duplicate symbol `{}` (already defined in `{}`)", sym.name(), file),
+                ));
+            }
+            let id = next.checked_add(sym.value()).ok_or_else(|| too_long(obj))?;
+            exports.insert(sym.name(), (id, obj.name()));
+            symbols
+                .define(sym.name(), id, sym.span())
+                .map_err(|err| LinkError::in_file(obj.name(), err.message()))?;
+        }
+        next = next.checked_add(obj.instr_count()).ok_or_else(|| too_long(obj))?;
+    }
+
+    // patch the label references, and decode each object's code
+    let mut instrs = Vec::new();
+    let mut data = Vec::new();
+    for (obj, base) in objects.iter().zip(bases) {
+        let mut code: Vec<u32> = obj.code().iter().map(|op| op.value()).collect();
+        for reloc in obj.relocations() {
+            let word = code.get_mut(reloc.offset() as usize).ok_or_else(|| {
+                LinkError::in_file(
+                    obj.name(),
+                    &format!("relocation offset {} is outside the code section", reloc.offset()),
+                )
+            })?;
+            let (mask, bits) = match reloc.field() {
+                RelocationField::Wide => (0x00FFFFFF, 24),
+                RelocationField::Short => (0x0000FFFF, 16),
+            };
+            let sym = match reloc.symbol() {
+                Some(idx) => Some(obj.symbols().get(idx as usize).ok_or_else(|| {
+                    LinkError::in_file(obj.name(), &format!("relocation refers to missing symbol {}", idx))
+                })?),
+                None => None,
+            };
+            let id = match sym {
+                Some(sym) if sym.binding() == Binding::Extern => match exports.get(sym.name()) {
+                    Some((id, _)) => Some(*id),
+                    None => {
+                        return Err(LinkError::in_file(
+                            obj.name(),
+                            &format!("undefined symbol `{}`", sym.name()),
+                        ))
+                    }
+                },
+                Some(sym) => base.checked_add(sym.value()),
+                None => base.checked_add(*word & mask),
+            };
+            match id.filter(|id| id & !mask == 0) {
+                Some(id) => *word = (*word & !mask) | id,
+                None => {
+                    return Err(LinkError::in_file(
+                        obj.name(),
+                        &format!(
+                            "label ID {} at word {} does not fit in {} bits",
+                            id.map_or_else(|| "past 2^32".to_owned(), |id| id.to_string()),
+                            reloc.offset(),
+                            bits
+                        ),
+                    ))
+                }
+            }
+        }
+
+        let words: Vec<Opcode> = code.into_iter().map(Opcode::from).collect();
+        let decoded = decode_all(&words).map_err(|err| {
+            let reason = match err.reason() {
+                Some(reason) => format!("cannot decode opcode {}: {}", err.op(), reason),
+                None => format!("cannot decode opcode {}", err.op()),
+            };
+            LinkError::in_file(obj.name(), &reason)
+        })?;
+        if decoded.len() != obj.instr_count() as usize {
+            return Err(LinkError::in_file(
+                obj.name(),
+                &format!(
+                    "code section holds {} instructions, but the header says {}",
+                    decoded.len(),
+                    obj.instr_count()
+                ),
+            ));
+        }
+        instrs.extend(decoded);
+        data.extend_from_slice(obj.data());
+    }
+
+    Ok(Program::new(instrs, symbols).with_data(data))
+}
+
+/// [Internal use only]
+/// Creates the error for a program with more than `u32::MAX` instructions
+///
+/// # Arguments
+///
+/// * `obj` - The object that made the program too long
+///
+/// # Returns
+///
+/// A `LinkError` naming the object
+fn too_long(obj: &ObjectFile) -> LinkError {
+    LinkError::in_file(obj.name(), "the linked program has too many instructions")
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the function into scope
+    use super::*;
+    use super::super::super::asm::assemble_object;
+    use super::super::super::asm::MemoryFileSystem;
+    use super::super::super::cpu::Flag;
+    use super::super::super::cpu::Register;
+    use super::super::super::data::*;
+
+    // assembles an object from in-memory sources
+    fn object(fs: &MemoryFileSystem, path: &str) -> ObjectFile {
+        assemble_object(fs, path).unwrap()
+    }
+
+    // tests renumbering labels and resolving externs
+    #[test]
+    fn test_link() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(
+            "main.asm",
+            ".extern square, halt\nmov 3, r0\nloop: call square\njs flag_Zero, done\njmp loop\ndone: jmp halt\n.data 1\n",
+        );
+        fs.add_file("math.asm", ".global square, halt\nhalt: hlt\nsquare: mul r0, r0, r0\nret\n.data 2\n");
+        let main = object(&fs, "main.asm");
+        let math = object(&fs, "math.asm");
+
+        // objects survive being written out and read back
+        let main = ObjectFile::from_bytes(&main.to_bytes()).unwrap();
+        let program = link(&[main, math]).unwrap();
+        assert_eq!(
+            program.instructions(),
+            &[
+                MOVData::from_literal(3, Register::R0).into(),
+                CALLData::new(6).into(),
+                JSData::new(Flag::Zero, 4).into(),
+                JMPData::new(1).into(),
+                JMPData::new(5).into(),
+                HLTData::new().into(),
+                MULData::new(Register::R0, Register::R0, Register::R0).into(),
+                RETData::new().into(),
+            ][..]
+        );
+        assert_eq!(program.data(), &[1, 2]);
+        let exports: Vec<(&str, u32)> = program.symbols().iter().collect();
+        assert_eq!(exports, vec![("halt", 5), ("square", 6)]);
+    }
+
+    // tests label references in an object that doesn't start at 0
+    #[test]
+    fn test_link_offset() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file("first.asm", "nop\nnop\n");
+        fs.add_file("second.asm", "x: nop\n.align 2\njmp x + 1\njs flag_Zero, L0\n");
+        let program = link(&[object(&fs, "first.asm"), object(&fs, "second.asm")]).unwrap();
+        assert_eq!(
+            &program.instructions()[2..],
+            &[
+                NOPData::new().into(),
+                NOPData::new().into(),
+                JMPData::new(3).into(),
+                JSData::new(Flag::Zero, 2).into(),
+            ][..]
+        );
+
+        // label IDs used as values would not be relocated, so they are rejected
+        for src in &["x: nop\nmov x, r0\n", "x: nop\n.data x\n", "x: nop\n.const K, x + 1\n"] {
+            fs.add_file("value.asm", src);
+            let err = assemble_object(&fs, "value.asm").unwrap_err();
+            assert_eq!(
+                err.message(),
+                "label `x` can only be used as a jump target or an address in an object"
+            );
+        }
+    }
+
+    // tests undefined, duplicate and out-of-range labels
+    #[test]
+    fn test_link_errors() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file("a.asm", ".extern f\n.global g\ng: call f\n");
+        fs.add_file("b.asm", ".global g\ng: nop\n");
+        fs.add_file("c.asm", ".org 0x10000\n.global far\nfar: nop\n");
+        fs.add_file("d.asm", ".extern far\njs flag_Zero, far\n");
+        let a = object(&fs, "a.asm");
+        let b = object(&fs, "b.asm");
+        let err1 = link(&[object(&fs, "a.asm")]).unwrap_err();
+        let err2 = link(&[a, b]).unwrap_err();
+        let err3 = link(&[object(&fs, "d.asm"), object(&fs, "c.asm")]).unwrap_err();
+        assert_eq!(err1.to_string(), "Couldn't link `a.asm`! Reason: undefined symbol `f`");
+        assert_eq!(err2.file(), Some("b.asm"));
+        assert_eq!(err2.reason(), "duplicate symbol `g` (already defined in `a.asm`)");
+        assert_eq!(err3.file(), Some("d.asm"));
+        assert_eq!(err3.reason(), "label ID 65537 at word 0 does not fit in 16 bits");
+    }
+}
+
+// end of file
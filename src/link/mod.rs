@@ -0,0 +1,29 @@
+/*
+ * mod.rs
+ * Module header for mcisa's link module
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// module exports
+mod bytes;
+mod object;
+pub use object::Binding;
+pub use object::ObjectFile;
+pub use object::ObjectSymbol;
+pub use object::Relocation;
+pub use object::RelocationField;
+pub use object::OBJECT_MAGIC;
+pub use object::OBJECT_VERSION;
+mod linker;
+pub use linker::link;
+mod image;
+pub use image::ProgramImage;
+pub use image::IMAGE_MAGIC;
+pub use image::IMAGE_VERSION;
+
+// end of file
@@ -0,0 +1,534 @@
+/*
+ * object.rs
+ * Defines the relocatable object file format
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::asm::Span;
+use super::super::error::LinkError;
+use super::super::opcode::Opcode;
+use super::bytes::push_len;
+use super::bytes::push_str;
+use super::bytes::push_u32;
+use super::bytes::Reader;
+
+/// The magic number that starts every object file
+pub const OBJECT_MAGIC: [u8; 4] = *b"MCOB";
+
+/// The version of the object file format written by this crate
+pub const OBJECT_VERSION: u16 = 1;
+
+/// [Internal use only]
+/// The symbol index of a relocation that refers to no symbol
+const NO_SYMBOL: u32 = u32::MAX;
+
+/// The visibility of a symbol in an object file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Binding {
+    /// A label that only the object itself can refer to
+    Local,
+
+    /// A label exported by `.global`, which other objects can refer to
+    Global,
+
+    /// A label declared by `.extern`, which another object must export
+    Extern,
+}
+
+/// The operand field that a relocation patches
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelocationField {
+    /// The 24-bit wide field of `JMP` and `CALL`
+    Wide,
+
+    /// The 16-bit short field of `JS`, `JNS` and `J<cc>`
+    Short,
+}
+
+/// A label in the symbol table of an object file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectSymbol {
+    /// The name of the label
+    name: String,
+
+    /// The visibility of the label
+    binding: Binding,
+
+    /// The ID of the label within its object, or 0 for an extern
+    value: u32,
+
+    /// The location of the label's definition or declaration
+    span: Span,
+}
+
+// implementation
+impl ObjectSymbol {
+    /// Creates a new `ObjectSymbol` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label
+    /// * `binding` - The visibility of the label
+    /// * `value` - The ID of the label within its object, or 0 for an extern
+    /// * `span` - The location of the label's definition or declaration
+    ///
+    /// # Returns
+    ///
+    /// A new `ObjectSymbol` instance with the given data
+    pub fn new(name: &str, binding: Binding, value: u32, span: Span) -> Self {
+        ObjectSymbol {
+            name: name.to_owned(),
+            binding,
+            value,
+            span,
+        }
+    }
+
+    /// Gets the name of the label
+    ///
+    /// # Returns
+    ///
+    /// The name of the label
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the visibility of the label
+    ///
+    /// # Returns
+    ///
+    /// The `Binding` of the label
+    pub fn binding(&self) -> Binding {
+        self.binding
+    }
+
+    /// Gets the ID of the label within its object
+    ///
+    /// # Returns
+    ///
+    /// The index of the instruction that the label marks,
+    /// counted from the start of the object, or 0 for an extern
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Gets the location of the label's definition or declaration
+    ///
+    /// # Returns
+    ///
+    /// The `Span` of the label in the object's source
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// A label reference that the linker must patch
+///
+/// A relocation that refers to a symbol replaces the field
+/// with the final ID of that symbol. One that doesn't refers
+/// to a label of its own object, whose ID is already in the
+/// field, so the linker adds the object's starting index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Relocation {
+    /// The index of the code word holding the reference
+    offset: u32,
+
+    /// The operand field holding the reference
+    field: RelocationField,
+
+    /// The index of the symbol being referred to, if any
+    symbol: Option<u32>,
+}
+
+// implementation
+impl Relocation {
+    /// Creates a new `Relocation` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The index of the code word holding the reference
+    /// * `field` - The operand field holding the reference
+    /// * `symbol` - The index of the symbol being referred to,
+    ///   or `None` for a label ID within the object
+    ///
+    /// # Returns
+    ///
+    /// A new `Relocation` instance with the given data
+    pub fn new(offset: u32, field: RelocationField, symbol: Option<u32>) -> Self {
+        Relocation {
+            offset,
+            field,
+            symbol,
+        }
+    }
+
+    /// Gets the index of the code word holding the reference
+    ///
+    /// # Returns
+    ///
+    /// The index of the word in the object's code section
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Gets the operand field holding the reference
+    ///
+    /// # Returns
+    ///
+    /// The `RelocationField` that is patched
+    pub fn field(&self) -> RelocationField {
+        self.field
+    }
+
+    /// Gets the symbol being referred to
+    ///
+    /// # Returns
+    ///
+    /// The index of the symbol in the object's symbol
+    /// table, or `None` for a label ID within the object
+    pub fn symbol(&self) -> Option<u32> {
+        self.symbol
+    }
+}
+
+/// A separately assembled module, which the linker
+/// combines with other objects into a program
+///
+/// An object file is stored as big-endian bytes:
+///
+/// | Section     | Contents                                          |
+/// |-------------|---------------------------------------------------|
+/// | Header      | `MCOB`, `u16` format version, name                |
+/// | Code        | `u32` instruction count, `u32` word count, words  |
+/// | Data        | `u32` value count, values                         |
+/// | Symbols     | `u32` count, then per symbol: `u8` binding, `u32` value, `u32` line, `u32` column, `u32` length, name |
+/// | Relocations | `u32` count, then per relocation: `u32` offset, `u8` field, `u32` symbol index |
+///
+/// Names are a `u16` byte length followed by UTF-8 bytes. Bindings
+/// are 0 for local, 1 for global and 2 for extern, fields are 0 for
+/// wide and 1 for short, and a relocation without a symbol has a
+/// symbol index of `0xFFFFFFFF`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectFile {
+    /// The name of the object, usually the path of its source file
+    name: String,
+
+    /// The number of instructions encoded in `code`
+    instr_count: u32,
+
+    /// The encoded instructions
+    code: Vec<Opcode>,
+
+    /// The values declared by `.data` directives
+    data: Vec<u32>,
+
+    /// The labels defined or declared by the object
+    symbols: Vec<ObjectSymbol>,
+
+    /// The label references in `code`
+    relocations: Vec<Relocation>,
+}
+
+// implementation
+impl ObjectFile {
+    /// Creates a new `ObjectFile` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the object, usually the path of its source file
+    /// * `instr_count` - The number of instructions encoded in `code`
+    /// * `code` - The encoded instructions
+    /// * `symbols` - The labels defined or declared by the object
+    /// * `relocations` - The label references in `code`
+    ///
+    /// # Returns
+    ///
+    /// A new `ObjectFile` instance with the given data and no `.data` values
+    pub fn new(
+        name: &str,
+        instr_count: u32,
+        code: Vec<Opcode>,
+        symbols: Vec<ObjectSymbol>,
+        relocations: Vec<Relocation>,
+    ) -> Self {
+        ObjectFile {
+            name: name.to_owned(),
+            instr_count,
+            code,
+            data: Vec::new(),
+            symbols,
+            relocations,
+        }
+    }
+
+    /// Sets the data declared by the object
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The values declared by `.data` directives
+    ///
+    /// # Returns
+    ///
+    /// The object with the given data
+    pub fn with_data(mut self, data: Vec<u32>) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Gets the name of the object
+    ///
+    /// # Returns
+    ///
+    /// The name of the object, which the linker uses in error messages
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the number of instructions in the object
+    ///
+    /// # Returns
+    ///
+    /// The number of instructions encoded in the code section
+    pub fn instr_count(&self) -> u32 {
+        self.instr_count
+    }
+
+    /// Gets the code section of the object
+    ///
+    /// # Returns
+    ///
+    /// The encoded instructions
+    pub fn code(&self) -> &[Opcode] {
+        &self.code
+    }
+
+    /// Gets the data declared by the object
+    ///
+    /// # Returns
+    ///
+    /// The values declared by `.data` directives
+    pub fn data(&self) -> &[u32] {
+        &self.data
+    }
+
+    /// Gets the symbol table of the object
+    ///
+    /// # Returns
+    ///
+    /// The labels defined or declared by the object
+    pub fn symbols(&self) -> &[ObjectSymbol] {
+        &self.symbols
+    }
+
+    /// Gets the relocations of the object
+    ///
+    /// # Returns
+    ///
+    /// The label references in the code section
+    pub fn relocations(&self) -> &[Relocation] {
+        &self.relocations
+    }
+
+    /// Serializes the object
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the object file
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a name is longer than
+    /// 65535 bytes or a section has more than
+    /// `u32::MAX` entries.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = OBJECT_MAGIC.to_vec();
+        out.extend_from_slice(&OBJECT_VERSION.to_be_bytes());
+        push_str(&mut out, &self.name);
+
+        push_u32(&mut out, self.instr_count);
+        push_len(&mut out, self.code.len());
+        for word in &self.code {
+            push_u32(&mut out, word.value());
+        }
+        push_len(&mut out, self.data.len());
+        for val in &self.data {
+            push_u32(&mut out, *val);
+        }
+
+        push_len(&mut out, self.symbols.len());
+        for sym in &self.symbols {
+            out.push(match sym.binding {
+                Binding::Local => 0,
+                Binding::Global => 1,
+                Binding::Extern => 2,
+            });
+            push_u32(&mut out, sym.value);
+            push_len(&mut out, sym.span.line());
+            push_len(&mut out, sym.span.col());
+            push_len(&mut out, sym.span.len());
+            push_str(&mut out, &sym.name);
+        }
+
+        push_len(&mut out, self.relocations.len());
+        for reloc in &self.relocations {
+            push_u32(&mut out, reloc.offset);
+            out.push(match reloc.field {
+                RelocationField::Wide => 0,
+                RelocationField::Short => 1,
+            });
+            push_u32(&mut out, reloc.symbol.unwrap_or(NO_SYMBOL));
+        }
+        out
+    }
+
+    /// Deserializes an object
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes of the object file
+    ///
+    /// # Returns
+    ///
+    /// The object, or a `LinkError` if the bytes are not
+    /// a well-formed object file of a supported version
+    pub fn from_bytes(bytes: &[u8]) -> Result<ObjectFile, LinkError> {
+        let mut reader = Reader::new(bytes, LinkError::new);
+        if reader.take(4)? != OBJECT_MAGIC {
+            return Err(LinkError::new("not an object file (bad magic number)"));
+        }
+        let version = reader.u16()?;
+        if version != OBJECT_VERSION {
+            return Err(LinkError::new(&format!(
+                "unsupported object file version {} (expected {})",
+                version, OBJECT_VERSION
+            )));
+        }
+        let name = reader.string()?;
+        let err = |reason: &str| LinkError::in_file(&name, reason);
+
+        let instr_count = reader.u32()?;
+        let code = (0..reader.u32()?)
+            .map(|_| reader.u32().map(Opcode::from))
+            .collect::<Result<Vec<Opcode>, LinkError>>()?;
+        let data = (0..reader.u32()?)
+            .map(|_| reader.u32())
+            .collect::<Result<Vec<u32>, LinkError>>()?;
+
+        let mut symbols = Vec::new();
+        for _ in 0..reader.u32()? {
+            let binding = match reader.u8()? {
+                0 => Binding::Local,
+                1 => Binding::Global,
+                2 => Binding::Extern,
+                other => return Err(err(&format!("invalid symbol binding {}", other))),
+            };
+            let value = reader.u32()?;
+            let line = reader.u32()? as usize;
+            let col = reader.u32()? as usize;
+            let len = reader.u32()? as usize;
+            let name = reader.string()?;
+            symbols.push(ObjectSymbol::new(&name, binding, value, Span::new(line, col, len)));
+        }
+
+        let mut relocations = Vec::new();
+        for _ in 0..reader.u32()? {
+            let offset = reader.u32()?;
+            let field = match reader.u8()? {
+                0 => RelocationField::Wide,
+                1 => RelocationField::Short,
+                other => return Err(err(&format!("invalid relocation field {}", other))),
+            };
+            let symbol = match reader.u32()? {
+                NO_SYMBOL => None,
+                idx => Some(idx),
+            };
+            if offset as usize >= code.len() {
+                return Err(err(&format!(
+                    "relocation offset {} is outside the {}-word code section",
+                    offset,
+                    code.len()
+                )));
+            }
+            if let Some(idx) = symbol.filter(|&idx| idx as usize >= symbols.len()) {
+                return Err(err(&format!(
+                    "relocation refers to symbol {}, but there are only {}",
+                    idx,
+                    symbols.len()
+                )));
+            }
+            relocations.push(Relocation::new(offset, field, symbol));
+        }
+
+        if !reader.at_end() {
+            return Err(err("unexpected bytes after the end of the object file"));
+        }
+        Ok(ObjectFile::new(&name, instr_count, code, symbols, relocations).with_data(data))
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the types into scope
+    use super::*;
+
+    // builds an object with one of everything
+    fn sample() -> ObjectFile {
+        ObjectFile::new(
+            "lib.asm",
+            2,
+            vec![0x40000001.into(), 0x43000000.into()],
+            vec![
+                ObjectSymbol::new("start", Binding::Global, 0, Span::new(1, 1, 5)),
+                ObjectSymbol::new("loop", Binding::Local, 1, Span::new(2, 1, 4)),
+                ObjectSymbol::new("print", Binding::Extern, 0, Span::new(3, 9, 5)),
+            ],
+            vec![
+                Relocation::new(0, RelocationField::Wide, None),
+                Relocation::new(1, RelocationField::Wide, Some(2)),
+            ],
+        )
+        .with_data(vec![7, 8])
+    }
+
+    // tests writing and reading back an object
+    #[test]
+    fn test_round_trip() {
+        let obj = sample();
+        let bytes = obj.to_bytes();
+        assert_eq!(&bytes[..6], b"MCOB\x00\x01");
+        assert_eq!(ObjectFile::from_bytes(&bytes).unwrap(), obj);
+    }
+
+    // tests rejecting malformed objects
+    #[test]
+    fn test_malformed() {
+        let bytes = sample().to_bytes();
+        let mut version = bytes.clone();
+        version[5] = 9;
+        let mut reloc = bytes.clone();
+        let len = reloc.len();
+        reloc[len - 1] = 3;
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        let err1 = ObjectFile::from_bytes(b"MCIMxxxx").unwrap_err();
+        let err2 = ObjectFile::from_bytes(&version).unwrap_err();
+        let err3 = ObjectFile::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+        let err4 = ObjectFile::from_bytes(&reloc).unwrap_err();
+        let err5 = ObjectFile::from_bytes(&trailing).unwrap_err();
+        assert_eq!(err1.reason(), "not an object file (bad magic number)");
+        assert_eq!(err2.reason(), "unsupported object file version 9 (expected 1)");
+        assert_eq!(err3.reason(), "unexpected end of file");
+        assert_eq!(err4.reason(), "relocation refers to symbol 3, but there are only 3");
+        assert_eq!(err4.file(), Some("lib.asm"));
+        assert_eq!(err5.reason(), "unexpected bytes after the end of the object file");
+    }
+}
+
+// end of file
@@ -0,0 +1,940 @@
+/*
+ * decode.rs
+ * Defines a function that decodes opcodes into instructions
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Condition;
+use super::super::cpu::Flag;
+use super::super::cpu::Register;
+use super::super::data::*;
+use super::super::error::DecodeError;
+use super::format;
+use super::Opcode;
+use std::convert::TryFrom;
+
+/// Decodes a single-word `Opcode` into an `Instruction`
+///
+/// The most significant byte of the opcode selects
+/// the instruction, and the remaining three bytes
+/// hold its operands:
+///
+/// | Mnemonic | Code   | Fields 0, 1, 2             |
+/// |----------|--------|----------------------------|
+/// | `NOP`    | `0x00` | 0, 0, 0                    |
+/// | `MOV`    | `0x01` | dest, src, 0               |
+/// | `MOV`    | `0x02` | dest, 16-bit literal       |
+/// | `MOV`    | `0x82` | dest, 0, 1 + 32-bit literal |
+/// | `EXG`    | `0x03` | arg0, arg1, 0              |
+/// | `ADD`    | `0x10` | arg0, arg1, dest           |
+/// | `SUB`    | `0x11` | arg0, arg1, dest           |
+/// | `MUL`    | `0x12` | arg0, arg1, dest           |
+/// | `DIV`    | `0x13` | arg0, arg1, dest           |
+/// | `MOD`    | `0x14` | arg0, arg1, dest           |
+/// | `MIN`    | `0x15` | arg0, arg1, dest           |
+/// | `MAX`    | `0x16` | arg0, arg1, dest           |
+/// | `ADD`    | `0x18` | arg0, dest, 8-bit literal  |
+/// | `SUB`    | `0x19` | arg0, dest, 8-bit literal  |
+/// | `MUL`    | `0x1a` | arg0, dest, 8-bit literal  |
+/// | `DIV`    | `0x1b` | arg0, dest, 8-bit literal  |
+/// | `MOD`    | `0x1c` | arg0, dest, 8-bit literal  |
+/// | `MIN`    | `0x1d` | arg0, dest, 8-bit literal  |
+/// | `MAX`    | `0x1e` | arg0, dest, 8-bit literal  |
+/// | `ADD`    | `0x98` | arg0, dest, 1 + 32-bit literal |
+/// | `SUB`    | `0x99` | arg0, dest, 1 + 32-bit literal |
+/// | `MUL`    | `0x9a` | arg0, dest, 1 + 32-bit literal |
+/// | `DIV`    | `0x9b` | arg0, dest, 1 + 32-bit literal |
+/// | `MOD`    | `0x9c` | arg0, dest, 1 + 32-bit literal |
+/// | `MIN`    | `0x9d` | arg0, dest, 1 + 32-bit literal |
+/// | `MAX`    | `0x9e` | arg0, dest, 1 + 32-bit literal |
+/// | `AND`    | `0x60` | arg0, arg1, dest           |
+/// | `OR`     | `0x61` | arg0, arg1, dest           |
+/// | `XOR`    | `0x62` | arg0, arg1, dest           |
+/// | `NOT`    | `0x63` | arg0, dest, 0              |
+/// | `SHL`    | `0x64` | arg0, dest, amount         |
+/// | `SHR`    | `0x65` | arg0, dest, amount         |
+/// | `AND`    | `0x68` | arg0, dest, 8-bit literal  |
+/// | `OR`     | `0x69` | arg0, dest, 8-bit literal  |
+/// | `XOR`    | `0x6a` | arg0, dest, 8-bit literal  |
+/// | `AND`    | `0xe8` | arg0, dest, 1 + 32-bit literal |
+/// | `OR`     | `0xe9` | arg0, dest, 1 + 32-bit literal |
+/// | `XOR`    | `0xea` | arg0, dest, 1 + 32-bit literal |
+/// | `PUSH`   | `0x20` | reg, 0, 0                  |
+/// | `POP`    | `0x21` | reg, 0, 0                  |
+/// | `CHK`    | `0x30` | reg, 0, 0                  |
+/// | `CMP`    | `0x31` | arg0, arg1, 0              |
+/// | `CMP`    | `0x39` | arg0, 16-bit literal       |
+/// | `CMP`    | `0xb9` | arg0, 0, 1 + 32-bit literal |
+/// | `JMP`    | `0x40` | 24-bit label ID            |
+/// | `JS`     | `0x41` | flag, 16-bit label ID      |
+/// | `JNS`    | `0x42` | flag, 16-bit label ID      |
+/// | `CALL`   | `0x43` | 24-bit label ID            |
+/// | `RET`    | `0x44` | 0, 0, 0                    |
+/// | `HLT`    | `0x45` | 0, 0, 0                    |
+/// | `J<cc>`  | `0x46` | condition, 16-bit label ID |
+/// | `INC`    | `0x70` | reg, 0, 0                  |
+/// | `DEC`    | `0x71` | reg, 0, 0                  |
+/// | `NEG`    | `0x72` | reg, 0, 0                  |
+/// | `BLK`    | `0xd0` | coordinate kinds, 0, count + x, y, z, block string |
+/// | `ENT`    | `0xd1` | 0, 0, count + selector string |
+///
+/// Mnemonics with bit 7 set are extended prefix words.
+/// Field 2 of a prefix word holds the number of trailing
+/// words that follow it, so extended instructions can only
+/// be decoded from a stream with a `Decoder`.
+///
+/// # Arguments
+///
+/// * `op` - The `Opcode` to decode
+///
+/// # Returns
+///
+/// The decoded `Instruction`, or a `DecodeError`
+/// describing which field of the opcode was invalid
+pub fn decode(op: Opcode) -> Result<Instruction, DecodeError> {
+    decode_words(&[op])
+}
+
+/// Decodes an entire stream of opcodes into instructions
+///
+/// # Arguments
+///
+/// * `words` - The opcode stream to decode
+///
+/// # Returns
+///
+/// The decoded instructions, or the first `DecodeError`
+/// encountered in the stream
+pub fn decode_all(words: &[Opcode]) -> Result<Vec<Instruction>, DecodeError> {
+    Decoder::new(words).collect()
+}
+
+/// A streaming decoder that consumes as many
+/// words as each instruction needs
+#[derive(Clone, Debug)]
+pub struct Decoder<'a> {
+    /// The opcode stream being decoded
+    words: &'a [Opcode],
+
+    /// The index of the next word to decode
+    pos: usize,
+}
+
+// implementation
+impl<'a> Decoder<'a> {
+    /// Creates a new `Decoder` instance
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - The opcode stream to decode
+    ///
+    /// # Returns
+    ///
+    /// A new `Decoder` positioned at the start of the stream
+    pub fn new(words: &'a [Opcode]) -> Self {
+        Decoder { words, pos: 0 }
+    }
+
+    /// Gets the index of the next word to be decoded
+    ///
+    /// # Returns
+    ///
+    /// The word index of the next instruction in the stream
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+// Iterator implementation
+impl<'a> Iterator for Decoder<'a> {
+    type Item = Result<Instruction, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // make sure that there are words left
+        let rest = &self.words[self.pos..];
+        let prefix = *rest.first()?;
+
+        // decode the instruction, and then skip past all of its
+        // words (even if it was invalid) so that decoding can resume
+        let len = (1 + format::trailing_words(prefix)).min(rest.len());
+        let result = decode_words(&rest[..len]);
+        self.pos += len;
+        Some(result)
+    }
+}
+
+/// [Internal use only]
+/// Decodes the words of a single instruction
+///
+/// # Arguments
+///
+/// * `words` - The words of the instruction, starting with its first word
+///
+/// # Returns
+///
+/// The decoded `Instruction`, or a `DecodeError` if it is invalid
+fn decode_words(words: &[Opcode]) -> Result<Instruction, DecodeError> {
+    // make sure that the whole instruction is present
+    let op = words[0];
+    let trailing = format::trailing_words(op);
+    if words.len() < trailing + 1 {
+        return Err(DecodeError::with_reason(
+            op,
+            &format!(
+                "truncated stream: expected {} trailing word(s), found {}",
+                trailing,
+                words.len() - 1
+            ),
+        ));
+    }
+
+    match op.mnemonic_field() {
+        format::MOV_REG => {
+            check_padding(op, 2)?;
+            let dest = register_field(op, 0, "destination")?;
+            let src = register_field(op, 1, "source")?;
+            Ok(MOVData::from_register(src, dest).into())
+        }
+        format::MOV_LIT => {
+            let dest = register_field(op, 0, "destination")?;
+            Ok(MOVData::from_literal(op.short_field() as u32, dest).into())
+        }
+        format::MOV_LIT_EXT => {
+            check_trailing(op, 1)?;
+            check_padding(op, 1)?;
+            let dest = register_field(op, 0, "destination")?;
+            Ok(MOVData::from_literal(words[1].value(), dest).into())
+        }
+        format::EXG => {
+            check_padding(op, 2)?;
+            let arg0 = register_field(op, 0, "first argument")?;
+            let arg1 = register_field(op, 1, "second argument")?;
+            Ok(EXGData::new(arg0, arg1).into())
+        }
+        format::ADD | format::ADD_LIT | format::ADD_LIT_EXT => {
+            math(words, ADDData::from_register, ADDData::from_literal)
+        }
+        format::SUB | format::SUB_LIT | format::SUB_LIT_EXT => {
+            math(words, SUBData::from_register, SUBData::from_literal)
+        }
+        format::MUL | format::MUL_LIT | format::MUL_LIT_EXT => {
+            math(words, MULData::from_register, MULData::from_literal)
+        }
+        format::DIV | format::DIV_LIT | format::DIV_LIT_EXT => {
+            math(words, DIVData::from_register, DIVData::from_literal)
+        }
+        format::MOD | format::MOD_LIT | format::MOD_LIT_EXT => {
+            math(words, MODData::from_register, MODData::from_literal)
+        }
+        format::MIN | format::MIN_LIT | format::MIN_LIT_EXT => {
+            math(words, MINData::from_register, MINData::from_literal)
+        }
+        format::MAX | format::MAX_LIT | format::MAX_LIT_EXT => {
+            math(words, MAXData::from_register, MAXData::from_literal)
+        }
+        format::AND | format::AND_LIT | format::AND_LIT_EXT => {
+            math(words, ANDData::from_register, ANDData::from_literal)
+        }
+        format::OR | format::OR_LIT | format::OR_LIT_EXT => {
+            math(words, ORData::from_register, ORData::from_literal)
+        }
+        format::XOR | format::XOR_LIT | format::XOR_LIT_EXT => {
+            math(words, XORData::from_register, XORData::from_literal)
+        }
+        format::NOT => {
+            check_padding(op, 2)?;
+            let arg0 = register_field(op, 0, "first argument")?;
+            let dest = register_field(op, 1, "destination")?;
+            Ok(NOTData::new(arg0, dest).into())
+        }
+        format::SHL => shift(op, SHLData::MAX_AMOUNT, SHLData::new),
+        format::SHR => shift(op, SHRData::MAX_AMOUNT, SHRData::new),
+        format::PUSH => {
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(PUSHData::new(register_field(op, 0, "source")?).into())
+        }
+        format::POP => {
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(POPData::new(register_field(op, 0, "destination")?).into())
+        }
+        format::INC => {
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(INCData::new(register_field(op, 0, "target")?).into())
+        }
+        format::DEC => {
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(DECData::new(register_field(op, 0, "target")?).into())
+        }
+        format::NEG => {
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(NEGData::new(register_field(op, 0, "target")?).into())
+        }
+        format::CHK => {
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(CHKData::new(register_field(op, 0, "checked")?).into())
+        }
+        format::CMP => {
+            check_padding(op, 2)?;
+            let arg0 = register_field(op, 0, "first argument")?;
+            let arg1 = register_field(op, 1, "second argument")?;
+            Ok(CMPData::new(arg0, arg1).into())
+        }
+        format::CMP_LIT => {
+            let arg0 = register_field(op, 0, "first argument")?;
+            Ok(CMPData::from_literal(arg0, op.short_field() as u32).into())
+        }
+        format::CMP_LIT_EXT => {
+            check_trailing(op, 1)?;
+            check_padding(op, 1)?;
+            let arg0 = register_field(op, 0, "first argument")?;
+            Ok(CMPData::from_literal(arg0, words[1].value()).into())
+        }
+        format::JMP => Ok(JMPData::new(op.wide_field()).into()),
+        format::JS => {
+            let flag = flag_field(op, 0)?;
+            Ok(JSData::new(flag, op.short_field() as u32).into())
+        }
+        format::JNS => {
+            let flag = flag_field(op, 0)?;
+            Ok(JNSData::new(flag, op.short_field() as u32).into())
+        }
+        format::JCC => {
+            let val = op.operand_field(0);
+            let cond = Condition::try_from(val).map_err(|_| {
+                DecodeError::with_reason(
+                    op,
+                    &format!("condition field (field 0) holds invalid condition {:#04x}", val),
+                )
+            })?;
+            Ok(JCCData::new(cond, op.short_field() as u32).into())
+        }
+        format::CALL => Ok(CALLData::new(op.wide_field()).into()),
+        format::RET => {
+            check_padding(op, 0)?;
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(RETData::new().into())
+        }
+        format::HLT => {
+            check_padding(op, 0)?;
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(HLTData::new().into())
+        }
+        format::NOP => {
+            check_padding(op, 0)?;
+            check_padding(op, 1)?;
+            check_padding(op, 2)?;
+            Ok(NOPData::new().into())
+        }
+        format::BLK => {
+            check_padding(op, 1)?;
+            let pos = coordinates(op, &words[1..])?;
+            let block_str = string(op, &words[1..], 3)?;
+            let block = block_str.parse().map_err(|_| {
+                DecodeError::with_reason(op, &format!("invalid block state `{}`", block_str))
+            })?;
+            let data = BLKData::new(pos[0], pos[1], pos[2], block);
+            if !data.has_valid_coordinates() {
+                return Err(DecodeError::with_reason(
+                    op,
+                    "local coordinates cannot be mixed with other coordinates",
+                ));
+            }
+            Ok(data.into())
+        }
+        format::ENT => {
+            check_padding(op, 0)?;
+            check_padding(op, 1)?;
+            let sel_str = string(op, &words[1..], 0)?;
+            let selector = sel_str.parse().map_err(|_| {
+                DecodeError::with_reason(op, &format!("invalid selector `{}`", sel_str))
+            })?;
+            Ok(ENTData::new(selector).into())
+        }
+        m => Err(DecodeError::with_reason(
+            op,
+            &format!("unknown mnemonic {:#04x}", m),
+        )),
+    }
+}
+
+/// [Internal use only]
+/// Decodes a register operand field
+///
+/// # Arguments
+///
+/// * `op` - The `Opcode` being decoded
+/// * `index` - The index of the operand field holding the register
+/// * `name` - The role of the register, used in error messages
+///
+/// # Returns
+///
+/// The decoded `Register`, or a `DecodeError` if the field is invalid
+fn register_field(op: Opcode, index: usize, name: &str) -> Result<Register, DecodeError> {
+    let val = op.operand_field(index);
+    Register::try_from(val).map_err(|_| {
+        // the stack and swap registers are never valid operands
+        let kind = match val {
+            0x10 | 0x11 => "reserved",
+            _ => "invalid",
+        };
+        DecodeError::with_reason(
+            op,
+            &format!(
+                "{} register field (field {}) holds {} register {:#04x}",
+                name, index, kind, val
+            ),
+        )
+    })
+}
+
+/// [Internal use only]
+/// Decodes a flag operand field
+///
+/// # Arguments
+///
+/// * `op` - The `Opcode` being decoded
+/// * `index` - The index of the operand field holding the flag
+///
+/// # Returns
+///
+/// The decoded `Flag`, or a `DecodeError` if the field is invalid
+fn flag_field(op: Opcode, index: usize) -> Result<Flag, DecodeError> {
+    let val = op.operand_field(index);
+    Flag::try_from(val).map_err(|_| {
+        DecodeError::with_reason(
+            op,
+            &format!("flag field (field {}) holds invalid flag index {:#04x}", index, val),
+        )
+    })
+}
+
+/// [Internal use only]
+/// Decodes one of the math instructions in any of its forms
+///
+/// # Arguments
+///
+/// * `words` - The words of the instruction
+/// * `from_register` - Builds the instruction from its
+///   first argument, second argument, and destination registers
+/// * `from_literal` - Builds the instruction from its first
+///   argument register, literal, and destination register
+///
+/// # Returns
+///
+/// The decoded `Instruction`, or a `DecodeError` if it is invalid
+fn math<T: Into<Instruction>>(
+    words: &[Opcode],
+    from_register: fn(Register, Register, Register) -> T,
+    from_literal: fn(Register, u32, Register) -> T,
+) -> Result<Instruction, DecodeError> {
+    let op = words[0];
+    let mnemonic = op.mnemonic_field();
+    let arg0 = register_field(op, 0, "first argument")?;
+    if mnemonic & format::EXTENDED != 0 {
+        check_trailing(op, 1)?;
+        let dest = register_field(op, 1, "destination")?;
+        Ok(from_literal(arg0, words[1].value(), dest).into())
+    } else if mnemonic & format::LITERAL != 0 {
+        let dest = register_field(op, 1, "destination")?;
+        Ok(from_literal(arg0, op.operand_field(2) as u32, dest).into())
+    } else {
+        let arg1 = register_field(op, 1, "second argument")?;
+        let dest = register_field(op, 2, "destination")?;
+        Ok(from_register(arg0, arg1, dest).into())
+    }
+}
+
+/// [Internal use only]
+/// Decodes a shift instruction
+///
+/// # Arguments
+///
+/// * `op` - The `Opcode` being decoded
+/// * `max` - The largest valid shift amount
+/// * `new` - The constructor for the instruction's data
+///
+/// # Returns
+///
+/// The decoded `Instruction`, or a `DecodeError`
+/// if the shift amount is out of range
+fn shift<T: Into<Instruction>>(
+    op: Opcode,
+    max: u32,
+    new: fn(Register, u32, Register) -> T,
+) -> Result<Instruction, DecodeError> {
+    let arg0 = register_field(op, 0, "first argument")?;
+    let dest = register_field(op, 1, "destination")?;
+    let amount = op.operand_field(2) as u32;
+    if amount > max {
+        return Err(DecodeError::with_reason(
+            op,
+            &format!("shift amount field (field 2) holds {}, but at most {} is allowed", amount, max),
+        ));
+    }
+    Ok(new(arg0, amount, dest).into())
+}
+
+/// [Internal use only]
+/// Decodes the coordinates of an extended instruction
+///
+/// # Arguments
+///
+/// * `op` - The prefix word being decoded, whose field 0 holds the coordinate kinds
+/// * `trailing` - The trailing words, starting with the x coordinate
+///
+/// # Returns
+///
+/// The x, y, and z coordinates, or a `DecodeError` if they are invalid
+fn coordinates(op: Opcode, trailing: &[Opcode]) -> Result<[Coordinate; 3], DecodeError> {
+    let kinds = op.operand_field(0);
+    if kinds & 0xC0 != 0 {
+        return Err(DecodeError::with_reason(
+            op,
+            &format!("coordinate field (field 0) has nonzero upper bits ({:#04x})", kinds),
+        ));
+    }
+    if trailing.len() < 3 {
+        return Err(DecodeError::with_reason(
+            op,
+            &format!("trailing word count is {} (expected at least 3)", trailing.len()),
+        ));
+    }
+
+    let mut pos = [Coordinate::Absolute(0); 3];
+    for axis in 0..3 {
+        let word = trailing[axis].value();
+        pos[axis] = match (kinds >> (4 - 2 * axis)) & 0x3 {
+            format::COORD_ABSOLUTE => Coordinate::Absolute(word as i32),
+            format::COORD_RELATIVE => Coordinate::Relative(word as i32),
+            format::COORD_LOCAL => Coordinate::Local(word as i32),
+            _ => {
+                let reg = u8::try_from(word)
+                    .ok()
+                    .and_then(|val| Register::try_from(val).ok())
+                    .filter(|reg| u8::try_from(*reg).is_ok());
+                match reg {
+                    Some(reg) => Coordinate::Register(reg),
+                    None => {
+                        return Err(DecodeError::with_reason(
+                            op,
+                            &format!("coordinate word {} holds invalid register {:#x}", axis, word),
+                        ))
+                    }
+                }
+            }
+        };
+    }
+    Ok(pos)
+}
+
+/// [Internal use only]
+/// Decodes a string stored in the trailing words of an extended instruction
+///
+/// The string must fill the rest of the instruction.
+///
+/// # Arguments
+///
+/// * `op` - The prefix word being decoded
+/// * `trailing` - The trailing words of the instruction
+/// * `start` - The index of the trailing word holding the string's length
+///
+/// # Returns
+///
+/// The decoded string, or a `DecodeError` if it is invalid
+fn string(op: Opcode, trailing: &[Opcode], start: usize) -> Result<String, DecodeError> {
+    // make sure that the string's words are all present
+    let len = match trailing.get(start) {
+        Some(word) => word.value() as usize,
+        None => {
+            return Err(DecodeError::with_reason(
+                op,
+                &format!(
+                    "trailing word count is {} (expected at least {})",
+                    trailing.len(),
+                    start + 1
+                ),
+            ))
+        }
+    };
+    check_trailing(op, start + 1 + len.div_ceil(4))?;
+
+    // unpack the bytes, which must be followed only by zero padding
+    let mut bytes: Vec<u8> = trailing[start + 1..]
+        .iter()
+        .flat_map(|word| word.value().to_be_bytes())
+        .collect();
+    if bytes[len..].iter().any(|b| *b != 0) {
+        return Err(DecodeError::with_reason(op, "string padding bytes are nonzero"));
+    }
+    bytes.truncate(len);
+    String::from_utf8(bytes)
+        .map_err(|_| DecodeError::with_reason(op, "string is not valid UTF-8"))
+}
+
+/// [Internal use only]
+/// Ensures that an extended instruction has the expected number of trailing words
+///
+/// # Arguments
+///
+/// * `op` - The prefix word being decoded
+/// * `expected` - The number of trailing words the instruction needs
+///
+/// # Returns
+///
+/// Nothing, or a `DecodeError` if the trailing word count is wrong
+fn check_trailing(op: Opcode, expected: usize) -> Result<(), DecodeError> {
+    match format::trailing_words(op) {
+        n if n == expected => Ok(()),
+        n => Err(DecodeError::with_reason(
+            op,
+            &format!(
+                "trailing word count is {} (expected {})",
+                n, expected
+            ),
+        )),
+    }
+}
+
+/// [Internal use only]
+/// Ensures that an unused operand field is zero
+///
+/// # Arguments
+///
+/// * `op` - The `Opcode` being decoded
+/// * `index` - The index of the unused operand field
+///
+/// # Returns
+///
+/// Nothing, or a `DecodeError` if the field holds nonzero padding bits
+fn check_padding(op: Opcode, index: usize) -> Result<(), DecodeError> {
+    match op.operand_field(index) {
+        0 => Ok(()),
+        val => Err(DecodeError::with_reason(
+            op,
+            &format!("padding field (field {}) is nonzero ({:#04x})", index, val),
+        )),
+    }
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the function into scope
+    use super::*;
+
+    // tests decoding valid opcodes
+    #[test]
+    fn test_decode_valid() {
+        assert_eq!(
+            decode(0x01000100.into()).unwrap(),
+            MOVData::from_register(Register::R1, Register::R0).into()
+        );
+        assert_eq!(
+            decode(0x02030032.into()).unwrap(),
+            MOVData::from_literal(0x32, Register::R3).into()
+        );
+        assert_eq!(
+            decode(0x03040500.into()).unwrap(),
+            EXGData::new(Register::R4, Register::R5).into()
+        );
+        assert_eq!(
+            decode(0x10000102.into()).unwrap(),
+            ADDData::new(Register::R0, Register::R1, Register::R2).into()
+        );
+        assert_eq!(
+            decode(0x210f0000.into()).unwrap(),
+            POPData::new(Register::R15).into()
+        );
+        assert_eq!(
+            decode(0x40123456.into()).unwrap(),
+            JMPData::new(0x123456).into()
+        );
+        assert_eq!(
+            decode(0x42020007.into()).unwrap(),
+            JNSData::new(Flag::Equal, 7).into()
+        );
+    }
+
+    // tests decoding a block check
+    #[test]
+    fn test_decode_blk() {
+        let words: Vec<Opcode> = vec![
+            0xD0070008.into(),
+            0x00000001.into(),
+            0xFFFFFFFF.into(),
+            0x00000002.into(),
+            0x0000000F.into(),
+            0x6D696E65.into(),
+            0x63726166.into(),
+            0x743A7374.into(),
+            0x6F6E6500.into(),
+        ];
+        assert_eq!(
+            decode_all(&words).unwrap(),
+            vec![BLKData::new(
+                Coordinate::Absolute(1),
+                Coordinate::Relative(-1),
+                Coordinate::Register(Register::R2),
+                BlockState::new("stone"),
+            )
+            .into()]
+        );
+
+        // corrupt the string padding, the length, and then a register
+        let mut bad = words.clone();
+        bad[8] = 0x6F6E6501.into();
+        assert_eq!(
+            decode_all(&bad).unwrap_err().reason().unwrap(),
+            "string padding bytes are nonzero"
+        );
+        let mut bad = words.clone();
+        bad[4] = 0x00000014.into();
+        assert_eq!(
+            decode_all(&bad).unwrap_err().reason().unwrap(),
+            "trailing word count is 8 (expected 9)"
+        );
+        let mut bad = words;
+        bad[3] = 0x00000010.into();
+        assert_eq!(
+            decode_all(&bad).unwrap_err().reason().unwrap(),
+            "coordinate word 2 holds invalid register 0x10"
+        );
+    }
+
+    // tests decoding an entity check
+    #[test]
+    fn test_decode_ent() {
+        let words: Vec<Opcode> = vec![0xD1000002.into(), 0x00000002.into(), 0x40700000.into()];
+        assert_eq!(
+            decode_all(&words).unwrap(),
+            vec![ENTData::new(Selector::new(SelectorKind::NearestPlayer)).into()]
+        );
+
+        let bad: Vec<Opcode> = vec![0xD1000002.into(), 0x00000002.into(), 0x40780000.into()];
+        assert_eq!(
+            decode_all(&bad).unwrap_err().reason().unwrap(),
+            "invalid selector `@x`"
+        );
+    }
+
+    // tests decoding literal second arguments
+    #[test]
+    fn test_decode_literal_forms() {
+        let words: Vec<Opcode> = vec![
+            0x19050507.into(),
+            0x9A000101.into(),
+            0xFFFFFFFF.into(),
+            0x3902FFFF.into(),
+        ];
+        assert_eq!(
+            decode_all(&words).unwrap(),
+            vec![
+                SUBData::from_literal(Register::R5, 7, Register::R5).into(),
+                MULData::from_literal(Register::R0, 0xFFFFFFFF, Register::R1).into(),
+                CMPData::from_literal(Register::R2, 0xFFFF).into(),
+            ]
+        );
+        assert_eq!(
+            decode(0x16010203.into()).unwrap(),
+            MAXData::new(Register::R1, Register::R2, Register::R3).into()
+        );
+        assert_eq!(
+            decode(0x1C0405FF.into()).unwrap(),
+            MODData::from_literal(Register::R4, 0xFF, Register::R5).into()
+        );
+        assert_eq!(
+            decode(0x18001000.into()).unwrap_err().reason().unwrap(),
+            "destination register field (field 1) holds reserved register 0x10"
+        );
+    }
+
+    // tests decoding the bitwise instructions
+    #[test]
+    fn test_decode_bitwise() {
+        assert_eq!(
+            decode(0x61000102.into()).unwrap(),
+            ORData::new(Register::R0, Register::R1, Register::R2).into()
+        );
+        assert_eq!(
+            decode(0x6A030480.into()).unwrap(),
+            XORData::from_literal(Register::R3, 0x80, Register::R4).into()
+        );
+        assert_eq!(
+            decode(0x64050601.into()).unwrap(),
+            SHLData::new(Register::R5, 1, Register::R6).into()
+        );
+        assert_eq!(
+            decode(0x65050620.into()).unwrap_err().reason().unwrap(),
+            "shift amount field (field 2) holds 32, but at most 31 is allowed"
+        );
+        assert_eq!(
+            decode(0x63000101.into()).unwrap_err().reason().unwrap(),
+            "padding field (field 2) is nonzero (0x01)"
+        );
+    }
+
+    // tests decoding subroutine calls, returns, halts and no-ops
+    #[test]
+    fn test_decode_call_ret() {
+        assert_eq!(decode(0x43000105.into()).unwrap(), CALLData::new(0x105).into());
+        assert_eq!(decode(0x44000000.into()).unwrap(), RETData::new().into());
+        assert_eq!(decode(0x45000000.into()).unwrap(), HLTData::new().into());
+        assert_eq!(decode(0x00000000.into()).unwrap(), NOPData::new().into());
+        assert_eq!(
+            decode(0x44010000.into()).unwrap_err().reason().unwrap(),
+            "padding field (field 0) is nonzero (0x01)"
+        );
+    }
+
+    // tests decoding the single-register arithmetic instructions
+    #[test]
+    fn test_decode_inc_dec_neg() {
+        assert_eq!(decode(0x70030000.into()).unwrap(), INCData::new(Register::R3).into());
+        assert_eq!(decode(0x710F0000.into()).unwrap(), DECData::new(Register::R15).into());
+        assert_eq!(decode(0x72000000.into()).unwrap(), NEGData::new(Register::R0).into());
+        assert_eq!(
+            decode(0x70100000.into()).unwrap_err().reason().unwrap(),
+            "target register field (field 0) holds reserved register 0x10"
+        );
+    }
+
+    // tests decoding condition-code jumps
+    #[test]
+    fn test_decode_jcc() {
+        assert_eq!(
+            decode(0x46030010.into()).unwrap(),
+            JCCData::new(Condition::GreaterOrEqual, 0x10).into()
+        );
+        assert_eq!(
+            decode(0x4607FFFF.into()).unwrap(),
+            JCCData::new(Condition::NotZero, 0xFFFF).into()
+        );
+        assert_eq!(
+            decode(0x46080000.into()).unwrap_err().reason().unwrap(),
+            "condition field (field 0) holds invalid condition 0x08"
+        );
+    }
+
+    // tests decoding an unknown mnemonic
+    #[test]
+    fn test_decode_unknown_mnemonic() {
+        let err = decode(0xff000000.into()).unwrap_err();
+        assert_eq!(err.op(), 0xff000000.into());
+        assert_eq!(err.reason().unwrap(), "unknown mnemonic 0xff");
+    }
+
+    // tests decoding reserved and invalid registers
+    #[test]
+    fn test_decode_bad_register() {
+        let err1 = decode(0x10001002.into()).unwrap_err();
+        let err2 = decode(0x20110000.into()).unwrap_err();
+        let err3 = decode(0x30120000.into()).unwrap_err();
+        assert_eq!(
+            err1.reason().unwrap(),
+            "second argument register field (field 1) holds reserved register 0x10"
+        );
+        assert_eq!(
+            err2.reason().unwrap(),
+            "source register field (field 0) holds reserved register 0x11"
+        );
+        assert_eq!(
+            err3.reason().unwrap(),
+            "checked register field (field 0) holds invalid register 0x12"
+        );
+    }
+
+    // tests decoding an invalid flag
+    #[test]
+    fn test_decode_bad_flag() {
+        let err = decode(0x41070000.into()).unwrap_err();
+        assert_eq!(
+            err.reason().unwrap(),
+            "flag field (field 0) holds invalid flag index 0x07"
+        );
+    }
+
+    // tests decoding extended instructions from a stream
+    #[test]
+    fn test_decode_stream() {
+        let words: Vec<Opcode> = vec![
+            0x82000001.into(),
+            0xdeadbeef.into(),
+            0x20010000.into(),
+        ];
+        let mut decoder = Decoder::new(&words);
+        assert_eq!(
+            decoder.next().unwrap().unwrap(),
+            MOVData::from_literal(0xdeadbeef, Register::R0).into()
+        );
+        assert_eq!(decoder.position(), 2);
+        assert_eq!(
+            decoder.next().unwrap().unwrap(),
+            PUSHData::new(Register::R1).into()
+        );
+        assert!(decoder.next().is_none());
+        assert_eq!(decode_all(&words).unwrap().len(), 2);
+    }
+
+    // tests decoding truncated streams
+    #[test]
+    fn test_decode_truncated() {
+        let words: Vec<Opcode> = vec![0x20010000.into(), 0x82000001.into()];
+        let err1 = decode_all(&words).unwrap_err();
+        let err2 = decode(0x82000001.into()).unwrap_err();
+        assert_eq!(err1.op(), 0x82000001.into());
+        assert_eq!(
+            err1.reason().unwrap(),
+            "truncated stream: expected 1 trailing word(s), found 0"
+        );
+        assert_eq!(err2.reason(), err1.reason());
+    }
+
+    // tests that decoding resumes after an invalid instruction
+    #[test]
+    fn test_decode_resumes() {
+        let words: Vec<Opcode> = vec![
+            0x82000002.into(),
+            0x00000000.into(),
+            0x00000000.into(),
+            0x20010000.into(),
+        ];
+        let results: Vec<_> = Decoder::new(&words).collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].clone().unwrap_err().reason().unwrap(),
+            "trailing word count is 2 (expected 1)"
+        );
+        assert!(results[1].is_ok());
+    }
+
+    // tests decoding nonzero padding
+    #[test]
+    fn test_decode_bad_padding() {
+        let err1 = decode(0x01000101.into()).unwrap_err();
+        let err2 = decode(0x20000100.into()).unwrap_err();
+        assert_eq!(
+            err1.reason().unwrap(),
+            "padding field (field 2) is nonzero (0x01)"
+        );
+        assert_eq!(
+            err2.reason().unwrap(),
+            "padding field (field 1) is nonzero (0x01)"
+        );
+    }
+}
+
+// end of file
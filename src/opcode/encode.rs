@@ -0,0 +1,652 @@
+/*
+ * encode.rs
+ * Defines a function that encodes instructions into opcodes
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// usage statements
+use super::super::cpu::Register;
+use super::super::data::Coordinate;
+use super::super::data::Instruction;
+use super::super::data::SHLData;
+use super::super::data::SHRData;
+use super::super::error::EncodeError;
+use super::format;
+use super::Opcode;
+use std::convert::TryFrom;
+
+/// Encodes an `Instruction` into one or more `Opcode` words
+///
+/// This is the inverse of `Decoder`, and uses the same
+/// opcode layout. Literals that fit in the single-word form
+/// of their instruction use it: 16 bits for `MOV` and `CMP`,
+/// and 8 bits for the arithmetic and bitwise instructions.
+/// Larger literals use the extended form, where a prefix word
+/// is followed by a trailing word holding the literal.
+///
+/// # Arguments
+///
+/// * `instr` - The `Instruction` to encode
+///
+/// # Returns
+///
+/// The encoded `Opcode` words, or an `EncodeError` if
+/// one of the instruction's operands cannot be
+/// represented in its field
+pub fn encode(instr: &Instruction) -> Result<Vec<Opcode>, EncodeError> {
+    let words = match instr {
+        Instruction::Mov(d) => {
+            let dest = register_field(instr, d.dest(), "destination")?;
+            match d.src_literal() {
+                Some(lit) if lit <= 0xFFFF => vec![pack(format::MOV_LIT, dest, 0, 0) | lit],
+                Some(lit) => vec![pack(format::MOV_LIT_EXT, dest, 0, 1), lit],
+                None => {
+                    let src = register_field(instr, d.unwrap_src_register(), "source")?;
+                    vec![pack(format::MOV_REG, dest, src, 0)]
+                }
+            }
+        }
+        Instruction::Exg(d) => {
+            let arg0 = register_field(instr, d.arg0(), "first argument")?;
+            let arg1 = register_field(instr, d.arg1(), "second argument")?;
+            vec![pack(format::EXG, arg0, arg1, 0)]
+        }
+        Instruction::Add(d) => math_words(instr, format::ADD, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Sub(d) => math_words(instr, format::SUB, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Mul(d) => math_words(instr, format::MUL, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Div(d) => math_words(instr, format::DIV, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Mod(d) => math_words(instr, format::MOD, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Min(d) => math_words(instr, format::MIN, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Max(d) => math_words(instr, format::MAX, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::And(d) => math_words(instr, format::AND, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Or(d) => math_words(instr, format::OR, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Xor(d) => math_words(instr, format::XOR, d.arg0(), d.arg1_literal(), d.arg1_register(), d.dest())?,
+        Instruction::Not(d) => {
+            let arg0 = register_field(instr, d.arg0(), "first argument")?;
+            let dest = register_field(instr, d.dest(), "destination")?;
+            vec![pack(format::NOT, arg0, dest, 0)]
+        }
+        Instruction::Shl(d) => shift_words(instr, format::SHL, d.arg0(), d.amount(), SHLData::MAX_AMOUNT, d.dest())?,
+        Instruction::Shr(d) => shift_words(instr, format::SHR, d.arg0(), d.amount(), SHRData::MAX_AMOUNT, d.dest())?,
+        Instruction::Inc(d) => vec![pack(format::INC, register_field(instr, d.arg0(), "target")?, 0, 0)],
+        Instruction::Dec(d) => vec![pack(format::DEC, register_field(instr, d.arg0(), "target")?, 0, 0)],
+        Instruction::Neg(d) => vec![pack(format::NEG, register_field(instr, d.arg0(), "target")?, 0, 0)],
+        Instruction::Push(d) => {
+            let reg = register_field(instr, d.arg0(), "source")?;
+            vec![pack(format::PUSH, reg, 0, 0)]
+        }
+        Instruction::Pop(d) => {
+            let reg = register_field(instr, d.arg0(), "destination")?;
+            vec![pack(format::POP, reg, 0, 0)]
+        }
+        Instruction::Chk(d) => {
+            let reg = register_field(instr, d.chk_reg(), "checked")?;
+            vec![pack(format::CHK, reg, 0, 0)]
+        }
+        Instruction::Cmp(d) => {
+            let arg0 = register_field(instr, d.arg0(), "first argument")?;
+            match d.arg1_literal() {
+                Some(lit) if lit <= 0xFFFF => vec![pack(format::CMP_LIT, arg0, 0, 0) | lit],
+                Some(lit) => vec![pack(format::CMP_LIT_EXT, arg0, 0, 1), lit],
+                None => {
+                    let arg1 = register_field(instr, d.arg1(), "second argument")?;
+                    vec![pack(format::CMP, arg0, arg1, 0)]
+                }
+            }
+        }
+        Instruction::Jmp(d) => {
+            let id = d.jmp_label_id();
+            if id > 0x00FFFFFF {
+                return Err(EncodeError::with_reason(
+                    instr.clone(),
+                    &format!("label ID {} does not fit in 24 bits", id),
+                ));
+            }
+            vec![pack(format::JMP, 0, 0, 0) | id]
+        }
+        Instruction::Js(d) => {
+            let id = short_field(instr, d.jmp_label_id(), "label ID")?;
+            vec![pack(format::JS, d.cond_flag().into(), 0, 0) | id]
+        }
+        Instruction::Jns(d) => {
+            let id = short_field(instr, d.jmp_label_id(), "label ID")?;
+            vec![pack(format::JNS, d.cond_flag().into(), 0, 0) | id]
+        }
+        Instruction::Jcc(d) => {
+            let id = short_field(instr, d.jmp_label_id(), "label ID")?;
+            vec![pack(format::JCC, d.condition().into(), 0, 0) | id]
+        }
+        Instruction::Call(d) => {
+            let id = d.jmp_label_id();
+            if id > 0x00FFFFFF {
+                return Err(EncodeError::with_reason(
+                    instr.clone(),
+                    &format!("label ID {} does not fit in 24 bits", id),
+                ));
+            }
+            vec![pack(format::CALL, 0, 0, 0) | id]
+        }
+        Instruction::Ret(_) => vec![pack(format::RET, 0, 0, 0)],
+        Instruction::Hlt(_) => vec![pack(format::HLT, 0, 0, 0)],
+        Instruction::Nop(_) => vec![pack(format::NOP, 0, 0, 0)],
+        Instruction::Blk(d) => {
+            if !d.has_valid_coordinates() {
+                return Err(EncodeError::with_reason(
+                    instr.clone(),
+                    "local coordinates cannot be mixed with other coordinates",
+                ));
+            }
+            let (kinds, mut trailing) = coordinate_words(instr, d.coordinates())?;
+            trailing.extend(string_words(&d.block().to_string()));
+            extended_words(instr, format::BLK, kinds, 0, trailing)?
+        }
+        Instruction::Ent(d) => {
+            let trailing = string_words(&d.selector().to_string());
+            extended_words(instr, format::ENT, 0, 0, trailing)?
+        }
+    };
+
+    Ok(words.into_iter().map(Opcode::from).collect())
+}
+
+/// Encodes a sequence of instructions into a single opcode stream
+///
+/// # Arguments
+///
+/// * `instrs` - The instructions to encode
+///
+/// # Returns
+///
+/// The concatenated `Opcode` words of every instruction,
+/// or the first `EncodeError` encountered
+pub fn encode_all(instrs: &[Instruction]) -> Result<Vec<Opcode>, EncodeError> {
+    let mut words = Vec::new();
+    for instr in instrs {
+        words.extend(encode(instr)?);
+    }
+    Ok(words)
+}
+
+/// [Internal use only]
+/// Packs a mnemonic and three operand fields into a word
+///
+/// # Arguments
+///
+/// * `mnemonic` - The mnemonic field
+/// * `f0` - Operand field 0
+/// * `f1` - Operand field 1
+/// * `f2` - Operand field 2
+///
+/// # Returns
+///
+/// The packed word
+fn pack(mnemonic: u8, f0: u8, f1: u8, f2: u8) -> u32 {
+    u32::from_be_bytes([mnemonic, f0, f1, f2])
+}
+
+/// [Internal use only]
+/// Encodes a register operand
+///
+/// # Arguments
+///
+/// * `instr` - The `Instruction` being encoded
+/// * `reg` - The `Register` to encode
+/// * `name` - The role of the register, used in error messages
+///
+/// # Returns
+///
+/// The field value for the register, or an `EncodeError`
+/// if the register cannot be used as an operand
+fn register_field(instr: &Instruction, reg: Register, name: &str) -> Result<u8, EncodeError> {
+    u8::try_from(reg).map_err(|_| {
+        EncodeError::with_reason(
+            instr.clone(),
+            &format!("{} cannot be used as the {} register", reg, name),
+        )
+    })
+}
+
+/// [Internal use only]
+/// Encodes a value into the 16-bit short field
+///
+/// # Arguments
+///
+/// * `instr` - The `Instruction` being encoded
+/// * `val` - The value to encode
+/// * `name` - The role of the value, used in error messages
+///
+/// # Returns
+///
+/// The value, or an `EncodeError` if it does not fit in 16 bits
+fn short_field(instr: &Instruction, val: u32, name: &str) -> Result<u32, EncodeError> {
+    if val > 0xFFFF {
+        Err(EncodeError::with_reason(
+            instr.clone(),
+            &format!("{} {} does not fit in 16 bits", name, val),
+        ))
+    } else {
+        Ok(val)
+    }
+}
+
+/// [Internal use only]
+/// Encodes one of the math instructions
+///
+/// Literals that fit in 8 bits use the single-word literal
+/// form, and larger literals use the extended literal form.
+///
+/// # Arguments
+///
+/// * `instr` - The `Instruction` being encoded
+/// * `mnemonic` - The mnemonic field for the register form of the instruction
+/// * `arg0` - The first argument `Register`
+/// * `lit` - The second argument, if it is a literal
+/// * `reg` - The second argument, if it is a register
+/// * `dest` - The destination `Register`
+///
+/// # Returns
+///
+/// The encoded words, or an `EncodeError` if a register is invalid
+fn math_words(
+    instr: &Instruction,
+    mnemonic: u8,
+    arg0: Register,
+    lit: Option<u32>,
+    reg: Option<Register>,
+    dest: Register,
+) -> Result<Vec<u32>, EncodeError> {
+    let arg0 = register_field(instr, arg0, "first argument")?;
+    let dest = register_field(instr, dest, "destination")?;
+    let words = match (lit, reg) {
+        (Some(lit), _) if lit <= 0xFF => vec![pack(mnemonic | format::LITERAL, arg0, dest, lit as u8)],
+        (Some(lit), _) => vec![pack(mnemonic | format::LITERAL | format::EXTENDED, arg0, dest, 1), lit],
+        (None, Some(reg)) => {
+            let arg1 = register_field(instr, reg, "second argument")?;
+            vec![pack(mnemonic, arg0, arg1, dest)]
+        }
+        (None, None) => unreachable!("math instruction has no second argument"),
+    };
+    Ok(words)
+}
+
+/// [Internal use only]
+/// Encodes one of the shift instructions
+///
+/// # Arguments
+///
+/// * `instr` - The `Instruction` being encoded
+/// * `mnemonic` - The mnemonic field for the instruction
+/// * `arg0` - The `Register` being shifted
+/// * `amount` - The number of bits to shift by
+/// * `max` - The largest valid shift amount
+/// * `dest` - The destination `Register`
+///
+/// # Returns
+///
+/// The encoded words, or an `EncodeError` if a register
+/// is invalid or the shift amount is out of range
+fn shift_words(
+    instr: &Instruction,
+    mnemonic: u8,
+    arg0: Register,
+    amount: u32,
+    max: u32,
+    dest: Register,
+) -> Result<Vec<u32>, EncodeError> {
+    let arg0 = register_field(instr, arg0, "first argument")?;
+    let dest = register_field(instr, dest, "destination")?;
+    if amount > max {
+        return Err(EncodeError::with_reason(
+            instr.clone(),
+            &format!("shift amount {} is out of range (expected 0 to {})", amount, max),
+        ));
+    }
+    Ok(vec![pack(mnemonic, arg0, dest, amount as u8)])
+}
+
+/// [Internal use only]
+/// Encodes a position into its coordinate kinds and trailing words
+///
+/// # Arguments
+///
+/// * `instr` - The `Instruction` being encoded
+/// * `pos` - The x, y, and z coordinates to encode
+///
+/// # Returns
+///
+/// The packed coordinate kinds and one trailing word per axis,
+/// or an `EncodeError` if a coordinate register is invalid
+fn coordinate_words(instr: &Instruction, pos: [Coordinate; 3]) -> Result<(u8, Vec<u32>), EncodeError> {
+    let mut kinds = 0;
+    let mut words = Vec::new();
+    for (axis, coord) in pos.iter().enumerate() {
+        let (kind, word) = match *coord {
+            Coordinate::Absolute(v) => (format::COORD_ABSOLUTE, v as u32),
+            Coordinate::Relative(v) => (format::COORD_RELATIVE, v as u32),
+            Coordinate::Local(v) => (format::COORD_LOCAL, v as u32),
+            Coordinate::Register(r) => (
+                format::COORD_REGISTER,
+                register_field(instr, r, "coordinate")? as u32,
+            ),
+        };
+        kinds |= kind << (4 - 2 * axis);
+        words.push(word);
+    }
+    Ok((kinds, words))
+}
+
+/// [Internal use only]
+/// Encodes a string into trailing words
+///
+/// # Arguments
+///
+/// * `s` - The string to encode
+///
+/// # Returns
+///
+/// A word holding the length of the string in bytes,
+/// followed by its bytes packed into zero-padded words
+fn string_words(s: &str) -> Vec<u32> {
+    let mut words = vec![s.len() as u32];
+    for chunk in s.as_bytes().chunks(4) {
+        let mut bytes = [0; 4];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        words.push(u32::from_be_bytes(bytes));
+    }
+    words
+}
+
+/// [Internal use only]
+/// Builds an extended instruction from its prefix fields and trailing words
+///
+/// # Arguments
+///
+/// * `instr` - The `Instruction` being encoded
+/// * `mnemonic` - The mnemonic field of the prefix word
+/// * `f0` - Operand field 0 of the prefix word
+/// * `f1` - Operand field 1 of the prefix word
+/// * `trailing` - The trailing words
+///
+/// # Returns
+///
+/// The prefix word followed by the trailing words, or an
+/// `EncodeError` if there are too many trailing words to count
+fn extended_words(
+    instr: &Instruction,
+    mnemonic: u8,
+    f0: u8,
+    f1: u8,
+    trailing: Vec<u32>,
+) -> Result<Vec<u32>, EncodeError> {
+    let count = u8::try_from(trailing.len()).map_err(|_| {
+        EncodeError::with_reason(
+            instr.clone(),
+            &format!(
+                "operands take {} trailing words, but at most 255 fit",
+                trailing.len()
+            ),
+        )
+    })?;
+    let mut words = vec![pack(mnemonic, f0, f1, count)];
+    words.extend(trailing);
+    Ok(words)
+}
+
+// start of unit tests
+#[cfg(test)]
+mod tests {
+    // bring the function into scope
+    use super::*;
+    use super::super::super::cpu::Condition;
+    use super::super::super::cpu::Flag;
+    use super::super::super::data::*;
+    use super::super::decode_all;
+
+    // tests encoding into known opcodes
+    #[test]
+    fn test_encode() {
+        let mov = Instruction::from(MOVData::from_literal(0x32, Register::R0));
+        let add = Instruction::from(ADDData::new(Register::R0, Register::R1, Register::R2));
+        let js = Instruction::from(JSData::new(Flag::Negative, 0x1234));
+        assert_eq!(encode(&mov).unwrap(), vec![0x02000032.into()]);
+        assert_eq!(encode(&add).unwrap(), vec![0x10000102.into()]);
+        assert_eq!(encode(&js).unwrap(), vec![0x41011234.into()]);
+    }
+
+    // tests that decoding an encoded instruction yields the original
+    #[test]
+    fn test_round_trip() {
+        let instrs: Vec<Instruction> = vec![
+            MOVData::from_literal(0xFFFF, Register::R0).into(),
+            MOVData::from_literal(0x10000, Register::R1).into(),
+            MOVData::from_literal(0xFFFFFFFF, Register::R2).into(),
+            MOVData::from_register(Register::R15, Register::R7).into(),
+            EXGData::new(Register::R4, Register::R15).into(),
+            ADDData::new(Register::R0, Register::R1, Register::R2).into(),
+            SUBData::new(Register::R3, Register::R4, Register::R5).into(),
+            MULData::new(Register::R6, Register::R7, Register::R8).into(),
+            DIVData::new(Register::R9, Register::R10, Register::R11).into(),
+            PUSHData::new(Register::R12).into(),
+            POPData::new(Register::R13).into(),
+            JMPData::new(0x00FFFFFF).into(),
+            CHKData::new(Register::R14).into(),
+            CMPData::new(Register::R15, Register::R0).into(),
+            ADDData::from_literal(Register::R1, 0xFF, Register::R1).into(),
+            SUBData::from_literal(Register::R2, 0x100, Register::R3).into(),
+            MULData::from_literal(Register::R4, 0, Register::R5).into(),
+            DIVData::from_literal(Register::R6, 0xFFFFFFFF, Register::R7).into(),
+            ANDData::new(Register::R0, Register::R1, Register::R2).into(),
+            ORData::from_literal(Register::R3, 0xFF, Register::R4).into(),
+            XORData::from_literal(Register::R5, 0xFFFFFFFF, Register::R6).into(),
+            NOTData::new(Register::R7, Register::R8).into(),
+            SHLData::new(Register::R9, 31, Register::R10).into(),
+            SHRData::new(Register::R11, 0, Register::R12).into(),
+            CALLData::new(0x00FFFFFF).into(),
+            RETData::new().into(),
+            HLTData::new().into(),
+            NOPData::new().into(),
+            JCCData::new(Condition::GreaterOrEqual, 0xFFFF).into(),
+            JCCData::new(Condition::NotZero, 0).into(),
+            INCData::new(Register::R0).into(),
+            DECData::new(Register::R15).into(),
+            NEGData::new(Register::R7).into(),
+            CMPData::from_literal(Register::R8, 0xFFFF).into(),
+            MODData::new(Register::R0, Register::R1, Register::R2).into(),
+            MODData::from_literal(Register::R3, 7, Register::R4).into(),
+            MINData::new(Register::R5, Register::R6, Register::R7).into(),
+            MINData::from_literal(Register::R8, 0x80000000, Register::R9).into(),
+            MAXData::new(Register::R10, Register::R11, Register::R12).into(),
+            MAXData::from_literal(Register::R13, 0, Register::R14).into(),
+            CMPData::from_literal(Register::R9, 0x10000).into(),
+            JSData::new(Flag::Block, 0xFFFF).into(),
+            JNSData::new(Flag::Entity, 0).into(),
+            BLKData::new(
+                Coordinate::Absolute(-64),
+                Coordinate::Register(Register::R3),
+                Coordinate::Relative(2),
+                BlockState::new("oak_stairs").with_property("half", "top"),
+            )
+            .into(),
+            BLKData::new(
+                Coordinate::Local(0),
+                Coordinate::Local(-1),
+                Coordinate::Local(i32::MIN),
+                BlockState::new("mod:ore"),
+            )
+            .into(),
+            ENTData::new(
+                Selector::new(SelectorKind::AllEntities)
+                    .with_arg(SelectorArg::Type {
+                        id: "minecraft:zombie".to_owned(),
+                        negated: false,
+                    })
+                    .with_arg(SelectorArg::Distance {
+                        min: Some(1),
+                        max: Some(10),
+                    }),
+            )
+            .into(),
+            ENTData::new(Selector::new(SelectorKind::Executor)).into(),
+        ];
+        for instr in instrs {
+            let words = encode(&instr).unwrap();
+            assert_eq!(decode_all(&words).unwrap(), vec![instr]);
+        }
+    }
+
+    // tests encoding the stack and swap registers
+    #[test]
+    fn test_encode_reserved_register() {
+        let push = Instruction::from(PUSHData::new(Register::SR));
+        let mov = Instruction::from(MOVData::from_register(Register::R0, Register::SWP));
+        let err1 = encode(&push).unwrap_err();
+        let err2 = encode(&mov).unwrap_err();
+        assert_eq!(err1.instruction(), &push);
+        assert_eq!(
+            err1.reason().unwrap(),
+            "sr cannot be used as the source register"
+        );
+        assert_eq!(
+            err2.reason().unwrap(),
+            "swp cannot be used as the destination register"
+        );
+        let exg = Instruction::from(EXGData::new(Register::R0, Register::SWP));
+        assert_eq!(
+            encode(&exg).unwrap_err().reason().unwrap(),
+            "swp cannot be used as the second argument register"
+        );
+    }
+
+    // tests encoding a literal that needs the extended form
+    #[test]
+    fn test_encode_extended() {
+        let mov = Instruction::from(MOVData::from_literal(0x10000, Register::R3));
+        assert_eq!(
+            encode(&mov).unwrap(),
+            vec![0x82030001.into(), 0x00010000.into()]
+        );
+        assert_eq!(encode_all(&[mov.clone(), mov]).unwrap().len(), 4);
+    }
+
+    // tests encoding a block check
+    #[test]
+    fn test_encode_blk() {
+        let blk = Instruction::from(BLKData::new(
+            Coordinate::Absolute(1),
+            Coordinate::Relative(-1),
+            Coordinate::Register(Register::R2),
+            BlockState::new("stone"),
+        ));
+        let words: Vec<u32> = encode(&blk).unwrap().iter().map(|op| op.value()).collect();
+        assert_eq!(
+            words,
+            vec![
+                0xD0070008, 0x00000001, 0xFFFFFFFF, 0x00000002, 0x0000000F,
+                0x6D696E65, 0x63726166, 0x743A7374, 0x6F6E6500,
+            ]
+        );
+
+        let mixed = Instruction::from(BLKData::new(
+            Coordinate::Local(1),
+            Coordinate::Relative(-1),
+            Coordinate::Local(2),
+            BlockState::new("stone"),
+        ));
+        assert_eq!(
+            encode(&mixed).unwrap_err().reason().unwrap(),
+            "local coordinates cannot be mixed with other coordinates"
+        );
+
+        let long = Instruction::from(BLKData::new(
+            Coordinate::Absolute(0),
+            Coordinate::Absolute(0),
+            Coordinate::Absolute(0),
+            BlockState::new(&"a".repeat(1100)),
+        ));
+        assert_eq!(
+            encode(&long).unwrap_err().reason().unwrap(),
+            "operands take 282 trailing words, but at most 255 fit"
+        );
+    }
+
+    // tests encoding an entity check
+    #[test]
+    fn test_encode_ent() {
+        let ent = Instruction::from(ENTData::new(Selector::new(SelectorKind::NearestPlayer)));
+        assert_eq!(
+            encode(&ent).unwrap(),
+            vec![0xD1000002.into(), 0x00000002.into(), 0x40700000.into()]
+        );
+    }
+
+    // tests encoding literal second arguments
+    #[test]
+    fn test_encode_literal_forms() {
+        let add = Instruction::from(ADDData::from_literal(Register::R0, 1, Register::R0));
+        let div = Instruction::from(DIVData::from_literal(Register::R1, 1000, Register::R2));
+        let cmp = Instruction::from(CMPData::from_literal(Register::R3, 1000));
+        assert_eq!(encode(&add).unwrap(), vec![0x18000001.into()]);
+        assert_eq!(
+            encode(&div).unwrap(),
+            vec![0x9B010201.into(), 0x000003E8.into()]
+        );
+        assert_eq!(encode(&cmp).unwrap(), vec![0x390303E8.into()]);
+        let rem = Instruction::from(MODData::new(Register::R0, Register::R1, Register::R2));
+        let min = Instruction::from(MINData::from_literal(Register::R3, 9, Register::R3));
+        let max = Instruction::from(MAXData::from_literal(Register::R4, 0x100, Register::R5));
+        assert_eq!(encode(&rem).unwrap(), vec![0x14000102.into()]);
+        assert_eq!(encode(&min).unwrap(), vec![0x1D030309.into()]);
+        assert_eq!(
+            encode(&max).unwrap(),
+            vec![0x9E040501.into(), 0x00000100.into()]
+        );
+    }
+
+    // tests encoding the bitwise instructions
+    #[test]
+    fn test_encode_bitwise() {
+        let and = Instruction::from(ANDData::from_literal(Register::R0, 0xFF, Register::R1));
+        let not = Instruction::from(NOTData::new(Register::R2, Register::R3));
+        let shr = Instruction::from(SHRData::new(Register::R4, 31, Register::R5));
+        let shl = Instruction::from(SHLData::new(Register::R4, 32, Register::R5));
+        assert_eq!(encode(&and).unwrap(), vec![0x680001FF.into()]);
+        assert_eq!(encode(&not).unwrap(), vec![0x63020300.into()]);
+        assert_eq!(encode(&shr).unwrap(), vec![0x6504051F.into()]);
+        assert_eq!(
+            encode(&shl).unwrap_err().reason().unwrap(),
+            "shift amount 32 is out of range (expected 0 to 31)"
+        );
+    }
+
+    // tests encoding values that overflow their fields
+    #[test]
+    fn test_encode_overflow() {
+        let jmp = Instruction::from(JMPData::new(0x01000000));
+        let jns = Instruction::from(JNSData::new(Flag::Zero, 0x10000));
+        let call = Instruction::from(CALLData::new(0x01000000));
+        let jle = Instruction::from(JCCData::new(Condition::LessOrEqual, 0x10000));
+        assert_eq!(
+            encode(&jmp).unwrap_err().reason().unwrap(),
+            "label ID 16777216 does not fit in 24 bits"
+        );
+        assert_eq!(
+            encode(&jns).unwrap_err().reason().unwrap(),
+            "label ID 65536 does not fit in 16 bits"
+        );
+        assert_eq!(
+            encode(&call).unwrap_err().reason().unwrap(),
+            "label ID 16777216 does not fit in 24 bits"
+        );
+        assert_eq!(
+            encode(&jle).unwrap_err().reason().unwrap(),
+            "label ID 65536 does not fit in 16 bits"
+        );
+    }
+}
+
+// end of file
@@ -0,0 +1,259 @@
+/*
+ * format.rs
+ * Defines the mnemonic codes used in encoded opcodes
+ * Created by Sarah Davis
+ * Created on 10/18/2026
+ *
+ * Copyright (C) 2026 Sarah Davis
+ *
+ * Licensed under the MIT License (see LICENSE file for details)
+ */
+
+// Every opcode is a 32-bit word laid out as follows:
+//
+//   31      24 23      16 15       8 7        0
+//  +----------+----------+----------+----------+
+//  | mnemonic | field 0  | field 1  | field 2  |
+//  +----------+----------+----------+----------+
+//
+// Fields 1 and 2 together form the 16-bit short field,
+// and fields 0 through 2 together form the 24-bit wide field.
+// Register fields hold the value produced by `u8::try_from(Register)`,
+// flag fields hold the value produced by `u8::from(Flag)`, and
+// condition fields hold the value produced by `u8::from(Condition)`.
+// Any field that an instruction does not use must be zero.
+//
+// Instructions whose operands do not fit in a single word use an
+// extended encoding. Bit 7 of the mnemonic marks an extended prefix
+// word, and field 2 of the prefix holds the number of trailing words
+// that follow it. The trailing words carry the remaining operands.
+//
+// Coordinates are packed into field 0 of a prefix two bits per axis,
+// with x in bits 5-4, y in bits 3-2 and z in bits 1-0. Each axis then
+// takes one trailing word holding either its offset as a two's
+// complement integer or its register field. Strings take one trailing
+// word holding their length in bytes, followed by their UTF-8 bytes
+// packed big-endian into words and padded with zero bytes.
+
+// usage statements
+use super::Opcode;
+
+/// The major revision of the instruction set encoded by this crate
+///
+/// It changes whenever an existing encoding changes meaning,
+/// so words encoded for another major revision can't be decoded.
+pub const ISA_MAJOR_VERSION: u16 = 1;
+
+/// The minor revision of the instruction set encoded by this crate
+///
+/// It changes whenever encodings are added, so words encoded for
+/// an earlier minor revision of the same major revision still
+/// decode, but words encoded for a later one may not.
+pub const ISA_MINOR_VERSION: u16 = 0;
+
+/// The mnemonic bit that marks an extended prefix word
+pub(crate) const EXTENDED: u8 = 0x80;
+
+/// The mnemonic bit that marks the literal form of an
+/// instruction whose second argument may be a literal
+pub(crate) const LITERAL: u8 = 0x08;
+
+/// `NOP`: `0 | 0 | 0`, so an all-zero word is a `NOP`
+pub(crate) const NOP: u8 = 0x00;
+
+/// `MOV` with a register source: `dest | src | 0`
+pub(crate) const MOV_REG: u8 = 0x01;
+
+/// `MOV` with a literal source: `dest | literal (short)`
+pub(crate) const MOV_LIT: u8 = 0x02;
+
+/// `MOV` with a 32-bit literal source: `dest | 0 | 1`, then `literal`
+pub(crate) const MOV_LIT_EXT: u8 = MOV_LIT | EXTENDED;
+
+/// `EXG`: `arg0 | arg1 | 0`
+pub(crate) const EXG: u8 = 0x03;
+
+/// `ADD`: `arg0 | arg1 | dest`
+pub(crate) const ADD: u8 = 0x10;
+
+/// `SUB`: `arg0 | arg1 | dest`
+pub(crate) const SUB: u8 = 0x11;
+
+/// `MUL`: `arg0 | arg1 | dest`
+pub(crate) const MUL: u8 = 0x12;
+
+/// `DIV`: `arg0 | arg1 | dest`
+pub(crate) const DIV: u8 = 0x13;
+
+/// `MOD`: `arg0 | arg1 | dest`
+pub(crate) const MOD: u8 = 0x14;
+
+/// `MIN`: `arg0 | arg1 | dest`
+pub(crate) const MIN: u8 = 0x15;
+
+/// `MAX`: `arg0 | arg1 | dest`
+pub(crate) const MAX: u8 = 0x16;
+
+/// `ADD` with a literal: `arg0 | dest | literal`
+pub(crate) const ADD_LIT: u8 = ADD | LITERAL;
+
+/// `SUB` with a literal: `arg0 | dest | literal`
+pub(crate) const SUB_LIT: u8 = SUB | LITERAL;
+
+/// `MUL` with a literal: `arg0 | dest | literal`
+pub(crate) const MUL_LIT: u8 = MUL | LITERAL;
+
+/// `DIV` with a literal: `arg0 | dest | literal`
+pub(crate) const DIV_LIT: u8 = DIV | LITERAL;
+
+/// `MOD` with a literal: `arg0 | dest | literal`
+pub(crate) const MOD_LIT: u8 = MOD | LITERAL;
+
+/// `MIN` with a literal: `arg0 | dest | literal`
+pub(crate) const MIN_LIT: u8 = MIN | LITERAL;
+
+/// `MAX` with a literal: `arg0 | dest | literal`
+pub(crate) const MAX_LIT: u8 = MAX | LITERAL;
+
+/// `ADD` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const ADD_LIT_EXT: u8 = ADD_LIT | EXTENDED;
+
+/// `SUB` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const SUB_LIT_EXT: u8 = SUB_LIT | EXTENDED;
+
+/// `MUL` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const MUL_LIT_EXT: u8 = MUL_LIT | EXTENDED;
+
+/// `DIV` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const DIV_LIT_EXT: u8 = DIV_LIT | EXTENDED;
+
+/// `MOD` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const MOD_LIT_EXT: u8 = MOD_LIT | EXTENDED;
+
+/// `MIN` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const MIN_LIT_EXT: u8 = MIN_LIT | EXTENDED;
+
+/// `MAX` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const MAX_LIT_EXT: u8 = MAX_LIT | EXTENDED;
+
+/// `PUSH`: `reg | 0 | 0`
+pub(crate) const PUSH: u8 = 0x20;
+
+/// `POP`: `reg | 0 | 0`
+pub(crate) const POP: u8 = 0x21;
+
+/// `CHK`: `reg | 0 | 0`
+pub(crate) const CHK: u8 = 0x30;
+
+/// `CMP`: `arg0 | arg1 | 0`
+pub(crate) const CMP: u8 = 0x31;
+
+/// `CMP` with a literal: `arg0 | literal (short)`
+pub(crate) const CMP_LIT: u8 = CMP | LITERAL;
+
+/// `CMP` with a 32-bit literal: `arg0 | 0 | 1`, then `literal`
+pub(crate) const CMP_LIT_EXT: u8 = CMP_LIT | EXTENDED;
+
+/// `JMP`: `label ID (wide)`
+pub(crate) const JMP: u8 = 0x40;
+
+/// `JS`: `flag | label ID (short)`
+pub(crate) const JS: u8 = 0x41;
+
+/// `JNS`: `flag | label ID (short)`
+pub(crate) const JNS: u8 = 0x42;
+
+/// `CALL`: `label ID (wide)`
+pub(crate) const CALL: u8 = 0x43;
+
+/// `RET`: `0 | 0 | 0`
+pub(crate) const RET: u8 = 0x44;
+
+/// `HLT`: `0 | 0 | 0`
+pub(crate) const HLT: u8 = 0x45;
+
+/// `J<cc>`: `condition | label ID (short)`
+pub(crate) const JCC: u8 = 0x46;
+
+/// `AND`: `arg0 | arg1 | dest`
+pub(crate) const AND: u8 = 0x60;
+
+/// `OR`: `arg0 | arg1 | dest`
+pub(crate) const OR: u8 = 0x61;
+
+/// `XOR`: `arg0 | arg1 | dest`
+pub(crate) const XOR: u8 = 0x62;
+
+/// `NOT`: `arg0 | dest | 0`
+pub(crate) const NOT: u8 = 0x63;
+
+/// `SHL`: `arg0 | dest | amount`
+pub(crate) const SHL: u8 = 0x64;
+
+/// `SHR`: `arg0 | dest | amount`
+pub(crate) const SHR: u8 = 0x65;
+
+/// `AND` with a literal: `arg0 | dest | literal`
+pub(crate) const AND_LIT: u8 = AND | LITERAL;
+
+/// `OR` with a literal: `arg0 | dest | literal`
+pub(crate) const OR_LIT: u8 = OR | LITERAL;
+
+/// `XOR` with a literal: `arg0 | dest | literal`
+pub(crate) const XOR_LIT: u8 = XOR | LITERAL;
+
+/// `AND` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const AND_LIT_EXT: u8 = AND_LIT | EXTENDED;
+
+/// `OR` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const OR_LIT_EXT: u8 = OR_LIT | EXTENDED;
+
+/// `XOR` with a 32-bit literal: `arg0 | dest | 1`, then `literal`
+pub(crate) const XOR_LIT_EXT: u8 = XOR_LIT | EXTENDED;
+
+/// `INC`: `reg | 0 | 0`
+pub(crate) const INC: u8 = 0x70;
+
+/// `DEC`: `reg | 0 | 0`
+pub(crate) const DEC: u8 = 0x71;
+
+/// `NEG`: `reg | 0 | 0`
+pub(crate) const NEG: u8 = 0x72;
+
+/// `BLK`: `coordinate kinds | 0 | count`, then `x`, `y`, `z`,
+/// the block string's length in bytes, and the block string
+pub(crate) const BLK: u8 = 0x50 | EXTENDED;
+
+/// `ENT`: `0 | 0 | count`, then the selector string
+pub(crate) const ENT: u8 = 0x51 | EXTENDED;
+
+/// Coordinate kind for an absolute coordinate
+pub(crate) const COORD_ABSOLUTE: u8 = 0;
+
+/// Coordinate kind for a relative (`~`) coordinate
+pub(crate) const COORD_RELATIVE: u8 = 1;
+
+/// Coordinate kind for a local (`^`) coordinate
+pub(crate) const COORD_LOCAL: u8 = 2;
+
+/// Coordinate kind for a register-sourced coordinate
+pub(crate) const COORD_REGISTER: u8 = 3;
+
+/// Gets the number of trailing words that follow an opcode
+///
+/// # Arguments
+///
+/// * `op` - The first word of an encoded instruction
+///
+/// # Returns
+///
+/// The number of words that follow `op` in its instruction
+pub(crate) fn trailing_words(op: Opcode) -> usize {
+    if op.mnemonic_field() & EXTENDED != 0 {
+        op.operand_field(2) as usize
+    } else {
+        0
+    }
+}
+
+// end of file
@@ -10,7 +10,18 @@
  */
 
 // module exports
+#[allow(clippy::module_inception)]
 mod opcode;
 pub use opcode::Opcode;
+mod format;
+pub use format::ISA_MAJOR_VERSION;
+pub use format::ISA_MINOR_VERSION;
+mod decode;
+pub use decode::decode;
+pub use decode::decode_all;
+pub use decode::Decoder;
+mod encode;
+pub use encode::encode;
+pub use encode::encode_all;
 
 // end of file
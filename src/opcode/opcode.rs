@@ -28,6 +28,64 @@ impl Opcode {
     pub fn value(&self) -> u32 {
         self.0
     }
+
+    /// Gets the mnemonic field of the `Opcode`
+    ///
+    /// The mnemonic field occupies the
+    /// most significant byte (bits 31-24)
+    ///
+    /// # Returns
+    ///
+    /// The mnemonic field of the `Opcode`
+    pub fn mnemonic_field(&self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// Gets one of the three byte-wide operand fields of the `Opcode`
+    ///
+    /// Operand field 0 occupies bits 23-16,
+    /// operand field 1 occupies bits 15-8,
+    /// and operand field 2 occupies bits 7-0
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the operand field to get
+    ///
+    /// # Returns
+    ///
+    /// The value of the operand field
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `index` is greater than 2.
+    pub fn operand_field(&self, index: usize) -> u8 {
+        assert!(index < 3, "Operand field index out of range: {}", index);
+        (self.0 >> (16 - 8 * index)) as u8
+    }
+
+    /// Gets the short operand field of the `Opcode`
+    ///
+    /// The short operand field spans operand
+    /// fields 1 and 2 (bits 15-0)
+    ///
+    /// # Returns
+    ///
+    /// The value of the short operand field
+    pub fn short_field(&self) -> u16 {
+        self.0 as u16
+    }
+
+    /// Gets the wide operand field of the `Opcode`
+    ///
+    /// The wide operand field spans all
+    /// three operand fields (bits 23-0)
+    ///
+    /// # Returns
+    ///
+    /// The value of the wide operand field
+    pub fn wide_field(&self) -> u32 {
+        self.0 & 0x00FFFFFF
+    }
 }
 
 // PartialEq implementation
@@ -40,7 +98,7 @@ impl PartialEq for Opcode {
 // Display implementation
 impl fmt::Display for Opcode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", format!("{:#010x}", self.0))
+        write!(f, "{:#010x}", self.0)
     }
 }
 
@@ -93,6 +151,18 @@ mod tests {
         assert_eq!(Opcode(0xdeadbeef).value(), 0xdeadbeef);
     }
 
+    // this test checks the field accessors
+    #[test]
+    fn test_fields() {
+        let op = Opcode(0x12345678);
+        assert_eq!(op.mnemonic_field(), 0x12);
+        assert_eq!(op.operand_field(0), 0x34);
+        assert_eq!(op.operand_field(1), 0x56);
+        assert_eq!(op.operand_field(2), 0x78);
+        assert_eq!(op.short_field(), 0x5678);
+        assert_eq!(op.wide_field(), 0x345678);
+    }
+
     // this test checks display formatting
     #[test]
     fn test_display_formatting() {